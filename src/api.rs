@@ -71,7 +71,18 @@ pub fn start(
                 .data(context.clone())
                 .service(web::resource("/api/server").to(endpoints::server_info))
                 .service(web::resource("/api/builds").to(endpoints::get_builds))
-                .service(web::resource("/api/builds/view/{id}").to(endpoints::get_builds_for_view));
+                .service(web::resource("/api/builds/view/{id}").to(endpoints::get_builds_for_view))
+                .service(web::resource("/api/builds/{id}/history").to(endpoints::get_build_history))
+                .service(web::resource("/api/history").to(endpoints::get_history))
+                .service(web::resource("/api/events").to(endpoints::get_events))
+                .service(
+                    web::resource("/api/ingest/{id}")
+                        .route(web::post().to(endpoints::ingest_build)),
+                )
+                .service(
+                    web::resource("/api/github/{id}")
+                        .route(web::post().to(endpoints::github_webhook)),
+                );
 
             // Serve static files from the web directory?
             if cfg!(feature = "docker") {
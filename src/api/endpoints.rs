@@ -1,12 +1,18 @@
 use std::sync::Arc;
 
 use actix_web::web;
-use actix_web::HttpResponse;
+use actix_web::{HttpRequest, HttpResponse};
+use futures::StreamExt;
 
 use crate::engine::state::EngineState;
+use crate::providers::collectors::github::{self, GitHubEvent};
+use crate::utils::signature;
 use crate::utils::VERSION;
 
-use super::models::{BuildViewModel, ServerInfoModel, ViewInfoModel};
+use super::models::{
+    BuildViewModel, HistoryEntryViewModel, HistoryQuery, IngestBuildModel, LiveEventModel,
+    ServerInfoModel, ViewInfoModel,
+};
 
 ///////////////////////////////////////////////////////////
 // Server information
@@ -73,3 +79,149 @@ pub async fn get_builds_for_view(
         .content_type("application/json")
         .body(json)
 }
+
+///////////////////////////////////////////////////////////
+// Build history
+
+pub async fn get_build_history(
+    id: web::Path<u64>,
+    state: web::Data<Arc<EngineState>>,
+) -> HttpResponse {
+    let entries = match state.history.for_build(*id) {
+        Some(entries) => entries,
+        None => return HttpResponse::NotFound().finish(),
+    };
+
+    // Convert to view models
+    let history: Vec<HistoryEntryViewModel> =
+        entries.iter().map(HistoryEntryViewModel::from).collect();
+
+    // Serialize to JSON and return.
+    let json = serde_json::to_string(&history).unwrap();
+    HttpResponse::Ok()
+        .content_type("application/json")
+        .body(json)
+}
+
+///////////////////////////////////////////////////////////
+// History
+
+pub async fn get_history(
+    query: web::Query<HistoryQuery>,
+    state: web::Data<Arc<EngineState>>,
+) -> HttpResponse {
+    // Convert to view models
+    let history: Vec<HistoryEntryViewModel> = state
+        .history
+        .between(query.from, query.to)
+        .iter()
+        .map(HistoryEntryViewModel::from)
+        .collect();
+
+    // Serialize to JSON and return.
+    let json = serde_json::to_string(&history).unwrap();
+    HttpResponse::Ok()
+        .content_type("application/json")
+        .body(json)
+}
+
+///////////////////////////////////////////////////////////
+// Live events
+
+pub async fn get_events(state: web::Data<Arc<EngineState>>) -> HttpResponse {
+    // Convert events to server-sent events as they arrive.
+    let events = state.events.subscribe().map(|event| {
+        let event = match LiveEventModel::from_event(&event) {
+            Some(event) => event.to_string(),
+            None => ":keepalive\n\n".to_string(),
+        };
+        Ok::<_, actix_web::Error>(web::Bytes::from(event))
+    });
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .header("Cache-Control", "no-cache")
+        .streaming(events)
+}
+
+///////////////////////////////////////////////////////////
+// Ingest
+
+pub async fn ingest_build(
+    id: web::Path<String>,
+    request: HttpRequest,
+    body: web::Bytes,
+    state: web::Data<Arc<EngineState>>,
+) -> HttpResponse {
+    let credentials = match state.ingest.get_credentials(&id[..]) {
+        Some(credentials) => credentials,
+        None => return HttpResponse::NotFound().finish(),
+    };
+
+    // Make sure that the sender knows the secret.
+    let header = |name: &str| {
+        request
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    };
+    if !credentials.verify(
+        header("Authorization"),
+        header("X-Duck-Signature-256"),
+        &body[..],
+    ) {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    // Convert the payload to a build.
+    let build = match serde_json::from_slice::<IngestBuildModel>(&body[..]) {
+        Ok(model) => match model.to_build(&id[..]) {
+            Ok(build) => build,
+            Err(e) => return HttpResponse::BadRequest().body(e),
+        },
+        Err(e) => return HttpResponse::BadRequest().body(e.to_string()),
+    };
+
+    // The engine will pick up the build shortly.
+    state.ingest.push(build);
+    HttpResponse::Accepted().finish()
+}
+
+pub async fn github_webhook(
+    id: web::Path<String>,
+    request: HttpRequest,
+    body: web::Bytes,
+    state: web::Data<Arc<EngineState>>,
+) -> HttpResponse {
+    let config = match state.ingest.get_github(&id[..]) {
+        Some(config) => config,
+        None => return HttpResponse::NotFound().finish(),
+    };
+
+    // Make sure that the event was signed by GitHub.
+    let header = |name: &str| {
+        request
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    };
+    let secret = config.webhook_secret.as_deref().unwrap_or_default();
+    let signature = header("X-Hub-Signature-256").unwrap_or_default();
+    if !signature::verify(secret, &body[..], signature) {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let event = header("X-GitHub-Event").unwrap_or_default();
+    match github::parse_event(&config, event, &body[..]) {
+        Ok(GitHubEvent::Build(build)) => {
+            state.ingest.push(*build);
+            HttpResponse::Accepted().finish()
+        }
+        Ok(GitHubEvent::Refresh) => {
+            state.ingest.request_refresh(&id[..]);
+            HttpResponse::Accepted().finish()
+        }
+        Ok(GitHubEvent::Ignored) => HttpResponse::NoContent().finish(),
+        Err(e) => HttpResponse::BadRequest().body(e.to_string()),
+    }
+}
@@ -1,7 +1,9 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use crate::builds::{Build, BuildStatus};
+use crate::builds::{Build, BuildBuilder, BuildStatus};
 use crate::config::ViewConfiguration;
+use crate::engine::state::events::LiveEvent;
+use crate::engine::state::history::HistoryEntry;
 
 ///////////////////////////////////////////////////////////
 // Server information
@@ -61,6 +63,7 @@ pub enum BuildStatusViewModel {
     Running,
     Canceled,
     Queued,
+    Blocked,
     Skipped,
 }
 
@@ -92,7 +95,141 @@ impl From<&BuildStatus> for BuildStatusViewModel {
             BuildStatus::Running => BuildStatusViewModel::Running,
             BuildStatus::Canceled => BuildStatusViewModel::Canceled,
             BuildStatus::Queued => BuildStatusViewModel::Queued,
+            BuildStatus::Blocked => BuildStatusViewModel::Blocked,
             BuildStatus::Skipped => BuildStatusViewModel::Skipped,
         }
     }
 }
+
+///////////////////////////////////////////////////////////
+// History
+
+#[derive(Deserialize)]
+pub struct HistoryQuery {
+    pub from: Option<i64>,
+    pub to: Option<i64>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct HistoryEntryViewModel {
+    pub timestamp: i64,
+    pub build: BuildViewModel,
+}
+
+impl From<&HistoryEntry> for HistoryEntryViewModel {
+    fn from(item: &HistoryEntry) -> Self {
+        HistoryEntryViewModel {
+            timestamp: item.timestamp,
+            build: BuildViewModel::from(&item.build),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////
+// Ingest
+
+/// A build that is pushed to a webhook collector.
+#[derive(Deserialize)]
+pub struct IngestBuildModel {
+    pub provider: Option<String>,
+    pub project: String,
+    #[serde(rename = "projectId")]
+    pub project_id: Option<String>,
+    pub definition: String,
+    #[serde(rename = "definitionId")]
+    pub definition_id: Option<String>,
+    pub branch: String,
+    #[serde(rename = "buildId")]
+    pub build_id: String,
+    #[serde(rename = "buildNumber")]
+    pub build_number: Option<String>,
+    pub status: BuildStatus,
+    pub url: Option<String>,
+    pub started: Option<i64>,
+    pub finished: Option<i64>,
+}
+
+impl IngestBuildModel {
+    pub fn to_build(&self, collector: &str) -> Result<Build, String> {
+        if self.project.is_empty() {
+            return Err("The project is empty".to_owned());
+        }
+        if self.definition.is_empty() {
+            return Err("The definition is empty".to_owned());
+        }
+        if self.branch.is_empty() {
+            return Err("The branch is empty".to_owned());
+        }
+        if self.build_id.is_empty() {
+            return Err("The build ID is empty".to_owned());
+        }
+
+        BuildBuilder::new()
+            .build_id(&self.build_id)
+            .provider(self.provider.as_deref().unwrap_or("Webhook"))
+            .origin(collector)
+            .collector(collector)
+            .project_id(self.project_id.as_ref().unwrap_or(&self.project))
+            .project_name(&self.project)
+            .definition_id(self.definition_id.as_ref().unwrap_or(&self.definition))
+            .definition_name(&self.definition)
+            .build_number(self.build_number.as_ref().unwrap_or(&self.build_id))
+            .status(self.status.clone())
+            .url(self.url.as_deref().unwrap_or(""))
+            .started_at(
+                self.started
+                    .unwrap_or_else(|| chrono::Utc::now().timestamp()),
+            )
+            .finished_at(self.finished)
+            .branch(&self.branch)
+            .build()
+    }
+}
+
+///////////////////////////////////////////////////////////
+// Live events
+
+#[derive(Serialize, Clone)]
+pub struct StatusViewModel {
+    pub status: BuildStatusViewModel,
+}
+
+/// A server-sent event.
+pub struct LiveEventModel {
+    pub event: &'static str,
+    pub data: String,
+}
+
+impl LiveEventModel {
+    /// Converts a live event to a server-sent event. Keep-alives
+    /// have no event of their own and are sent as comments.
+    pub fn from_event(item: &LiveEvent) -> Option<Self> {
+        let (event, data) = match item {
+            LiveEvent::BuildUpdated(build) => (
+                "buildUpdated",
+                serde_json::to_string(&BuildViewModel::from(build)),
+            ),
+            LiveEvent::BuildStatusChanged(build) => (
+                "buildStatusChanged",
+                serde_json::to_string(&BuildViewModel::from(build)),
+            ),
+            LiveEvent::StatusChanged(status) => (
+                "statusChanged",
+                serde_json::to_string(&StatusViewModel {
+                    status: BuildStatusViewModel::from(status),
+                }),
+            ),
+            LiveEvent::KeepAlive => return None,
+        };
+        Some(LiveEventModel {
+            event,
+            data: data.unwrap(),
+        })
+    }
+}
+
+impl std::fmt::Display for LiveEventModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "event: {}\ndata: {}\n\n", self.event, self.data)
+    }
+}
@@ -127,7 +127,7 @@ impl BuildBuilder {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BuildStatus {
     Unknown,
     Success,
@@ -135,6 +135,7 @@ pub enum BuildStatus {
     Running,
     Canceled,
     Queued,
+    Blocked,
     Skipped,
 }
 
@@ -147,6 +148,7 @@ impl Display for BuildStatus {
             BuildStatus::Running => write!(f, "Running"),
             BuildStatus::Canceled => write!(f, "Canceled"),
             BuildStatus::Queued => write!(f, "Queued"),
+            BuildStatus::Blocked => write!(f, "Blocked"),
             BuildStatus::Skipped => write!(f, "Skipped"),
         }
     }
@@ -161,6 +163,7 @@ impl BuildStatus {
             BuildStatus::Running => false,
             BuildStatus::Canceled => false,
             BuildStatus::Queued => false,
+            BuildStatus::Blocked => false,
             BuildStatus::Skipped => false,
         }
     }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use schemars::JsonSchema;
 use serde::Deserialize;
 
@@ -38,6 +40,10 @@ pub struct Configuration {
     /// # Observers
     #[serde(default)]
     pub observers: Option<Vec<ObserverConfiguration>>,
+    /// # Build history
+    /// Persists build status transitions to disk
+    #[serde(default)]
+    pub history: Option<HistoryConfiguration>,
 }
 
 impl Configuration {
@@ -71,6 +77,30 @@ impl Configuration {
         result
     }
 
+    /// Gets the update interval in seconds for a collector.
+    /// Falls back to the global interval if the collector
+    /// does not specify one.
+    pub fn get_collector_interval(&self, id: &str) -> u16 {
+        let interval = self
+            .collectors
+            .iter()
+            .find(|c| c.get_id() == id)
+            .and_then(|c| c.get_interval())
+            .unwrap_or(self.interval);
+        std::cmp::max(1, interval)
+    }
+
+    /// Gets the collection timeout in seconds for a collector.
+    pub fn get_collector_timeout(&self, id: &str) -> u16 {
+        let timeout = self
+            .collectors
+            .iter()
+            .find(|c| c.get_id() == id)
+            .and_then(|c| c.get_timeout())
+            .unwrap_or_else(default_timeout);
+        std::cmp::max(1, timeout)
+    }
+
     pub fn collector_exist(&self, id: &str) -> bool {
         for collector in self.collectors.iter() {
             if collector.get_id() == id {
@@ -102,6 +132,23 @@ pub struct ViewConfiguration {
     pub collectors: Vec<String>,
 }
 
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct HistoryConfiguration {
+    /// # History file
+    /// The path to the file where build history is stored
+    pub path: String,
+    /// # Retention
+    /// The number of days to keep build history for
+    #[serde(default)]
+    pub retention: Option<u16>,
+}
+
+impl HistoryConfiguration {
+    pub fn get_retention(&self) -> u16 {
+        std::cmp::max(1, self.retention.unwrap_or_else(default_retention))
+    }
+}
+
 #[derive(Serialize, Deserialize, JsonSchema, Clone)]
 pub enum CollectorConfiguration {
     /// # TeamCity collector
@@ -124,6 +171,38 @@ pub enum CollectorConfiguration {
     /// Gets builds from AppVeyor
     #[serde(rename = "appveyor")]
     AppVeyor(AppVeyorConfiguration),
+    /// # GitLab collector
+    /// Gets pipelines from GitLab CI/CD
+    #[serde(rename = "gitlab")]
+    GitLab(GitLabConfiguration),
+    /// # Jenkins collector
+    /// Gets builds from Jenkins
+    #[serde(rename = "jenkins")]
+    Jenkins(JenkinsConfiguration),
+    /// # Bitbucket collector
+    /// Gets pipelines from Bitbucket Pipelines
+    #[serde(rename = "bitbucket")]
+    Bitbucket(BitbucketConfiguration),
+    /// # CircleCI collector
+    /// Gets workflows from CircleCI
+    #[serde(rename = "circleci")]
+    CircleCI(CircleCIConfiguration),
+    /// # Buildkite collector
+    /// Gets builds from Buildkite
+    #[serde(rename = "buildkite")]
+    Buildkite(BuildkiteConfiguration),
+    /// # Drone collector
+    /// Gets builds from Drone or Woodpecker CI
+    #[serde(rename = "drone")]
+    Drone(DroneConfiguration),
+    /// # Argo CD collector
+    /// Gets application deployments from Argo CD
+    #[serde(rename = "argocd")]
+    ArgoCD(ArgoCDConfiguration),
+    /// # Webhook collector
+    /// Receives builds that are pushed to Duck
+    #[serde(rename = "webhook")]
+    Webhook(WebhookConfiguration),
     /// # Duck collector
     /// Gets builds from another Duck instance
     #[serde(rename = "duck")]
@@ -142,6 +221,14 @@ impl CollectorConfiguration {
             CollectorConfiguration::GitHub(c) => &c.id,
             CollectorConfiguration::OctopusDeploy(c) => &c.id,
             CollectorConfiguration::AppVeyor(c) => &c.id,
+            CollectorConfiguration::Webhook(c) => &c.id,
+            CollectorConfiguration::ArgoCD(c) => &c.id,
+            CollectorConfiguration::Drone(c) => &c.id,
+            CollectorConfiguration::Buildkite(c) => &c.id,
+            CollectorConfiguration::CircleCI(c) => &c.id,
+            CollectorConfiguration::Bitbucket(c) => &c.id,
+            CollectorConfiguration::Jenkins(c) => &c.id,
+            CollectorConfiguration::GitLab(c) => &c.id,
             CollectorConfiguration::Duck(c) => &c.id,
             CollectorConfiguration::Debugger(c) => &c.id,
         }
@@ -154,6 +241,14 @@ impl CollectorConfiguration {
             CollectorConfiguration::GitHub(c) => c.enabled,
             CollectorConfiguration::OctopusDeploy(c) => c.enabled,
             CollectorConfiguration::AppVeyor(c) => c.enabled,
+            CollectorConfiguration::Webhook(c) => c.enabled,
+            CollectorConfiguration::ArgoCD(c) => c.enabled,
+            CollectorConfiguration::Drone(c) => c.enabled,
+            CollectorConfiguration::Buildkite(c) => c.enabled,
+            CollectorConfiguration::CircleCI(c) => c.enabled,
+            CollectorConfiguration::Bitbucket(c) => c.enabled,
+            CollectorConfiguration::Jenkins(c) => c.enabled,
+            CollectorConfiguration::GitLab(c) => c.enabled,
             CollectorConfiguration::Duck(c) => c.enabled,
             CollectorConfiguration::Debugger(c) => c.enabled,
         } {
@@ -161,6 +256,50 @@ impl CollectorConfiguration {
         }
         return true;
     }
+
+    pub fn get_interval(&self) -> Option<u16> {
+        match self {
+            CollectorConfiguration::TeamCity(c) => c.interval,
+            CollectorConfiguration::Azure(c) => c.interval,
+            CollectorConfiguration::GitHub(c) => c.interval.or_else(|| {
+                c.webhook_secret
+                    .as_ref()
+                    .map(|_| default_github_webhook_interval())
+            }),
+            CollectorConfiguration::OctopusDeploy(c) => c.interval,
+            CollectorConfiguration::AppVeyor(c) => c.interval,
+            CollectorConfiguration::Webhook(_) => None,
+            CollectorConfiguration::ArgoCD(c) => c.interval,
+            CollectorConfiguration::Drone(c) => c.interval,
+            CollectorConfiguration::Buildkite(c) => c.interval,
+            CollectorConfiguration::CircleCI(c) => c.interval,
+            CollectorConfiguration::Bitbucket(c) => c.interval,
+            CollectorConfiguration::Jenkins(c) => c.interval,
+            CollectorConfiguration::GitLab(c) => c.interval,
+            CollectorConfiguration::Duck(c) => c.interval,
+            CollectorConfiguration::Debugger(c) => c.interval,
+        }
+    }
+
+    pub fn get_timeout(&self) -> Option<u16> {
+        match self {
+            CollectorConfiguration::TeamCity(c) => c.timeout,
+            CollectorConfiguration::Azure(c) => c.timeout,
+            CollectorConfiguration::GitHub(c) => c.timeout,
+            CollectorConfiguration::OctopusDeploy(c) => c.timeout,
+            CollectorConfiguration::AppVeyor(c) => c.timeout,
+            CollectorConfiguration::Webhook(_) => None,
+            CollectorConfiguration::ArgoCD(c) => c.timeout,
+            CollectorConfiguration::Drone(c) => c.timeout,
+            CollectorConfiguration::Buildkite(c) => c.timeout,
+            CollectorConfiguration::CircleCI(c) => c.timeout,
+            CollectorConfiguration::Bitbucket(c) => c.timeout,
+            CollectorConfiguration::Jenkins(c) => c.timeout,
+            CollectorConfiguration::GitLab(c) => c.timeout,
+            CollectorConfiguration::Duck(c) => c.timeout,
+            CollectorConfiguration::Debugger(c) => c.timeout,
+        }
+    }
 }
 
 impl Validate for CollectorConfiguration {
@@ -171,6 +310,14 @@ impl Validate for CollectorConfiguration {
             CollectorConfiguration::GitHub(c) => c.validate(),
             CollectorConfiguration::OctopusDeploy(c) => c.validate(),
             CollectorConfiguration::AppVeyor(c) => c.validate(),
+            CollectorConfiguration::Webhook(c) => c.validate(),
+            CollectorConfiguration::ArgoCD(c) => c.validate(),
+            CollectorConfiguration::Drone(c) => c.validate(),
+            CollectorConfiguration::Buildkite(c) => c.validate(),
+            CollectorConfiguration::CircleCI(c) => c.validate(),
+            CollectorConfiguration::Bitbucket(c) => c.validate(),
+            CollectorConfiguration::Jenkins(c) => c.validate(),
+            CollectorConfiguration::GitLab(c) => c.validate(),
             CollectorConfiguration::Duck(c) => c.validate(),
             CollectorConfiguration::Debugger(c) => c.validate(),
         }
@@ -188,6 +335,24 @@ pub enum ObserverConfiguration {
     /// # Mattermost observer
     #[serde(rename = "mattermost")]
     Mattermost(MattermostConfiguration),
+    /// # Microsoft Teams observer
+    #[serde(rename = "teams")]
+    Teams(TeamsConfiguration),
+    /// # Discord observer
+    #[serde(rename = "discord")]
+    Discord(DiscordConfiguration),
+    /// # Webhook observer
+    #[serde(rename = "webhook")]
+    Webhook(WebhookObserverConfiguration),
+    /// # Email observer
+    #[serde(rename = "email")]
+    Email(EmailConfiguration),
+    /// # MQTT observer
+    #[serde(rename = "mqtt")]
+    Mqtt(MqttConfiguration),
+    /// # Home Assistant observer
+    #[serde(rename = "homeassistant")]
+    HomeAssistant(HomeAssistantConfiguration),
 }
 
 impl ObserverConfiguration {
@@ -196,6 +361,12 @@ impl ObserverConfiguration {
             ObserverConfiguration::Hue(c) => &c.id,
             ObserverConfiguration::Slack(c) => &c.id,
             ObserverConfiguration::Mattermost(c) => &c.id,
+            ObserverConfiguration::Teams(c) => &c.id,
+            ObserverConfiguration::Discord(c) => &c.id,
+            ObserverConfiguration::Webhook(c) => &c.id,
+            ObserverConfiguration::Email(c) => &c.id,
+            ObserverConfiguration::Mqtt(c) => &c.id,
+            ObserverConfiguration::HomeAssistant(c) => &c.id,
         }
     }
 
@@ -204,6 +375,12 @@ impl ObserverConfiguration {
             ObserverConfiguration::Hue(c) => c.enabled,
             ObserverConfiguration::Slack(c) => c.enabled,
             ObserverConfiguration::Mattermost(c) => c.enabled,
+            ObserverConfiguration::Teams(c) => c.enabled,
+            ObserverConfiguration::Discord(c) => c.enabled,
+            ObserverConfiguration::Webhook(c) => c.enabled,
+            ObserverConfiguration::Email(c) => c.enabled,
+            ObserverConfiguration::Mqtt(c) => c.enabled,
+            ObserverConfiguration::HomeAssistant(c) => c.enabled,
         } {
             return enabled;
         }
@@ -215,6 +392,12 @@ impl ObserverConfiguration {
             ObserverConfiguration::Hue(c) => c.collectors.clone(),
             ObserverConfiguration::Slack(c) => c.collectors.clone(),
             ObserverConfiguration::Mattermost(c) => c.collectors.clone(),
+            ObserverConfiguration::Teams(c) => c.collectors.clone(),
+            ObserverConfiguration::Discord(c) => c.collectors.clone(),
+            ObserverConfiguration::Webhook(c) => c.collectors.clone(),
+            ObserverConfiguration::Email(c) => c.collectors.clone(),
+            ObserverConfiguration::Mqtt(c) => c.collectors.clone(),
+            ObserverConfiguration::HomeAssistant(c) => c.collectors.clone(),
         }
     }
 }
@@ -225,6 +408,12 @@ impl Validate for ObserverConfiguration {
             ObserverConfiguration::Hue(c) => c.validate(),
             ObserverConfiguration::Slack(c) => c.validate(),
             ObserverConfiguration::Mattermost(c) => c.validate(),
+            ObserverConfiguration::Teams(c) => c.validate(),
+            ObserverConfiguration::Discord(c) => c.validate(),
+            ObserverConfiguration::Webhook(c) => c.validate(),
+            ObserverConfiguration::Email(c) => c.validate(),
+            ObserverConfiguration::Mqtt(c) => c.validate(),
+            ObserverConfiguration::HomeAssistant(c) => c.validate(),
         }
     }
 }
@@ -239,13 +428,38 @@ pub struct AppVeyorConfiguration {
     /// # Determines whether or not this collector is enabled
     #[serde(default)]
     pub enabled: Option<bool>,
-    /// # The TeamCity credentials
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
+    /// # The AppVeyor credentials
     pub credentials: AppVeyorCredentials,
     /// # The AppVeyor account
     pub account: String,
     /// # The AppVeyor project
-    pub project: String,
+    /// The project slug
+    #[serde(default)]
+    pub project: Option<String>,
+    /// # The AppVeyor projects
+    /// The project slugs. All projects in the account
+    /// are included if neither a project or projects are specified.
+    #[serde(default)]
+    pub projects: Option<Vec<String>>,
+    /// # The branches to include
+    /// Exact branch names, glob patterns or regular expressions
+    /// prefixed with `regex:`. All branches are included if omitted.
+    #[serde(default)]
+    pub branches: Option<Vec<String>>,
+    /// # The branches to exclude
+    /// Uses the same pattern syntax as the branches to include
+    #[serde(default, rename = "excludedBranches")]
+    pub excluded_branches: Option<Vec<String>>,
     /// # The number of builds to retrieve
+    /// The latest build for each branch is found among these builds
     #[serde(default)]
     pub count: Option<u16>,
 }
@@ -253,14 +467,36 @@ pub struct AppVeyorConfiguration {
 impl AppVeyorConfiguration {
     pub fn get_count(&self) -> u16 {
         match self.count {
-            None => 1,
+            None => 20,
             Some(count) => std::cmp::max(1, count),
         }
     }
+
+    /// Gets the configured projects, or `None`
+    /// if all projects should be included.
+    pub fn get_projects(&self) -> Option<Vec<String>> {
+        let mut result = Vec::<String>::new();
+        if let Some(project) = &self.project {
+            result.push(project.clone());
+        }
+        if let Some(projects) = &self.projects {
+            result.extend(projects.iter().cloned());
+        }
+        if result.is_empty() {
+            return None;
+        }
+        Some(result)
+    }
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Clone)]
 pub enum AppVeyorCredentials {
+    /// # Anonymous
+    /// Use anonymous authentication for public projects
+    #[serde(rename = "anonymous")]
+    Anonymous,
+    /// # Bearer token
+    /// Authenticate using an AppVeyor API key
     #[serde(rename = "bearer")]
     Bearer(String),
 }
@@ -275,13 +511,35 @@ pub struct TeamCityConfiguration {
     /// # Determines whether or not this collector is enabled
     #[serde(default)]
     pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
     /// # The TeamCity server URL
     #[serde(rename = "serverUrl")]
     pub server_url: String,
     /// # The TeamCity credentials
     pub credentials: TeamCityAuth,
     /// # The TeamCity builds definitions to include
+    #[serde(default)]
     pub builds: Vec<String>,
+    /// # The TeamCity projects to include
+    /// Includes all build definitions in the projects and their subprojects
+    #[serde(default)]
+    pub projects: Option<Vec<String>>,
+    /// # The branches to include
+    /// Exact branch names, glob patterns or regular expressions
+    /// prefixed with `regex:`. All branches are included if omitted.
+    #[serde(default)]
+    pub branches: Option<Vec<String>>,
+    /// # The branches to exclude
+    /// Uses the same pattern syntax as the branches to include
+    #[serde(default, rename = "excludedBranches")]
+    pub excluded_branches: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Clone)]
@@ -299,6 +557,10 @@ pub enum TeamCityAuth {
         /// # The password to use
         password: String,
     },
+    /// # Access token
+    /// Authenticate using a TeamCity access token
+    #[serde(rename = "token")]
+    Token(String),
 }
 
 ///////////////////////////////////////////////////////////
@@ -311,6 +573,14 @@ pub struct AzureDevOpsConfiguration {
     /// # Determines whether or not this collector is enabled
     #[serde(default)]
     pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
     /// # The Azure DevOps server URL.
     /// Only required if Duck should collect builds
     /// from a self-hosted instance of Azure DevOps Server.
@@ -323,9 +593,36 @@ pub struct AzureDevOpsConfiguration {
     /// # The Azure DevOps credentials
     pub credentials: AzureDevOpsCredentials,
     /// # The branches to include
+    /// Exact branch names such as `refs/heads/master`, glob patterns
+    /// such as `refs/heads/release/*` or regular expressions
+    /// prefixed with `regex:`. Branches matching a pattern are
+    /// discovered from recent builds.
     pub branches: Vec<String>,
+    /// # The branches to exclude
+    /// Uses the same pattern syntax as the branches to include
+    #[serde(default, rename = "excludedBranches")]
+    pub excluded_branches: Option<Vec<String>>,
     /// # The build definitions to include
     pub definitions: Vec<String>,
+    /// # Collect stages
+    /// Reports each stage of a multi-stage YAML pipeline
+    /// as a separate build instead of the pipeline as a whole
+    #[serde(default)]
+    pub stages: Option<bool>,
+    /// # The release definitions to include
+    /// Collects the latest deployment of classic release pipelines
+    #[serde(default)]
+    pub releases: Option<Vec<AzureDevOpsRelease>>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct AzureDevOpsRelease {
+    /// # The release definition ID
+    #[serde(rename = "definitionId")]
+    pub definition_id: String,
+    /// # The environments within the release definition
+    /// Either environment names or definition environment IDs
+    pub environments: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Clone)]
@@ -350,6 +647,19 @@ pub struct GitHubConfiguration {
     /// # Determines whether or not this collector is enabled
     #[serde(default)]
     pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
+    /// # The GitHub API URL
+    /// Defaults to https://api.github.com.
+    /// Use https://<host>/api/v3 for GitHub Enterprise Server.
+    #[serde(default, rename = "apiUrl")]
+    pub api_url: Option<String>,
     /// # The GitHub owner
     pub owner: String,
     /// # The GitHub repository
@@ -357,7 +667,31 @@ pub struct GitHubConfiguration {
     /// # The GitHub credentials
     pub credentials: GitHubCredentials,
     /// # The GitHub Actions workflow
-    pub workflow: String,
+    /// The file name or ID of a single workflow
+    #[serde(default)]
+    pub workflow: Option<String>,
+    /// # The GitHub Actions workflows
+    /// The file names or IDs of the workflows to collect.
+    /// All workflows in the repository are collected if
+    /// neither this nor a single workflow is specified.
+    #[serde(default)]
+    pub workflows: Option<Vec<String>>,
+    /// # The branches to include
+    /// Exact branch names, glob patterns such as `release/*` or
+    /// regular expressions prefixed with `regex:`.
+    /// All branches are included if not specified.
+    #[serde(default)]
+    pub branches: Option<Vec<String>>,
+    /// # The branches to exclude
+    /// Uses the same pattern syntax as the branches to include
+    #[serde(default, rename = "excludedBranches")]
+    pub excluded_branches: Option<Vec<String>>,
+    /// # The GitHub webhook secret
+    /// Enables receiving `workflow_run` and `check_suite` events
+    /// at /api/github/{id}. The collector is then only polled
+    /// every five minutes unless another interval is specified.
+    #[serde(default, rename = "webhookSecret")]
+    pub webhook_secret: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Clone)]
@@ -371,6 +705,283 @@ pub enum GitHubCredentials {
         /// # The password to use
         password: String,
     },
+    /// # Personal access token
+    /// Authenticate using a personal access token
+    #[serde(rename = "token")]
+    Token(String),
+    /// # GitHub App
+    /// Authenticate as a GitHub App installation
+    #[serde(rename = "app")]
+    App {
+        /// # The GitHub App ID
+        #[serde(rename = "appId")]
+        app_id: String,
+        /// # The installation ID
+        #[serde(rename = "installationId")]
+        installation_id: String,
+        /// # The PEM encoded private key of the GitHub App
+        #[serde(rename = "privateKey")]
+        private_key: String,
+    },
+}
+
+///////////////////////////////////////////////////////////
+// Bitbucket Pipelines
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct BitbucketConfiguration {
+    /// # The Bitbucket collector ID
+    pub id: String,
+    /// # Determines whether or not this collector is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
+    /// # The Bitbucket workspace
+    pub workspace: String,
+    /// # The Bitbucket repository
+    pub repository: String,
+    /// # The Bitbucket credentials
+    pub credentials: BitbucketCredentials,
+    /// # The branches to include
+    /// If omitted, pipelines for all branches will be included
+    #[serde(default)]
+    pub branches: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub enum BitbucketCredentials {
+    /// # Anonymous
+    /// Use anonymous authentication
+    #[serde(rename = "anonymous")]
+    Anonymous,
+    /// # App password
+    /// Authenticate using an app password
+    #[serde(rename = "appPassword")]
+    AppPassword {
+        /// # The username to use
+        username: String,
+        /// # The app password to use
+        password: String,
+    },
+}
+
+///////////////////////////////////////////////////////////
+// Buildkite
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct BuildkiteConfiguration {
+    /// # The Buildkite collector ID
+    pub id: String,
+    /// # Determines whether or not this collector is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
+    /// # The Buildkite organization slug
+    pub organization: String,
+    /// # The Buildkite pipeline slugs
+    pub pipelines: Vec<String>,
+    /// # The Buildkite credentials
+    pub credentials: BuildkiteCredentials,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub enum BuildkiteCredentials {
+    /// # API access token
+    /// Authenticate using an API access token with the read_builds scope
+    #[serde(rename = "token")]
+    Token(String),
+}
+
+///////////////////////////////////////////////////////////
+// CircleCI
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct CircleCIConfiguration {
+    /// # The CircleCI collector ID
+    pub id: String,
+    /// # Determines whether or not this collector is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
+    /// # The CircleCI project slug
+    /// The project slug in the form 'vcs/organization/repository' (i.e. 'gh/duckhq/duck')
+    pub project: String,
+    /// # The CircleCI credentials
+    pub credentials: CircleCICredentials,
+    /// # The branches to include
+    /// If omitted, the latest pipelines for all branches will be included
+    #[serde(default)]
+    pub branches: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub enum CircleCICredentials {
+    /// # Personal API token
+    /// Authenticate using a personal API token
+    #[serde(rename = "token")]
+    Token(String),
+}
+
+///////////////////////////////////////////////////////////
+// Drone
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DroneConfiguration {
+    /// # The Drone collector ID
+    pub id: String,
+    /// # Determines whether or not this collector is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
+    /// # The Drone or Woodpecker server URL
+    #[serde(rename = "serverUrl")]
+    pub server_url: String,
+    /// # The repository owner
+    pub owner: String,
+    /// # The repository name
+    pub repository: String,
+    /// # The Drone credentials
+    pub credentials: DroneCredentials,
+    /// # The branches to include
+    /// If omitted, the latest builds for all branches will be included
+    #[serde(default)]
+    pub branches: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub enum DroneCredentials {
+    /// # Anonymous
+    /// Use anonymous authentication
+    #[serde(rename = "anonymous")]
+    Anonymous,
+    /// # Personal token
+    /// Authenticate using a personal token
+    #[serde(rename = "token")]
+    Token(String),
+}
+
+///////////////////////////////////////////////////////////
+// GitLab
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct GitLabConfiguration {
+    /// # The GitLab collector ID
+    pub id: String,
+    /// # Determines whether or not this collector is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
+    /// # The GitLab server URL
+    /// Only required if Duck should collect pipelines
+    /// from a self-hosted instance of GitLab.
+    #[serde(rename = "serverUrl")]
+    pub server_url: Option<String>,
+    /// # The GitLab project
+    /// The numeric project ID or the full project path (i.e. 'group/project')
+    pub project: String,
+    /// # The GitLab credentials
+    pub credentials: GitLabCredentials,
+    /// # The branches to include
+    /// If omitted, pipelines for all branches will be included
+    #[serde(default)]
+    pub branches: Option<Vec<String>>,
+    /// # The pipeline sources to include
+    /// For example 'push', 'schedule' or 'merge_request_event'.
+    /// If omitted, pipelines from all sources will be included
+    #[serde(default)]
+    pub sources: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub enum GitLabCredentials {
+    /// # Anonymous
+    /// Use anonymous authentication
+    #[serde(rename = "anonymous")]
+    Anonymous,
+    /// # Private token
+    /// Authenticate using a personal, group or project access token
+    #[serde(rename = "privateToken")]
+    PrivateToken(String),
+}
+
+///////////////////////////////////////////////////////////
+// Jenkins
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct JenkinsConfiguration {
+    /// # The Jenkins collector ID
+    pub id: String,
+    /// # Determines whether or not this collector is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
+    /// # The Jenkins server URL
+    #[serde(rename = "serverUrl")]
+    pub server_url: String,
+    /// # The Jenkins credentials
+    pub credentials: JenkinsCredentials,
+    /// # The Jenkins jobs to include
+    /// The full name of a job, multibranch pipeline or folder (i.e. 'folder/job')
+    pub jobs: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub enum JenkinsCredentials {
+    /// # Anonymous
+    /// Use anonymous authentication
+    #[serde(rename = "anonymous")]
+    Anonymous,
+    /// # Basic authentication
+    /// Authenticate using a username and API token
+    #[serde(rename = "basic")]
+    Basic {
+        /// # The username to use
+        username: String,
+        /// # The API token to use
+        token: String,
+    },
 }
 
 ///////////////////////////////////////////////////////////
@@ -383,22 +994,41 @@ pub struct OctopusDeployConfiguration {
     /// # Determines whether or not this collector is enabled
     #[serde(default)]
     pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
     /// # The Octopus Deploy server URL
     #[serde(rename = "serverUrl")]
     pub server_url: String,
     /// # The Octopus Deploy credentials
     pub credentials: OctopusDeployCredentials,
+    /// # The Octopus Deploy space
+    /// The ID, name or slug of the space. Uses the default space if omitted.
+    #[serde(default)]
+    pub space: Option<String>,
     /// # The Octopus Deploy projects to include
     pub projects: Vec<OctopusDeployProject>,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Clone)]
 pub struct OctopusDeployProject {
-    /// # The Octopus Deploy project ID
-    #[serde(rename = "projectId")]
+    /// # The Octopus Deploy project
+    /// The ID, name or slug of the project
+    #[serde(rename = "projectId", alias = "project")]
     pub project_id: String,
-    /// # The Octopus Deploy environment IDs within the project
+    /// # The Octopus Deploy environments within the project
+    /// The IDs or names of the environments
     pub environments: Vec<String>,
+    /// # The Octopus Deploy tenants to include
+    /// The IDs or names of the tenants. Every tenant
+    /// with a deployment is included if omitted.
+    #[serde(default)]
+    pub tenants: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Clone)]
@@ -409,6 +1039,70 @@ pub enum OctopusDeployCredentials {
     ApiKey(String),
 }
 
+///////////////////////////////////////////////////////////
+// Argo CD
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct ArgoCDConfiguration {
+    /// # The Argo CD collector ID
+    pub id: String,
+    /// # Determines whether or not this collector is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
+    /// # The Argo CD API server URL
+    #[serde(rename = "serverUrl")]
+    pub server_url: String,
+    /// # The Argo CD credentials
+    pub credentials: ArgoCDCredentials,
+    /// # The Argo CD applications to include
+    /// If omitted, all applications visible to the token will be included
+    #[serde(default)]
+    pub applications: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub enum ArgoCDCredentials {
+    /// # Bearer token
+    /// Authenticate using an Argo CD account or project token
+    #[serde(rename = "token")]
+    Token(String),
+}
+
+///////////////////////////////////////////////////////////
+// Webhook
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct WebhookConfiguration {
+    /// # The webhook collector ID
+    /// Builds are pushed to /api/ingest/{id}
+    pub id: String,
+    /// # Determines whether or not this collector is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The webhook credentials
+    pub credentials: WebhookCredentials,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub enum WebhookCredentials {
+    /// # Shared secret
+    /// Requests must include the secret as a bearer token in the Authorization header
+    #[serde(rename = "secret")]
+    Secret(String),
+    /// # HMAC signature
+    /// Requests must include a HMAC-SHA256 signature of the body in the X-Duck-Signature-256 header
+    #[serde(rename = "hmac")]
+    Hmac(String),
+}
+
 ///////////////////////////////////////////////////////////
 // Debugger
 
@@ -419,6 +1113,14 @@ pub struct DebuggerConfiguration {
     /// # Determines whether or not this collector is enabled
     #[serde(default)]
     pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
     /// # The Duck debugger URL
     #[serde(rename = "serverUrl")]
     pub server_url: String,
@@ -434,6 +1136,14 @@ pub struct DuckConfiguration {
     /// # Determines whether or not this collector is enabled
     #[serde(default)]
     pub enabled: Option<bool>,
+    /// # The update interval in seconds
+    /// Overrides the global update interval for this collector
+    #[serde(default)]
+    pub interval: Option<u16>,
+    /// # The collection timeout in seconds
+    /// The maximum time a collection may take before it is aborted
+    #[serde(default)]
+    pub timeout: Option<u16>,
     /// # The Duck server URL
     #[serde(rename = "serverUrl")]
     pub server_url: String,
@@ -528,6 +1238,364 @@ pub enum MattermostCredentials {
     Webhook { url: String },
 }
 
+///////////////////////////////////////////////////////////
+// Microsoft Teams
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct TeamsConfiguration {
+    /// # The Microsoft Teams observer ID
+    pub id: String,
+    /// # Determines whether or not this observer is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The collectors to include events from
+    #[serde(default)]
+    pub collectors: Option<Vec<String>>,
+    /// # The Microsoft Teams credentials
+    pub credentials: TeamsCredentials,
+    /// # An optional filter expression
+    pub filter: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub enum TeamsCredentials {
+    /// # Webhook
+    /// Send messages to an incoming webhook or a Workflows URL
+    #[serde(rename = "webhook")]
+    Webhook { url: String },
+}
+
+///////////////////////////////////////////////////////////
+// Discord
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DiscordConfiguration {
+    /// # The Discord observer ID
+    pub id: String,
+    /// # Determines whether or not this observer is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The collectors to include events from
+    #[serde(default)]
+    pub collectors: Option<Vec<String>>,
+    /// # The Discord credentials
+    pub credentials: DiscordCredentials,
+    /// # An optional filter expression
+    pub filter: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub enum DiscordCredentials {
+    /// # Webhook
+    /// Send messages directly to a webhook
+    #[serde(rename = "webhook")]
+    Webhook { url: String },
+}
+
+///////////////////////////////////////////////////////////
+// Webhook observer
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct WebhookObserverConfiguration {
+    /// # The webhook observer ID
+    pub id: String,
+    /// # Determines whether or not this observer is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The collectors to include events from
+    #[serde(default)]
+    pub collectors: Option<Vec<String>>,
+    /// # The URL to send requests to
+    pub url: String,
+    /// # The HTTP method to use
+    /// Defaults to POST
+    #[serde(default)]
+    pub method: Option<WebhookMethod>,
+    /// # Additional HTTP headers
+    #[serde(default)]
+    pub headers: Option<HashMap<String, String>>,
+    /// # The events to send requests for
+    /// Defaults to all events
+    #[serde(default)]
+    pub events: Option<Vec<WebhookEvent>>,
+    /// # The body template
+    /// Placeholders such as {{branch}} or {{status}} are replaced with
+    /// values from the observation. A JSON body is sent if omitted.
+    #[serde(default)]
+    pub template: Option<String>,
+    /// # The HMAC secret
+    /// Signs the body using HMAC-SHA256 and sends the signature
+    /// in the X-Duck-Signature-256 header
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// # An optional filter expression
+    pub filter: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub enum WebhookMethod {
+    #[serde(rename = "POST", alias = "post")]
+    Post,
+    #[serde(rename = "PUT", alias = "put")]
+    Put,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub enum WebhookEvent {
+    /// # The overall Duck status changed
+    #[serde(rename = "duckStatusChanged")]
+    DuckStatusChanged,
+    /// # A build was updated
+    #[serde(rename = "buildUpdated")]
+    BuildUpdated,
+    /// # The status of a build changed
+    #[serde(rename = "buildStatusChanged")]
+    BuildStatusChanged,
+    /// # Duck is shutting down
+    #[serde(rename = "shuttingDown")]
+    ShuttingDown,
+}
+
+///////////////////////////////////////////////////////////
+// Email
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct EmailConfiguration {
+    /// # The email observer ID
+    pub id: String,
+    /// # Determines whether or not this observer is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The collectors to include events from
+    #[serde(default)]
+    pub collectors: Option<Vec<String>>,
+    /// # The SMTP server host
+    pub host: String,
+    /// # The SMTP server port
+    /// Defaults to 587 when using STARTTLS, otherwise 25
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// # Determines whether or not to use STARTTLS
+    /// Defaults to true
+    #[serde(default)]
+    pub starttls: Option<bool>,
+    /// # The SMTP credentials
+    /// Requires STARTTLS
+    #[serde(default)]
+    pub credentials: Option<EmailCredentials>,
+    /// # The sender address
+    pub from: String,
+    /// # The recipient addresses
+    pub to: Vec<String>,
+    /// # The digest window (in minutes)
+    /// Batches all changes within the window into a single email
+    #[serde(default)]
+    pub digest: Option<u32>,
+    /// # An optional filter expression
+    pub filter: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub enum EmailCredentials {
+    /// # Basic authentication
+    /// Authenticate using AUTH PLAIN
+    #[serde(rename = "basic")]
+    Basic {
+        /// # The username to use
+        username: String,
+        /// # The password to use
+        password: String,
+    },
+}
+
+impl EmailConfiguration {
+    pub fn get_port(&self) -> u16 {
+        match self.port {
+            Some(port) => port,
+            None => {
+                if self.starttls.unwrap_or(true) {
+                    587
+                } else {
+                    25
+                }
+            }
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////
+// MQTT
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct MqttConfiguration {
+    /// # The MQTT observer ID
+    pub id: String,
+    /// # Determines whether or not this observer is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The collectors to include events from
+    #[serde(default)]
+    pub collectors: Option<Vec<String>>,
+    /// # The MQTT broker host
+    pub host: String,
+    /// # The MQTT broker port
+    /// Defaults to 8883 when using TLS, otherwise 1883
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// # Determines whether or not to connect using TLS
+    /// Defaults to false
+    #[serde(default)]
+    pub tls: Option<bool>,
+    /// # The MQTT client ID
+    /// Defaults to duck-{id}
+    #[serde(default, rename = "clientId")]
+    pub client_id: Option<String>,
+    /// # The MQTT credentials
+    #[serde(default)]
+    pub credentials: Option<MqttCredentials>,
+    /// # The topic to publish the overall status to
+    /// Defaults to duck/status
+    #[serde(default, rename = "statusTopic")]
+    pub status_topic: Option<String>,
+    /// # The topic to publish build status changes below
+    /// Defaults to duck/builds
+    #[serde(default, rename = "buildTopic")]
+    pub build_topic: Option<String>,
+    /// # The quality of service (0, 1 or 2)
+    /// Defaults to 0
+    #[serde(default)]
+    pub qos: Option<u8>,
+    /// # Determines whether or not messages are retained by the broker
+    /// Defaults to true
+    #[serde(default)]
+    pub retain: Option<bool>,
+    /// # An optional filter expression
+    pub filter: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub enum MqttCredentials {
+    /// # Basic authentication
+    /// Authenticate using a username and password
+    #[serde(rename = "basic")]
+    Basic {
+        /// # The username to use
+        username: String,
+        /// # The password to use
+        password: String,
+    },
+}
+
+impl MqttConfiguration {
+    pub fn get_port(&self) -> u16 {
+        match self.port {
+            Some(port) => port,
+            None => {
+                if self.tls.unwrap_or(false) {
+                    8883
+                } else {
+                    1883
+                }
+            }
+        }
+    }
+
+    pub fn get_client_id(&self) -> String {
+        match &self.client_id {
+            Some(client_id) => client_id.clone(),
+            None => format!("duck-{}", self.id),
+        }
+    }
+
+    pub fn get_status_topic(&self) -> &str {
+        self.status_topic.as_deref().unwrap_or("duck/status")
+    }
+
+    pub fn get_build_topic(&self) -> &str {
+        self.build_topic.as_deref().unwrap_or("duck/builds")
+    }
+}
+
+///////////////////////////////////////////////////////////
+// Home Assistant
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct HomeAssistantConfiguration {
+    /// # The Home Assistant observer ID
+    pub id: String,
+    /// # Determines whether or not this observer is enabled
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// # The collectors to include events from
+    #[serde(default)]
+    pub collectors: Option<Vec<String>>,
+    /// # The Home Assistant server URL
+    #[serde(rename = "serverUrl")]
+    pub server_url: String,
+    /// # A long-lived access token
+    pub token: String,
+    /// # The light entities that should be controlled by this observer
+    #[serde(default)]
+    pub lights: Option<Vec<String>>,
+    /// # The brightness of the lights
+    #[serde(default)]
+    pub brightness: Option<u8>,
+    /// # The actions to take when the overall status changes
+    /// If omitted, the lights turn green, red or blue
+    #[serde(default)]
+    pub actions: Option<HomeAssistantActions>,
+    /// # An optional filter expression
+    pub filter: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Default)]
+pub struct HomeAssistantActions {
+    /// # The action to take on success
+    #[serde(default)]
+    pub success: Option<HomeAssistantAction>,
+    /// # The action to take on failure
+    #[serde(default)]
+    pub failed: Option<HomeAssistantAction>,
+    /// # The action to take when builds are running
+    #[serde(default)]
+    pub running: Option<HomeAssistantAction>,
+    /// # The action to take when builds are canceled
+    #[serde(default)]
+    pub canceled: Option<HomeAssistantAction>,
+    /// # The action to take when builds are queued
+    #[serde(default)]
+    pub queued: Option<HomeAssistantAction>,
+    /// # The action to take when builds are blocked
+    #[serde(default)]
+    pub blocked: Option<HomeAssistantAction>,
+    /// # The action to take when builds are skipped
+    #[serde(default)]
+    pub skipped: Option<HomeAssistantAction>,
+    /// # The action to take when the status is unknown
+    #[serde(default)]
+    pub unknown: Option<HomeAssistantAction>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub enum HomeAssistantAction {
+    /// # Color
+    /// Turn on the lights using an RGB color
+    #[serde(rename = "color")]
+    Color([u8; 3]),
+    /// # Off
+    /// Turn off the lights
+    #[serde(rename = "off")]
+    Off,
+    /// # Scene
+    /// Activate a scene
+    #[serde(rename = "scene")]
+    Scene(String),
+    /// # Event
+    /// Fire an event of the specified type
+    #[serde(rename = "event")]
+    Event(String),
+}
+
 ///////////////////////////////////////////////////////////
 // Default values
 
@@ -538,3 +1606,131 @@ fn default_title() -> String {
 fn default_interval() -> u16 {
     15
 }
+
+fn default_timeout() -> u16 {
+    60
+}
+
+fn default_github_webhook_interval() -> u16 {
+    300
+}
+
+fn default_retention() -> u16 {
+    30
+}
+
+///////////////////////////////////////////////////////////
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::text::TestVariableProvider;
+
+    static CONFIGURATION: &str = r#"
+    {
+        "interval": 30,
+        "collectors": [
+            {
+                "duck": {
+                    "id": "global",
+                    "serverUrl": "http://localhost:15825"
+                }
+            },
+            {
+                "duck": {
+                    "id": "specific",
+                    "interval": 120,
+                    "timeout": 10,
+                    "serverUrl": "http://localhost:15826"
+                }
+            },
+            {
+                "github": {
+                    "id": "github",
+                    "owner": "duckhq",
+                    "repository": "duck",
+                    "workflow": "ci.yml",
+                    "credentials": {
+                        "token": "SECRET"
+                    },
+                    "webhookSecret": "SECRET"
+                }
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn should_use_global_interval_if_collector_does_not_specify_one() {
+        // Given
+        let config = Configuration::from_json(&TestVariableProvider::new(), CONFIGURATION).unwrap();
+
+        // When
+        let interval = config.get_collector_interval("global");
+
+        // Then
+        assert_eq!(30, interval);
+    }
+
+    #[test]
+    fn should_use_collector_interval_if_specified() {
+        // Given
+        let config = Configuration::from_json(&TestVariableProvider::new(), CONFIGURATION).unwrap();
+
+        // When
+        let interval = config.get_collector_interval("specific");
+
+        // Then
+        assert_eq!(120, interval);
+    }
+
+    #[test]
+    fn should_poll_github_less_often_if_webhook_is_configured() {
+        // Given
+        let config = Configuration::from_json(&TestVariableProvider::new(), CONFIGURATION).unwrap();
+
+        // When
+        let interval = config.get_collector_interval("github");
+
+        // Then
+        assert_eq!(300, interval);
+    }
+
+    #[test]
+    fn should_use_default_timeout_if_collector_does_not_specify_one() {
+        // Given
+        let config = Configuration::from_json(&TestVariableProvider::new(), CONFIGURATION).unwrap();
+
+        // When
+        let timeout = config.get_collector_timeout("global");
+
+        // Then
+        assert_eq!(60, timeout);
+    }
+
+    #[test]
+    fn should_use_collector_timeout_if_specified() {
+        // Given
+        let config = Configuration::from_json(&TestVariableProvider::new(), CONFIGURATION).unwrap();
+
+        // When
+        let timeout = config.get_collector_timeout("specific");
+
+        // Then
+        assert_eq!(10, timeout);
+    }
+
+    #[test]
+    fn should_never_return_an_interval_of_zero() {
+        // Given
+        let mut config =
+            Configuration::from_json(&TestVariableProvider::new(), CONFIGURATION).unwrap();
+        config.interval = 0;
+
+        // When
+        let interval = config.get_collector_interval("global");
+
+        // Then
+        assert_eq!(1, interval);
+    }
+}
@@ -34,6 +34,52 @@ mod tests {
                     "definitions": [ "${AZURE_DEF}_1", "${AZURE_DEF}_2" ]
                 }
             },
+            {
+                "bitbucket": {
+                    "id": "${BITBUCKET_ID}",
+                    "workspace": "${BITBUCKET_WORKSPACE}",
+                    "repository": "${BITBUCKET_REPOSITORY}",
+                    "credentials": {
+                        "appPassword": {
+                            "username": "${BITBUCKET_USERNAME}",
+                            "password": "${BITBUCKET_PASSWORD}"
+                        }
+                    },
+                    "branches": [ "${BITBUCKET_BRANCH}_1", "${BITBUCKET_BRANCH}_2" ]
+                }
+            },
+            {
+                "buildkite": {
+                    "id": "${BUILDKITE_ID}",
+                    "organization": "${BUILDKITE_ORG}",
+                    "pipelines": [ "${BUILDKITE_PIPELINE}_1", "${BUILDKITE_PIPELINE}_2" ],
+                    "credentials": {
+                        "token": "${BUILDKITE_TOKEN}"
+                    }
+                }
+            },
+            {
+                "circleci": {
+                    "id": "${CIRCLECI_ID}",
+                    "project": "${CIRCLECI_PROJECT}",
+                    "credentials": {
+                        "token": "${CIRCLECI_TOKEN}"
+                    },
+                    "branches": [ "${CIRCLECI_BRANCH}_1", "${CIRCLECI_BRANCH}_2" ]
+                }
+            },
+            {
+                "drone": {
+                    "id": "${DRONE_ID}",
+                    "serverUrl": "${DRONE_SERVER}",
+                    "owner": "${DRONE_OWNER}",
+                    "repository": "${DRONE_REPOSITORY}",
+                    "credentials": {
+                        "token": "${DRONE_TOKEN}"
+                    },
+                    "branches": [ "${DRONE_BRANCH}_1", "${DRONE_BRANCH}_2" ]
+                }
+            },
             {
                 "github": {
                     "id": "${GITHUB_ID}",
@@ -45,7 +91,33 @@ mod tests {
                             "username": "${GITHUB_USERNAME}",
                             "password": "${GITHUB_PASSWORD}"
                         }
-                    }
+                    },
+                    "webhookSecret": "${GITHUB_WEBHOOK_SECRET}"
+                }
+            },
+            {
+                "gitlab": {
+                    "id": "${GITLAB_ID}",
+                    "serverUrl": "https://${GITLAB_HOST}",
+                    "project": "${GITLAB_PROJECT}",
+                    "credentials": {
+                        "privateToken": "${GITLAB_TOKEN}"
+                    },
+                    "branches": [ "${GITLAB_BRANCH}_1", "${GITLAB_BRANCH}_2" ],
+                    "sources": [ "${GITLAB_SOURCE}" ]
+                }
+            },
+            {
+                "jenkins": {
+                    "id": "${JENKINS_ID}",
+                    "serverUrl": "https://${JENKINS_HOST}",
+                    "credentials": {
+                        "basic": {
+                            "username": "${JENKINS_USERNAME}",
+                            "token": "${JENKINS_TOKEN}"
+                        }
+                    },
+                    "jobs": [ "${JENKINS_FOLDER}/job_1", "${JENKINS_FOLDER}/job_2" ]
                 }
             },
             {
@@ -66,6 +138,16 @@ mod tests {
                     ]
                 }
             },
+            {
+                "argocd": {
+                    "id": "${ARGOCD_ID}",
+                    "serverUrl": "${ARGOCD_SERVER}",
+                    "credentials": {
+                        "token": "${ARGOCD_TOKEN}"
+                    },
+                    "applications": [ "${ARGOCD_APPLICATION}_1", "${ARGOCD_APPLICATION}_2" ]
+                }
+            },
             {
                 "appveyor": {
                     "id": "${APPVEYOR_ID}",
@@ -77,6 +159,14 @@ mod tests {
                     "count": ${APPVEYOR_COUNT}
                 }
             },
+            {
+                "webhook": {
+                    "id": "${WEBHOOK_ID}",
+                    "credentials": {
+                        "hmac": "${WEBHOOK_SECRET}"
+                    }
+                }
+            },
             {
                 "duck": {
                     "id": "${DUCK_ID}",
@@ -117,6 +207,74 @@ mod tests {
                         }
                     }
                 }
+            },
+            {
+                "teams": {
+                    "id": "${TEAMS_ID}",
+                    "credentials": {
+                        "webhook": {
+                            "url": "${TEAMS_WEBHOOK_URL}"
+                        }
+                    }
+                }
+            },
+            {
+                "discord": {
+                    "id": "${DISCORD_ID}",
+                    "credentials": {
+                        "webhook": {
+                            "url": "${DISCORD_WEBHOOK_URL}"
+                        }
+                    }
+                }
+            },
+            {
+                "webhook": {
+                    "id": "${WEBHOOK_OBSERVER_ID}",
+                    "url": "${WEBHOOK_OBSERVER_URL}",
+                    "headers": {
+                        "Authorization": "Bearer ${WEBHOOK_OBSERVER_TOKEN}"
+                    },
+                    "secret": "${WEBHOOK_OBSERVER_SECRET}"
+                }
+            },
+            {
+                "email": {
+                    "id": "${EMAIL_ID}",
+                    "host": "${SMTP_HOST}",
+                    "credentials": {
+                        "basic": {
+                            "username": "${SMTP_USERNAME}",
+                            "password": "${SMTP_PASSWORD}"
+                        }
+                    },
+                    "from": "${EMAIL_FROM}",
+                    "to": [ "${EMAIL_TO}" ]
+                }
+            },
+            {
+                "mqtt": {
+                    "id": "${MQTT_ID}",
+                    "host": "${MQTT_HOST}",
+                    "credentials": {
+                        "basic": {
+                            "username": "${MQTT_USERNAME}",
+                            "password": "${MQTT_PASSWORD}"
+                        }
+                    },
+                    "statusTopic": "${MQTT_TOPIC}/status"
+                }
+            },
+            {
+                "homeassistant": {
+                    "id": "${HOMEASSISTANT_ID}",
+                    "serverUrl": "${HOMEASSISTANT_URL}",
+                    "token": "${HOMEASSISTANT_TOKEN}",
+                    "lights": [ "${HOMEASSISTANT_LIGHT}" ],
+                    "actions": {
+                        "failed": { "scene": "${HOMEASSISTANT_SCENE}" }
+                    }
+                }
             }
         ]
     }
@@ -153,23 +311,61 @@ mod tests {
         variables.add("AZURE_PAT", "SECRET-PAT-TOKEN");
         variables.add("AZURE_BRANCH", "MyBranch");
         variables.add("AZURE_DEF", "MyDefinition");
+        variables.add("BITBUCKET_ID", "bitbucket");
+        variables.add("BITBUCKET_WORKSPACE", "duckhq");
+        variables.add("BITBUCKET_REPOSITORY", "duck");
+        variables.add("BITBUCKET_USERNAME", "patrik");
+        variables.add("BITBUCKET_PASSWORD", "SECRET-APP-PASSWORD");
+        variables.add("BITBUCKET_BRANCH", "MyBranch");
+        variables.add("BUILDKITE_ID", "buildkite");
+        variables.add("BUILDKITE_ORG", "duckhq");
+        variables.add("BUILDKITE_PIPELINE", "MyPipeline");
+        variables.add("BUILDKITE_TOKEN", "SECRET-TOKEN");
+        variables.add("CIRCLECI_ID", "circleci");
+        variables.add("CIRCLECI_PROJECT", "gh/duckhq/duck");
+        variables.add("CIRCLECI_TOKEN", "SECRET-TOKEN");
+        variables.add("CIRCLECI_BRANCH", "MyBranch");
+        variables.add("DRONE_ID", "drone");
+        variables.add("DRONE_SERVER", "https://drone.example.com");
+        variables.add("DRONE_OWNER", "duckhq");
+        variables.add("DRONE_REPOSITORY", "duck");
+        variables.add("DRONE_TOKEN", "SECRET-TOKEN");
+        variables.add("DRONE_BRANCH", "MyBranch");
         variables.add("GITHUB_ID", "github");
         variables.add("GITHUB_OWNER", "spectresystems");
         variables.add("GITHUB_REPOSITORY", "duck");
         variables.add("GITHUB_WORKFLOW", "workflow.yml");
         variables.add("GITHUB_USERNAME", "patrik");
         variables.add("GITHUB_PASSWORD", "hunter1!");
+        variables.add("GITHUB_WEBHOOK_SECRET", "SECRET-WEBHOOK");
+        variables.add("GITLAB_ID", "gitlab");
+        variables.add("GITLAB_HOST", "gitlab.example.com");
+        variables.add("GITLAB_PROJECT", "duckhq/duck");
+        variables.add("GITLAB_TOKEN", "SECRET-GITLAB-TOKEN");
+        variables.add("GITLAB_BRANCH", "MyBranch");
+        variables.add("GITLAB_SOURCE", "push");
+        variables.add("JENKINS_ID", "jenkins");
+        variables.add("JENKINS_HOST", "jenkins.example.com");
+        variables.add("JENKINS_USERNAME", "patrik");
+        variables.add("JENKINS_TOKEN", "SECRET-JENKINS-TOKEN");
+        variables.add("JENKINS_FOLDER", "MyFolder");
         variables.add("OCTOPUS_ID", "octopus");
         variables.add("OCTOPUS_HOST", "localhost");
         variables.add("OCTOPUS_PORT", "9000");
         variables.add("OCTOPUS_PROJECT_PREFIX", "Projects");
         variables.add("OCTOPUS_ENVIRONMENT_PREFIX", "Environments");
         variables.add("OCTOPUS_API_KEY", "SECRET-API-KEY");
+        variables.add("ARGOCD_ID", "argocd");
+        variables.add("ARGOCD_SERVER", "https://argocd.example.com");
+        variables.add("ARGOCD_TOKEN", "SECRET-TOKEN");
+        variables.add("ARGOCD_APPLICATION", "MyApplication");
         variables.add("APPVEYOR_ID", "appveyor");
         variables.add("APPVEYOR_BEARER_TOKEN", "SECRET-APPVEYOR-TOKEN");
         variables.add("APPVEYOR_ACCOUNT", "patriksvensson");
         variables.add("APPVEYOR_PROJECT", "spectre-commandline");
         variables.add("APPVEYOR_COUNT", "4");
+        variables.add("WEBHOOK_ID", "webhook");
+        variables.add("WEBHOOK_SECRET", "SECRET-WEBHOOK");
         variables.add("DUCK_ID", "duck_localhost");
         variables.add("DUCK_SERVER_URL", "http://localhost:15825");
         variables.add("HUE_ID", "hue");
@@ -182,6 +378,30 @@ mod tests {
         variables.add("MATTERMOST_ID", "mattermost");
         variables.add("MATTERMOST_CHANNEL", "some-channel");
         variables.add("MATTERMOST_WEBHOOK_URL", "https://example.com/mattermost");
+        variables.add("TEAMS_ID", "teams");
+        variables.add("TEAMS_WEBHOOK_URL", "https://example.com/teams");
+        variables.add("DISCORD_ID", "discord");
+        variables.add("DISCORD_WEBHOOK_URL", "https://example.com/discord");
+        variables.add("WEBHOOK_OBSERVER_ID", "webhook_observer");
+        variables.add("WEBHOOK_OBSERVER_URL", "https://example.com/webhook");
+        variables.add("WEBHOOK_OBSERVER_TOKEN", "SECRET-TOKEN");
+        variables.add("WEBHOOK_OBSERVER_SECRET", "SECRET-HMAC");
+        variables.add("EMAIL_ID", "email");
+        variables.add("SMTP_HOST", "smtp.example.com");
+        variables.add("SMTP_USERNAME", "duck");
+        variables.add("SMTP_PASSWORD", "SECRET-SMTP");
+        variables.add("EMAIL_FROM", "duck@example.com");
+        variables.add("EMAIL_TO", "managers@example.com");
+        variables.add("MQTT_ID", "mqtt");
+        variables.add("MQTT_HOST", "mqtt.example.com");
+        variables.add("MQTT_USERNAME", "duck");
+        variables.add("MQTT_PASSWORD", "SECRET-MQTT");
+        variables.add("MQTT_TOPIC", "office");
+        variables.add("HOMEASSISTANT_ID", "homeassistant");
+        variables.add("HOMEASSISTANT_URL", "http://homeassistant.local:8123");
+        variables.add("HOMEASSISTANT_TOKEN", "SECRET-HOMEASSISTANT");
+        variables.add("HOMEASSISTANT_LIGHT", "light.office");
+        variables.add("HOMEASSISTANT_SCENE", "scene.red_alert");
         return variables;
     }
 
@@ -220,6 +440,74 @@ mod tests {
         assert_eq!("SECRET-PAT-TOKEN", azure.get_pat());
     }
 
+    #[test]
+    fn should_expand_bitbucket_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let bitbucket = find_config!(config.collectors, CollectorConfiguration::Bitbucket);
+        let (username, password) = bitbucket.get_app_password();
+        let branches = bitbucket.branches.as_ref().unwrap();
+
+        assert_eq!("bitbucket", bitbucket.id);
+        assert_eq!("duckhq", bitbucket.workspace);
+        assert_eq!("duck", bitbucket.repository);
+        assert_eq!("MyBranch_1", branches[0]);
+        assert_eq!("MyBranch_2", branches[1]);
+        assert_eq!("patrik", username);
+        assert_eq!("SECRET-APP-PASSWORD", password);
+    }
+
+    #[test]
+    fn should_expand_buildkite_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let buildkite = find_config!(config.collectors, CollectorConfiguration::Buildkite);
+
+        assert_eq!("buildkite", buildkite.id);
+        assert_eq!("duckhq", buildkite.organization);
+        assert_eq!("MyPipeline_1", buildkite.pipelines[0]);
+        assert_eq!("MyPipeline_2", buildkite.pipelines[1]);
+        assert_eq!("SECRET-TOKEN", buildkite.get_token());
+    }
+
+    #[test]
+    fn should_expand_circleci_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let circleci = find_config!(config.collectors, CollectorConfiguration::CircleCI);
+        let branches = circleci.branches.as_ref().unwrap();
+
+        assert_eq!("circleci", circleci.id);
+        assert_eq!("gh/duckhq/duck", circleci.project);
+        assert_eq!("MyBranch_1", branches[0]);
+        assert_eq!("MyBranch_2", branches[1]);
+        assert_eq!("SECRET-TOKEN", circleci.get_token());
+    }
+
+    #[test]
+    fn should_expand_drone_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let drone = find_config!(config.collectors, CollectorConfiguration::Drone);
+        let branches = drone.branches.as_ref().unwrap();
+
+        assert_eq!("drone", drone.id);
+        assert_eq!("https://drone.example.com", drone.server_url);
+        assert_eq!("duckhq", drone.owner);
+        assert_eq!("duck", drone.repository);
+        assert_eq!("MyBranch_1", branches[0]);
+        assert_eq!("MyBranch_2", branches[1]);
+        assert_eq!("SECRET-TOKEN", drone.get_token());
+    }
+
     #[test]
     fn should_expand_github_configuration() {
         // Given, When
@@ -232,10 +520,48 @@ mod tests {
         assert_eq!("github", github.id);
         assert_eq!("spectresystems", github.owner);
         assert_eq!("duck", github.repository);
-        assert_eq!("workflow.yml", github.workflow);
-        assert_eq!("workflow.yml", github.workflow);
+        assert_eq!("workflow.yml", github.workflow.as_ref().unwrap());
         assert_eq!("patrik", username);
         assert_eq!("hunter1!", password);
+        assert_eq!("SECRET-WEBHOOK", github.webhook_secret.as_ref().unwrap());
+    }
+
+    #[test]
+    fn should_expand_gitlab_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let gitlab = find_config!(config.collectors, CollectorConfiguration::GitLab);
+        let branches = gitlab.branches.as_ref().unwrap();
+
+        assert_eq!("gitlab", gitlab.id);
+        assert_eq!(
+            "https://gitlab.example.com",
+            gitlab.server_url.as_ref().unwrap()
+        );
+        assert_eq!("duckhq/duck", gitlab.project);
+        assert_eq!("MyBranch_1", branches[0]);
+        assert_eq!("MyBranch_2", branches[1]);
+        assert_eq!("push", gitlab.sources.as_ref().unwrap()[0]);
+        assert_eq!("SECRET-GITLAB-TOKEN", gitlab.get_private_token());
+    }
+
+    #[test]
+    fn should_expand_jenkins_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let jenkins = find_config!(config.collectors, CollectorConfiguration::Jenkins);
+        let (username, token) = jenkins.get_credentials();
+
+        assert_eq!("jenkins", jenkins.id);
+        assert_eq!("https://jenkins.example.com", jenkins.server_url);
+        assert_eq!("MyFolder/job_1", jenkins.jobs[0]);
+        assert_eq!("MyFolder/job_2", jenkins.jobs[1]);
+        assert_eq!("patrik", username);
+        assert_eq!("SECRET-JENKINS-TOKEN", token);
     }
 
     #[test]
@@ -254,6 +580,22 @@ mod tests {
         assert_eq!("Environments-2", octopus.projects[0].environments[1]);
     }
 
+    #[test]
+    fn should_expand_argocd_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let argocd = find_config!(config.collectors, CollectorConfiguration::ArgoCD);
+        let applications = argocd.applications.as_ref().unwrap();
+
+        assert_eq!("argocd", argocd.id);
+        assert_eq!("https://argocd.example.com", argocd.server_url);
+        assert_eq!("MyApplication_1", applications[0]);
+        assert_eq!("MyApplication_2", applications[1]);
+        assert_eq!("SECRET-TOKEN", argocd.get_token());
+    }
+
     #[test]
     fn should_expand_appveyor_configuration() {
         // Given, When
@@ -264,11 +606,23 @@ mod tests {
 
         assert_eq!("appveyor", appveyor.id);
         assert_eq!("patriksvensson", appveyor.account);
-        assert_eq!("spectre-commandline", appveyor.project);
+        assert_eq!("spectre-commandline", appveyor.project.as_ref().unwrap());
         assert_eq!("SECRET-APPVEYOR-TOKEN", appveyor.get_bearer_token());
         assert_eq!(4, appveyor.get_count());
     }
 
+    #[test]
+    fn should_expand_webhook_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let webhook = find_config!(config.collectors, CollectorConfiguration::Webhook);
+
+        assert_eq!("webhook", webhook.id);
+        assert_eq!("SECRET-WEBHOOK", webhook.get_secret());
+    }
+
     #[test]
     fn should_expand_duck_configuration() {
         // Given, When
@@ -327,6 +681,100 @@ mod tests {
             mattermost.get_webhook_url()
         );
     }
+
+    #[test]
+    fn should_expand_teams_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let observers = config.observers.as_ref().unwrap();
+        let teams = find_config!(observers, ObserverConfiguration::Teams);
+
+        assert_eq!("teams", teams.id);
+        assert_eq!("https://example.com/teams", teams.get_webhook_url());
+    }
+
+    #[test]
+    fn should_expand_discord_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let observers = config.observers.as_ref().unwrap();
+        let discord = find_config!(observers, ObserverConfiguration::Discord);
+
+        assert_eq!("discord", discord.id);
+        assert_eq!("https://example.com/discord", discord.get_webhook_url());
+    }
+
+    #[test]
+    fn should_expand_webhook_observer_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let observers = config.observers.as_ref().unwrap();
+        let webhook = find_config!(observers, ObserverConfiguration::Webhook);
+
+        assert_eq!("webhook_observer", webhook.id);
+        assert_eq!("https://example.com/webhook", webhook.url);
+        assert_eq!(
+            "Bearer SECRET-TOKEN",
+            webhook.headers.as_ref().unwrap()["Authorization"]
+        );
+        assert_eq!("SECRET-HMAC", webhook.secret.as_ref().unwrap());
+    }
+
+    #[test]
+    fn should_expand_email_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let observers = config.observers.as_ref().unwrap();
+        let email = find_config!(observers, ObserverConfiguration::Email);
+
+        assert_eq!("email", email.id);
+        assert_eq!("smtp.example.com", email.host);
+        assert_eq!(("duck", "SECRET-SMTP"), email.get_credentials());
+        assert_eq!("duck@example.com", email.from);
+        assert_eq!(vec!["managers@example.com"], email.to);
+    }
+
+    #[test]
+    fn should_expand_mqtt_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let observers = config.observers.as_ref().unwrap();
+        let mqtt = find_config!(observers, ObserverConfiguration::Mqtt);
+
+        assert_eq!("mqtt", mqtt.id);
+        assert_eq!("mqtt.example.com", mqtt.host);
+        assert_eq!(("duck", "SECRET-MQTT"), mqtt.get_credentials());
+        assert_eq!("office/status", mqtt.get_status_topic());
+    }
+
+    #[test]
+    fn should_expand_homeassistant_configuration() {
+        // Given, When
+        let config = read_config!(CONFIGURATION);
+
+        // Then
+        let observers = config.observers.as_ref().unwrap();
+        let homeassistant = find_config!(observers, ObserverConfiguration::HomeAssistant);
+
+        assert_eq!("homeassistant", homeassistant.id);
+        assert_eq!("http://homeassistant.local:8123", homeassistant.server_url);
+        assert_eq!("SECRET-HOMEASSISTANT", homeassistant.token);
+        assert_eq!("light.office", homeassistant.lights.as_ref().unwrap()[0]);
+        assert_eq!(
+            Some(&HomeAssistantAction::Scene("scene.red_alert".to_string())),
+            homeassistant.actions.as_ref().unwrap().failed.as_ref()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -338,6 +786,7 @@ mod utilities {
             match &self.credentials {
                 TeamCityAuth::Guest => panic!("TeamCity configuration has guest credentials"),
                 TeamCityAuth::BasicAuth { username, password } => (username, password),
+                TeamCityAuth::Token(_) => panic!("TeamCity configuration has token credentials"),
             }
         }
     }
@@ -353,10 +802,79 @@ mod utilities {
         }
     }
 
+    impl BitbucketConfiguration {
+        pub fn get_app_password(&self) -> (&str, &str) {
+            match &self.credentials {
+                BitbucketCredentials::Anonymous => {
+                    panic!("Bitbucket configuration have anonymous credentials")
+                }
+                BitbucketCredentials::AppPassword { username, password } => (username, password),
+            }
+        }
+    }
+
+    impl BuildkiteConfiguration {
+        pub fn get_token(&self) -> &str {
+            match &self.credentials {
+                BuildkiteCredentials::Token(token) => token,
+            }
+        }
+    }
+
+    impl CircleCIConfiguration {
+        pub fn get_token(&self) -> &str {
+            match &self.credentials {
+                CircleCICredentials::Token(token) => token,
+            }
+        }
+    }
+
+    impl DroneConfiguration {
+        pub fn get_token(&self) -> &str {
+            match &self.credentials {
+                DroneCredentials::Anonymous => {
+                    panic!("Drone configuration have anonymous credentials")
+                }
+                DroneCredentials::Token(token) => token,
+            }
+        }
+    }
+
     impl GitHubConfiguration {
         pub fn get_credentials(&self) -> (&str, &str) {
             match &self.credentials {
                 GitHubCredentials::Basic { username, password } => (username, password),
+                _ => panic!("GitHub configuration does not have basic credentials"),
+            }
+        }
+    }
+
+    impl GitLabConfiguration {
+        pub fn get_private_token(&self) -> &str {
+            match &self.credentials {
+                GitLabCredentials::Anonymous => {
+                    panic!("GitLab configuration have anonymous credentials")
+                }
+                GitLabCredentials::PrivateToken(token) => token,
+            }
+        }
+    }
+
+    impl JenkinsConfiguration {
+        pub fn get_credentials(&self) -> (&str, &str) {
+            match &self.credentials {
+                JenkinsCredentials::Anonymous => {
+                    panic!("Jenkins configuration have anonymous credentials")
+                }
+                JenkinsCredentials::Basic { username, token } => (username, token),
+            }
+        }
+    }
+
+    impl ArgoCDConfiguration {
+        pub fn get_token(&self) -> &str {
+            match &self.credentials {
+                ArgoCDCredentials::Token(token) => token,
             }
         }
     }
@@ -372,11 +890,23 @@ mod utilities {
     impl AppVeyorConfiguration {
         pub fn get_bearer_token(&self) -> &str {
             match &self.credentials {
+                AppVeyorCredentials::Anonymous => {
+                    panic!("AppVeyor configuration have anonymous credentials")
+                }
                 AppVeyorCredentials::Bearer(token) => token,
             }
         }
     }
 
+    impl WebhookConfiguration {
+        pub fn get_secret(&self) -> &str {
+            match &self.credentials {
+                WebhookCredentials::Secret(secret) => secret,
+                WebhookCredentials::Hmac(secret) => secret,
+            }
+        }
+    }
+
     impl SlackConfiguration {
         pub fn get_webhook_url(&self) -> &str {
             match &self.credentials {
@@ -392,4 +922,36 @@ mod utilities {
             }
         }
     }
+
+    impl TeamsConfiguration {
+        pub fn get_webhook_url(&self) -> &str {
+            match &self.credentials {
+                TeamsCredentials::Webhook { url } => url,
+            }
+        }
+    }
+
+    impl EmailConfiguration {
+        pub fn get_credentials(&self) -> (&str, &str) {
+            match self.credentials.as_ref().unwrap() {
+                EmailCredentials::Basic { username, password } => (username, password),
+            }
+        }
+    }
+
+    impl MqttConfiguration {
+        pub fn get_credentials(&self) -> (&str, &str) {
+            match self.credentials.as_ref().unwrap() {
+                MqttCredentials::Basic { username, password } => (username, password),
+            }
+        }
+    }
+
+    impl DiscordConfiguration {
+        pub fn get_webhook_url(&self) -> &str {
+            match &self.credentials {
+                DiscordCredentials::Webhook { url } => url,
+            }
+        }
+    }
 }
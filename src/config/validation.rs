@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 
 use log::warn;
 
-use super::{Configuration, Validate};
+use super::{Configuration, HistoryConfiguration, Validate};
 use crate::DuckResult;
 
 pub const ID_PATTERN: &str = r"^[a-zA-Z0-9_\-.]+$";
@@ -18,6 +18,11 @@ impl Validate for Configuration {
         validate_ids(&self)?;
         validate_collector_references(&self)?;
 
+        // Validate history
+        if let Some(history) = &self.history {
+            history.validate()?;
+        }
+
         // Validate collectors
         for collector in self.collectors.iter() {
             collector.validate()?;
@@ -34,6 +39,15 @@ impl Validate for Configuration {
     }
 }
 
+impl Validate for HistoryConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        if self.path.trim().is_empty() {
+            return Err(format_err!("The history path is empty"));
+        }
+        Ok(())
+    }
+}
+
 fn validate_views(configuration: &Configuration) -> DuckResult<()> {
     let valid_id_pattern = Regex::new(ID_PATTERN)?;
     if let Some(views) = &configuration.views {
@@ -304,4 +318,22 @@ mod tests {
         .unwrap();
         config.validate().unwrap();
     }
+
+    #[test]
+    #[should_panic(expected = "The history path is empty")]
+    fn should_return_error_if_history_path_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            { 
+                "collectors": [ ],
+                "history": {
+                    "path": ""
+                }
+            }
+        "#,
+        )
+        .unwrap();
+        config.validate().unwrap();
+    }
 }
@@ -210,12 +210,15 @@ fn run_accumulator(
         accumulator::accumulate(&mut context);
 
         // Wait for a little while
-        if handle.wait(std::time::Duration::from_secs(15)) {
+        if handle.wait(Duration::from_millis(500)) {
             debug!("The accumulator was instructed to stop");
             break;
         }
     }
 
+    debug!("Stopping collectors");
+    accumulator::stop(&mut context);
+
     debug!("Sending shutdown message");
     match sender.send(EngineEvent::ShuttingDown) {
         Result::Ok(_) => (),
@@ -1,9 +1,11 @@
 use std::collections::HashSet;
 use std::sync::mpsc::{Receiver, Sender};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 
 use log::{debug, error, trace};
-use waithandle::WaitHandleListener;
+use waithandle::{WaitHandleListener, WaitHandleSignaler};
 
 use crate::builds::Build;
 use crate::engine::{EngineEvent, EngineState};
@@ -19,7 +21,8 @@ pub struct Context {
     engine_receiver: Receiver<EngineThreadMessage>,
     state: Arc<EngineState>,
     sender: Sender<EngineEvent>,
-    collectors: Vec<Box<dyn Collector>>,
+    collectors: Vec<ScheduledCollector>,
+    workers: Vec<CollectorWorker>,
 }
 
 impl Context {
@@ -35,63 +38,279 @@ impl Context {
             state,
             sender: accumulator_sender,
             collectors: vec![],
+            workers: vec![],
+        }
+    }
+}
+
+/// A collector together with its polling schedule.
+pub struct ScheduledCollector {
+    collector: Box<dyn Collector>,
+    interval: Duration,
+    timeout: Duration,
+}
+
+impl ScheduledCollector {
+    pub fn new(collector: Box<dyn Collector>, interval: u16, timeout: u16) -> Self {
+        Self {
+            collector,
+            interval: Duration::from_secs(u64::from(interval)),
+            timeout: Duration::from_secs(u64::from(timeout)),
+        }
+    }
+}
+
+/// A thread that periodically collects builds from a single collector.
+struct CollectorWorker {
+    id: String,
+    timeout: Duration,
+    stop: WaitHandleSignaler,
+    wake: WaitHandleSignaler,
+    collection: Arc<Mutex<Option<Collection>>>,
+    thread: JoinHandle<()>,
+}
+
+/// A collection that is currently in progress.
+struct Collection {
+    started: Instant,
+    signaler: WaitHandleSignaler,
+}
+
+impl CollectorWorker {
+    fn start(
+        scheduled: ScheduledCollector,
+        state: Arc<EngineState>,
+        sender: Sender<EngineEvent>,
+    ) -> Self {
+        let (stop, stopping) = waithandle::new();
+        let (wake, waking) = waithandle::new();
+        let id = scheduled.collector.info().id.clone();
+        let timeout = scheduled.timeout;
+        let collection = Arc::new(Mutex::new(None));
+
+        let thread = {
+            let wake = wake.clone();
+            let collection = collection.clone();
+            std::thread::spawn(move || {
+                run_worker(
+                    scheduled,
+                    stopping,
+                    (wake, waking),
+                    collection,
+                    state,
+                    sender,
+                )
+            })
+        };
+
+        CollectorWorker {
+            id,
+            timeout,
+            stop,
+            wake,
+            collection,
+            thread,
+        }
+    }
+
+    /// Aborts the current collection if it has been running
+    /// for longer than the collector's timeout.
+    ///
+    /// The timeout is cooperative. The collector is signalled and
+    /// any builds it reports afterwards are ignored, but a collector
+    /// that is blocked in an HTTP request keeps its thread until the
+    /// request completes or the HTTP client's own timeout expires.
+    fn abort_if_timed_out(&self) {
+        let mut collection = self.collection.lock().unwrap();
+        if let Some(current) = collection.as_ref() {
+            if current.started.elapsed() >= self.timeout {
+                error!(
+                    "Collecting builds from '{}' timed out after {} seconds",
+                    self.id,
+                    self.timeout.as_secs()
+                );
+                current.signaler.signal();
+                *collection = None;
+            }
+        }
+    }
+
+    /// Makes the worker collect builds right away
+    /// instead of waiting for the next interval.
+    fn wake(&self) {
+        trace!("Waking worker for '{}'", self.id);
+        self.wake.signal();
+    }
+
+    /// Stops the worker and aborts any collection in progress.
+    /// This does not wait for the worker thread to finish.
+    fn stop(&self) {
+        self.stop.signal();
+        self.wake.signal();
+        if let Some(current) = self.collection.lock().unwrap().take() {
+            current.signaler.signal();
+        }
+    }
+
+    /// Waits for the worker thread to finish.
+    fn join(self) {
+        if self.thread.join().is_err() {
+            error!("The worker for '{}' panicked", self.id);
         }
     }
 }
 
-#[allow(clippy::borrowed_box)]
 pub fn accumulate(context: &mut Context) {
     if let Err(e) = check_for_updated_configuration(context) {
         error!("{}", e);
         return;
     }
 
-    for collector in context.collectors.iter() {
-        let mut build_hashes = std::collections::HashSet::<u64>::new();
-        if let Err(e) = collector.collect(context.listener.clone(), &mut |build: Build| {
-            build_hashes.insert(build.id);
-            match context.state.builds.update(&build) {
-                BuildUpdateResult::Added | BuildUpdateResult::BuildUpdated => {
-                    // The build was updated
-                    match context
-                        .sender
-                        .send(EngineEvent::BuildUpdated(Box::new(build)))
-                    {
-                        Result::Ok(_) => (),
-                        Result::Err(e) => error!("Failed to send build update event. {}", e),
-                    }
-                }
-                BuildUpdateResult::AbsoluteBuildStatusChanged => {
-                    // The build's status was changed (success->failed or failed->success)
-                    match context
-                        .sender
-                        .send(EngineEvent::AbsoluteBuildStatusChanged(Box::new(build)))
-                    {
-                        Result::Ok(_) => (),
-                        Result::Err(e) => error!("Failed to send build status event. {}", e),
-                    }
-                }
-                _ => {}
-            };
-        }) {
-            // Log the error but continue as normal since
-            // we don't want to retain the builds that we could
-            // not collect information about
-            error!(
-                "An error occured while collecting builds from '{}': {}",
-                collector.info().id,
-                e
+    // Start workers for newly loaded collectors.
+    for scheduled in context.collectors.drain(..) {
+        if !scheduled.collector.is_polled() {
+            trace!(
+                "Not starting a worker for '{}' since it's not polled",
+                scheduled.collector.info().id
             );
-        };
+            continue;
+        }
+        let worker =
+            CollectorWorker::start(scheduled, context.state.clone(), context.sender.clone());
+        context.workers.push(worker);
+    }
 
-        // Retain builds that were updated
-        context
-            .state
-            .builds
-            .retain_builds(&collector.info(), build_hashes);
+    // Abort collections that are taking too long.
+    for worker in context.workers.iter() {
+        worker.abort_if_timed_out();
+    }
+
+    // Wake workers for collectors that have been asked to refresh.
+    let refresh = context.state.ingest.take_refresh_requests();
+    for worker in context.workers.iter().filter(|w| refresh.contains(&w.id)) {
+        worker.wake();
+    }
+
+    // Process builds that have been pushed to us.
+    for build in context.state.ingest.drain() {
+        context.state.builds.retain_latest(&build);
+        update(&context.state, &context.sender, build);
     }
 }
 
+/// Stops all running collector workers and waits for them to finish.
+pub fn stop(context: &mut Context) {
+    // Signal all workers before waiting for any of
+    // them, so that they can finish in parallel.
+    for worker in context.workers.iter() {
+        worker.stop();
+    }
+    for worker in context.workers.drain(..) {
+        worker.join();
+    }
+}
+
+fn run_worker(
+    scheduled: ScheduledCollector,
+    stopping: WaitHandleListener,
+    (wake, waking): (WaitHandleSignaler, WaitHandleListener),
+    collection: Arc<Mutex<Option<Collection>>>,
+    state: Arc<EngineState>,
+    sender: Sender<EngineEvent>,
+) {
+    trace!("Started worker for '{}'", scheduled.collector.info().id);
+    loop {
+        let (signaler, listener) = waithandle::new();
+        {
+            // Check whether or not we've been stopped while holding
+            // the lock, so a stop request can't slip in between.
+            let mut current = collection.lock().unwrap();
+            if stopping.check() {
+                break;
+            }
+            *current = Some(Collection {
+                started: Instant::now(),
+                signaler,
+            });
+        }
+
+        collect(&listener, &state, &sender, &scheduled.collector);
+        *collection.lock().unwrap() = None;
+
+        // Wait until it's time to collect again,
+        // or until we're woken up or stopped.
+        if waking.wait(scheduled.interval) {
+            wake.reset();
+        }
+        if stopping.check() {
+            break;
+        }
+    }
+    trace!("Stopped worker for '{}'", scheduled.collector.info().id);
+}
+
+#[allow(clippy::borrowed_box)]
+fn collect(
+    listener: &WaitHandleListener,
+    state: &EngineState,
+    sender: &Sender<EngineEvent>,
+    collector: &Box<dyn Collector>,
+) {
+    let mut build_hashes = std::collections::HashSet::<u64>::new();
+    if let Err(e) = collector.collect(listener.clone(), &mut |build: Build| {
+        // Ignore builds that arrive after the collection was aborted
+        if listener.check() {
+            return;
+        }
+        build_hashes.insert(build.id);
+        update(state, sender, build);
+    }) {
+        // Log the error but continue as normal since
+        // we don't want to retain the builds that we could
+        // not collect information about
+        error!(
+            "An error occured while collecting builds from '{}': {}",
+            collector.info().id,
+            e
+        );
+    };
+
+    // If the collection was aborted we don't know which
+    // builds that are gone, so leave them as they are.
+    if listener.check() {
+        return;
+    }
+
+    // Retain builds that were updated
+    state.builds.retain_builds(&collector.info(), build_hashes);
+}
+
+/// Updates the state with a build and notifies the aggregator about it.
+fn update(state: &EngineState, sender: &Sender<EngineEvent>, build: Build) {
+    let result = state.builds.update(&build);
+    if result != BuildUpdateResult::Unchanged {
+        // The history ignores builds whose status hasn't changed.
+        state.history.record(&build);
+    }
+    match result {
+        BuildUpdateResult::Added | BuildUpdateResult::BuildUpdated => {
+            // The build was updated
+            match sender.send(EngineEvent::BuildUpdated(Box::new(build))) {
+                Result::Ok(_) => (),
+                Result::Err(e) => error!("Failed to send build update event. {}", e),
+            }
+        }
+        BuildUpdateResult::AbsoluteBuildStatusChanged => {
+            // The build's status was changed (success->failed or failed->success)
+            match sender.send(EngineEvent::AbsoluteBuildStatusChanged(Box::new(build))) {
+                Result::Ok(_) => (),
+                Result::Err(e) => error!("Failed to send build status event. {}", e),
+            }
+        }
+        _ => {}
+    };
+}
+
 pub enum ConfigurationResult {
     Unchanged,
     Updated,
@@ -104,20 +323,26 @@ pub fn check_for_updated_configuration(context: &mut Context) -> DuckResult<Conf
         trace!("Applying new configuration...");
         match providers::create_collectors(&config) {
             Ok(collectors) => {
+                stop(context);
                 context.collectors.clear();
                 for collector in collectors {
+                    let interval = config.get_collector_interval(&collector.info().id);
+                    let timeout = config.get_collector_timeout(&collector.info().id);
                     debug!(
-                        "Loaded {} collector: {}",
+                        "Loaded {} collector: {} (every {}s)",
                         collector.info().provider,
-                        collector.info().id
+                        collector.info().id,
+                        interval
                     );
-                    context.collectors.push(collector);
+                    context
+                        .collectors
+                        .push(ScheduledCollector::new(collector, interval, timeout));
                 }
 
                 // Remove state for unloaded collectors.
                 let mut collector_ids = HashSet::<String>::new();
-                for collector in context.collectors.iter() {
-                    collector_ids.insert(collector.info().id.clone());
+                for scheduled in context.collectors.iter() {
+                    collector_ids.insert(scheduled.collector.info().id.clone());
                 }
                 context.state.builds.retain(&collector_ids);
                 return Ok(ConfigurationResult::Updated);
@@ -139,18 +364,23 @@ mod tests {
     use crate::builds::{BuildBuilder, BuildStatus};
     use crate::providers::collectors::CollectorInfo;
     use crate::DuckResult;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
     use std::sync::mpsc::channel;
     use test_case::test_case;
 
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
     pub struct DummyCollector {
         pub build: Build,
         pub info: CollectorInfo,
+        pub collected: Arc<AtomicUsize>,
     }
 
     impl DummyCollector {
         pub fn new(build: Build) -> Self {
             DummyCollector {
                 build,
+                collected: Arc::new(AtomicUsize::new(0)),
                 info: CollectorInfo {
                     id: "dummy".to_owned(),
                     enabled: true,
@@ -169,11 +399,53 @@ mod tests {
             _: WaitHandleListener,
             callback: &mut dyn FnMut(Build),
         ) -> DuckResult<()> {
+            self.collected.fetch_add(1, Ordering::SeqCst);
             callback(self.build.clone());
             return Ok(());
         }
     }
 
+    /// A collector that blocks until the collection is aborted.
+    pub struct SlowCollector {
+        pub info: CollectorInfo,
+        pub started: WaitHandleListener,
+        pub aborted: Arc<AtomicBool>,
+        signaler: WaitHandleSignaler,
+    }
+
+    impl SlowCollector {
+        pub fn new() -> Self {
+            let (signaler, started) = waithandle::new();
+            SlowCollector {
+                info: CollectorInfo {
+                    id: "slow".to_owned(),
+                    enabled: true,
+                    provider: "TeamCity".to_owned(),
+                },
+                started,
+                aborted: Arc::new(AtomicBool::new(false)),
+                signaler,
+            }
+        }
+    }
+
+    impl Collector for SlowCollector {
+        fn info(&self) -> &crate::providers::collectors::CollectorInfo {
+            &self.info
+        }
+        fn collect(
+            &self,
+            listener: WaitHandleListener,
+            _: &mut dyn FnMut(Build),
+        ) -> DuckResult<()> {
+            self.signaler.signal();
+            if listener.wait(Duration::from_secs(60)) {
+                self.aborted.store(true, Ordering::SeqCst);
+            }
+            return Ok(());
+        }
+    }
+
     #[test]
     fn should_send_build_updated_event_if_build_is_new() {
         // Given
@@ -187,17 +459,194 @@ mod tests {
             engine_receiver,
             state: Arc::new(EngineState::new()),
             collectors: Vec::new(),
+            workers: Vec::new(),
         };
 
         let new_build = DummyCollector::new(BuildBuilder::dummy().build().unwrap());
         let collector = Box::new(new_build) as Box<dyn Collector>;
-        context.collectors.push(collector);
+        context
+            .collectors
+            .push(ScheduledCollector::new(collector, 15, 60));
 
         // When
         accumulate(&mut context);
 
         // Then
-        assert!(receiver.try_recv().unwrap().is_build_updated());
+        assert!(receiver.recv_timeout(TIMEOUT).unwrap().is_build_updated());
+    }
+
+    #[test]
+    fn should_not_collect_builds_before_collector_is_due() {
+        // Given
+        let (sender, receiver) = channel::<EngineEvent>();
+        let (_, engine_receiver) = channel::<EngineThreadMessage>();
+        let (_, listener) = waithandle::new();
+
+        let mut context = Context {
+            listener,
+            sender,
+            engine_receiver,
+            state: Arc::new(EngineState::new()),
+            collectors: Vec::new(),
+            workers: Vec::new(),
+        };
+
+        let dummy = DummyCollector::new(BuildBuilder::dummy().build().unwrap());
+        let collected = dummy.collected.clone();
+        let collector = Box::new(dummy) as Box<dyn Collector>;
+        context
+            .collectors
+            .push(ScheduledCollector::new(collector, 60, 60));
+
+        // When
+        accumulate(&mut context);
+        receiver.recv_timeout(TIMEOUT).unwrap();
+        accumulate(&mut context);
+        stop(&mut context);
+
+        // Then
+        assert_eq!(1, collected.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn should_collect_builds_right_away_if_refresh_is_requested() {
+        // Given
+        let (sender, receiver) = channel::<EngineEvent>();
+        let (_, engine_receiver) = channel::<EngineThreadMessage>();
+        let (_, listener) = waithandle::new();
+
+        let mut context = Context {
+            listener,
+            sender,
+            engine_receiver,
+            state: Arc::new(EngineState::new()),
+            collectors: Vec::new(),
+            workers: Vec::new(),
+        };
+
+        let dummy = DummyCollector::new(BuildBuilder::dummy().build().unwrap());
+        let collected = dummy.collected.clone();
+        let collector = Box::new(dummy) as Box<dyn Collector>;
+        context
+            .collectors
+            .push(ScheduledCollector::new(collector, 60, 60));
+
+        // When
+        accumulate(&mut context);
+        receiver.recv_timeout(TIMEOUT).unwrap();
+        context.state.ingest.request_refresh("dummy");
+        accumulate(&mut context);
+        let started = Instant::now();
+        while collected.load(Ordering::SeqCst) < 2 && started.elapsed() < TIMEOUT {
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        stop(&mut context);
+
+        // Then
+        assert_eq!(2, collected.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn should_not_wait_for_slow_collectors() {
+        // Given
+        let (sender, receiver) = channel::<EngineEvent>();
+        let (_, engine_receiver) = channel::<EngineThreadMessage>();
+        let (_, listener) = waithandle::new();
+
+        let mut context = Context {
+            listener,
+            sender,
+            engine_receiver,
+            state: Arc::new(EngineState::new()),
+            collectors: Vec::new(),
+            workers: Vec::new(),
+        };
+
+        let slow = Box::new(SlowCollector::new()) as Box<dyn Collector>;
+        let fast = Box::new(DummyCollector::new(BuildBuilder::dummy().build().unwrap()))
+            as Box<dyn Collector>;
+        context
+            .collectors
+            .push(ScheduledCollector::new(slow, 15, 60));
+        context
+            .collectors
+            .push(ScheduledCollector::new(fast, 15, 60));
+
+        // When
+        accumulate(&mut context);
+        let event = receiver.recv_timeout(TIMEOUT);
+        stop(&mut context);
+
+        // Then
+        assert!(event.unwrap().is_build_updated());
+    }
+
+    #[test]
+    fn should_abort_collection_that_times_out() {
+        // Given
+        let (sender, _receiver) = channel::<EngineEvent>();
+        let (_, engine_receiver) = channel::<EngineThreadMessage>();
+        let (_, listener) = waithandle::new();
+
+        let mut context = Context {
+            listener,
+            sender,
+            engine_receiver,
+            state: Arc::new(EngineState::new()),
+            collectors: Vec::new(),
+            workers: Vec::new(),
+        };
+
+        let slow = SlowCollector::new();
+        let aborted = slow.aborted.clone();
+        let collector = Box::new(slow) as Box<dyn Collector>;
+        context
+            .collectors
+            .push(ScheduledCollector::new(collector, 15, 1));
+
+        // When
+        let started = Instant::now();
+        while !aborted.load(Ordering::SeqCst) && started.elapsed() < TIMEOUT {
+            accumulate(&mut context);
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        stop(&mut context);
+
+        // Then
+        assert!(aborted.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn should_abort_collection_when_stopped() {
+        // Given
+        let (sender, _receiver) = channel::<EngineEvent>();
+        let (_, engine_receiver) = channel::<EngineThreadMessage>();
+        let (_, listener) = waithandle::new();
+
+        let mut context = Context {
+            listener,
+            sender,
+            engine_receiver,
+            state: Arc::new(EngineState::new()),
+            collectors: Vec::new(),
+            workers: Vec::new(),
+        };
+
+        let slow = SlowCollector::new();
+        let started = slow.started.clone();
+        let aborted = slow.aborted.clone();
+        let collector = Box::new(slow) as Box<dyn Collector>;
+        context
+            .collectors
+            .push(ScheduledCollector::new(collector, 15, 60));
+
+        // When
+        accumulate(&mut context);
+        started.wait(TIMEOUT);
+        stop(&mut context);
+
+        // Then
+        assert!(aborted.load(Ordering::SeqCst));
     }
 
     #[test]
@@ -214,6 +663,7 @@ mod tests {
             engine_receiver,
             state: Arc::new(EngineState::new()),
             collectors: Vec::new(),
+            workers: Vec::new(),
         };
 
         let current_build = BuildBuilder::dummy().build().unwrap();
@@ -221,14 +671,16 @@ mod tests {
 
         let new_build = BuildBuilder::dummy().build().unwrap();
         let collector = Box::new(DummyCollector::new(new_build)) as Box<dyn Collector>;
-        context.collectors.push(collector);
+        context
+            .collectors
+            .push(ScheduledCollector::new(collector, 15, 60));
 
         // When
         accumulate(&mut context);
 
         // Then
         receiver
-            .try_recv()
+            .recv_timeout(Duration::from_millis(500))
             .expect("Channel does not have any events");
     }
 
@@ -267,6 +719,7 @@ mod tests {
             engine_receiver,
             state: Arc::new(EngineState::new()),
             collectors: Vec::new(),
+            workers: Vec::new(),
         };
 
         let current_build = BuildBuilder::dummy().status(from).build().unwrap();
@@ -274,13 +727,15 @@ mod tests {
 
         let new_build = BuildBuilder::dummy().status(to).build().unwrap();
         let collector = Box::new(DummyCollector::new(new_build)) as Box<dyn Collector>;
-        context.collectors.push(collector);
+        context
+            .collectors
+            .push(ScheduledCollector::new(collector, 15, 60));
 
         // When
         accumulate(&mut context);
 
         // Then
-        assert!(receiver.try_recv().unwrap().is_build_updated());
+        assert!(receiver.recv_timeout(TIMEOUT).unwrap().is_build_updated());
     }
 
     #[test_case(BuildStatus::Unknown, BuildStatus::Failed ; "Unknown -> Failed")]
@@ -306,6 +761,7 @@ mod tests {
             engine_receiver,
             state: Arc::new(EngineState::new()),
             collectors: Vec::new(),
+            workers: Vec::new(),
         };
 
         let current_build = BuildBuilder::dummy().status(from).build().unwrap();
@@ -313,12 +769,17 @@ mod tests {
 
         let new_build = BuildBuilder::dummy().status(to).build().unwrap();
         let collector = Box::new(DummyCollector::new(new_build)) as Box<dyn Collector>;
-        context.collectors.push(collector);
+        context
+            .collectors
+            .push(ScheduledCollector::new(collector, 15, 60));
 
         // When
         accumulate(&mut context);
 
         // Then
-        assert!(receiver.try_recv().unwrap().is_build_status_changed());
+        assert!(receiver
+            .recv_timeout(TIMEOUT)
+            .unwrap()
+            .is_build_status_changed());
     }
 }
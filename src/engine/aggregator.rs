@@ -49,9 +49,13 @@ pub fn aggregate(context: &mut Context) -> AggregateResult {
 
         match command {
             EngineEvent::BuildUpdated(build) => {
+                context.state.events.build_updated(&build);
                 build_updated(context, build);
             }
             EngineEvent::AbsoluteBuildStatusChanged(build) => {
+                // Notify clients of the live event stream.
+                context.state.events.build_updated(&build);
+                context.state.events.build_status_changed(&build);
                 // Send the BuildUpdated event to all observers.
                 propagate_to_observers(&context.observers, &mut || {
                     Observation::BuildUpdated(&build)
@@ -67,8 +71,19 @@ pub fn aggregate(context: &mut Context) -> AggregateResult {
                 return AggregateResult::Stopped;
             }
         }
+
+        // Notify clients of the live event stream if
+        // the overall build status changed.
+        context
+            .state
+            .events
+            .status(context.state.builds.current_status());
     }
 
+    // Keep idle connections to the live event stream open
+    // and get rid of clients that have disconnected.
+    context.state.events.keep_alive();
+
     AggregateResult::Success
 }
 
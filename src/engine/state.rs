@@ -1,19 +1,28 @@
 use std::time::SystemTime;
 
-use log::debug;
+use log::{debug, error};
 
 use crate::config::Configuration;
 use crate::engine::state::builds::BuildRepository;
+use crate::engine::state::events::EventBroadcaster;
+use crate::engine::state::history::HistoryRepository;
+use crate::engine::state::ingest::IngestRepository;
 use crate::engine::state::ui::UiRepository;
 use crate::engine::state::views::ViewRepository;
 
 pub mod builds;
+pub mod events;
+pub mod history;
+pub mod ingest;
 pub mod ui;
 pub mod views;
 
 pub struct EngineState {
     pub started: SystemTime,
     pub builds: BuildRepository,
+    pub events: EventBroadcaster,
+    pub history: HistoryRepository,
+    pub ingest: IngestRepository,
     pub ui: UiRepository,
     pub views: ViewRepository,
 }
@@ -23,6 +32,9 @@ impl EngineState {
         return EngineState {
             started: SystemTime::now(),
             builds: BuildRepository::new(),
+            events: EventBroadcaster::new(),
+            history: HistoryRepository::new(),
+            ingest: IngestRepository::new(),
             ui: UiRepository::new(),
             views: ViewRepository::new(),
         };
@@ -34,5 +46,20 @@ impl EngineState {
         if let Some(views) = &config.views {
             self.views.add_views(views);
         }
+        self.ingest.configure(config);
+
+        // Restore the last known builds from the history so
+        // that observers aren't notified about them again.
+        match &config.history {
+            Some(history) => match self.history.open(history) {
+                Ok(builds) => {
+                    for build in builds.iter() {
+                        self.builds.update(build);
+                    }
+                }
+                Err(e) => error!("Could not open build history: {}", e),
+            },
+            None => self.history.close(),
+        }
     }
 }
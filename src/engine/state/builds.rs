@@ -113,6 +113,14 @@ impl BuildRepository {
         statuses.retain(|id, _| builds.iter().any(|b| &b.partition == id));
     }
 
+    /// Removes all other builds in the same partition as the provided build.
+    /// Builds that are pushed to Duck are never pruned by a collection,
+    /// so older builds would otherwise stick around.
+    pub fn retain_latest(&self, build: &Build) {
+        let mut builds = self.builds.lock().unwrap();
+        builds.retain(|b| b.partition != build.partition || b.id == build.id);
+    }
+
     /// Retains all builds that belong to the provided collectors.
     pub fn retain(&self, collectors: &HashSet<String>) {
         let mut builds = self.builds.lock().unwrap();
@@ -255,6 +263,27 @@ mod tests {
         assert!(state.current_status() == BuildStatus::Running);
     }
 
+    #[test]
+    fn should_only_retain_latest_build_in_partition() {
+        let state = BuildRepository::new();
+        state.update(&BuildBuilder::dummy().build_id("1").unwrap());
+        state.update(&BuildBuilder::dummy().build_id("2").unwrap());
+        state.update(
+            &BuildBuilder::dummy()
+                .build_id("3")
+                .branch("develop")
+                .unwrap(),
+        );
+        assert_eq!(3, state.all().len());
+
+        state.retain_latest(&BuildBuilder::dummy().build_id("2").unwrap());
+
+        let builds = state.all();
+        assert_eq!(2, builds.len());
+        assert!(builds.iter().any(|b| b.build_id == "2"));
+        assert!(builds.iter().any(|b| b.branch == "develop"));
+    }
+
     #[test]
     fn should_return_correct_state_for_specific_collectors() {
         let state = BuildRepository::new();
@@ -0,0 +1,211 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+
+use crate::builds::{Build, BuildStatus};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LiveEvent {
+    BuildUpdated(Build),
+    BuildStatusChanged(Build),
+    StatusChanged(BuildStatus),
+    KeepAlive,
+}
+
+static KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Broadcasts live events to subscribers such as
+/// clients connected to the event stream endpoint.
+pub struct EventBroadcaster {
+    subscribers: Mutex<Vec<UnboundedSender<LiveEvent>>>,
+    status: Mutex<BuildStatus>,
+    last_published: Mutex<Instant>,
+}
+
+impl EventBroadcaster {
+    pub fn new() -> Self {
+        Self {
+            subscribers: Mutex::new(Vec::new()),
+            status: Mutex::new(BuildStatus::Unknown),
+            last_published: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Subscribes to live events. The current overall
+    /// status is sent to the new subscriber right away.
+    pub fn subscribe(&self) -> UnboundedReceiver<LiveEvent> {
+        let (sender, receiver) = unbounded();
+        let status = self.status.lock().unwrap();
+        if sender
+            .unbounded_send(LiveEvent::StatusChanged(status.clone()))
+            .is_ok()
+        {
+            self.subscribers.lock().unwrap().push(sender);
+        }
+        receiver
+    }
+
+    pub fn build_updated(&self, build: &Build) {
+        self.publish(LiveEvent::BuildUpdated(build.clone()));
+    }
+
+    pub fn build_status_changed(&self, build: &Build) {
+        self.publish(LiveEvent::BuildStatusChanged(build.clone()));
+    }
+
+    /// Publishes the overall status if it differs
+    /// from the last published one.
+    pub fn status(&self, status: BuildStatus) {
+        let mut current = self.status.lock().unwrap();
+        if *current != status {
+            *current = status.clone();
+            self.publish(LiveEvent::StatusChanged(status));
+        }
+    }
+
+    /// Publishes a keep-alive if nothing has been
+    /// published for a while.
+    pub fn keep_alive(&self) {
+        self.keep_alive_at(Instant::now());
+    }
+
+    fn keep_alive_at(&self, now: Instant) {
+        let last_published = *self.last_published.lock().unwrap();
+        if now.duration_since(last_published) >= KEEP_ALIVE_INTERVAL {
+            self.publish(LiveEvent::KeepAlive);
+        }
+    }
+
+    fn publish(&self, event: LiveEvent) {
+        // Subscribers that have gone away are removed.
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|s| s.unbounded_send(event.clone()).is_ok());
+        *self.last_published.lock().unwrap() = Instant::now();
+    }
+}
+
+///////////////////////////////////////////////////////////
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::BuildBuilder;
+
+    fn subscribe(events: &EventBroadcaster) -> UnboundedReceiver<LiveEvent> {
+        let mut receiver = events.subscribe();
+        receiver.try_next().unwrap();
+        receiver
+    }
+
+    #[test]
+    fn should_send_current_status_to_new_subscribers() {
+        // Given
+        let events = EventBroadcaster::new();
+        events.status(BuildStatus::Failed);
+
+        // When
+        let mut receiver = events.subscribe();
+
+        // Then
+        assert_eq!(
+            Some(LiveEvent::StatusChanged(BuildStatus::Failed)),
+            receiver.try_next().unwrap()
+        );
+    }
+
+    #[test]
+    fn should_publish_events_to_all_subscribers() {
+        // Given
+        let events = EventBroadcaster::new();
+        let mut first = subscribe(&events);
+        let mut second = subscribe(&events);
+        let build = BuildBuilder::dummy().unwrap();
+
+        // When
+        events.build_updated(&build);
+
+        // Then
+        let expected = Some(LiveEvent::BuildUpdated(build));
+        assert_eq!(expected, first.try_next().unwrap());
+        assert_eq!(expected, second.try_next().unwrap());
+    }
+
+    #[test]
+    fn should_only_publish_status_when_it_changes() {
+        // Given
+        let events = EventBroadcaster::new();
+        let mut receiver = subscribe(&events);
+
+        // When
+        events.status(BuildStatus::Success);
+        events.status(BuildStatus::Success);
+        events.status(BuildStatus::Failed);
+
+        // Then
+        assert_eq!(
+            Some(LiveEvent::StatusChanged(BuildStatus::Success)),
+            receiver.try_next().unwrap()
+        );
+        assert_eq!(
+            Some(LiveEvent::StatusChanged(BuildStatus::Failed)),
+            receiver.try_next().unwrap()
+        );
+        assert!(receiver.try_next().is_err());
+    }
+
+    #[test]
+    fn should_remove_subscribers_that_have_gone_away() {
+        // Given
+        let events = EventBroadcaster::new();
+        let receiver = events.subscribe();
+        drop(receiver);
+
+        // When
+        events.build_updated(&BuildBuilder::dummy().unwrap());
+
+        // Then
+        assert_eq!(0, events.subscribers.lock().unwrap().len());
+    }
+
+    #[test]
+    fn should_not_publish_keep_alive_if_recently_published() {
+        // Given
+        let events = EventBroadcaster::new();
+        let mut receiver = subscribe(&events);
+
+        // When
+        events.keep_alive();
+
+        // Then
+        assert!(receiver.try_next().is_err());
+    }
+
+    #[test]
+    fn should_publish_keep_alive_when_idle() {
+        // Given
+        let events = EventBroadcaster::new();
+        let mut receiver = subscribe(&events);
+
+        // When
+        events.keep_alive_at(Instant::now() + KEEP_ALIVE_INTERVAL);
+
+        // Then
+        assert_eq!(Some(LiveEvent::KeepAlive), receiver.try_next().unwrap());
+    }
+
+    #[test]
+    fn should_remove_subscribers_that_have_gone_away_when_idle() {
+        // Given
+        let events = EventBroadcaster::new();
+        let receiver = events.subscribe();
+        drop(receiver);
+
+        // When
+        events.keep_alive_at(Instant::now() + KEEP_ALIVE_INTERVAL);
+
+        // Then
+        assert_eq!(0, events.subscribers.lock().unwrap().len());
+    }
+}
@@ -0,0 +1,601 @@
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use log::{debug, warn};
+
+use crate::builds::{Build, BuildBuilder, BuildStatus};
+use crate::config::HistoryConfiguration;
+use crate::DuckResult;
+
+const SECONDS_PER_DAY: i64 = 60 * 60 * 24;
+
+pub struct HistoryRepository {
+    store: Mutex<Option<HistoryStore>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoryEntry {
+    pub timestamp: i64,
+    pub build: Build,
+}
+
+impl HistoryRepository {
+    pub fn new() -> Self {
+        Self {
+            store: Mutex::new(None),
+        }
+    }
+
+    /// Opens the history file described by the configuration.
+    /// Returns the latest known build for every build partition
+    /// if the file was not already open.
+    pub fn open(&self, config: &HistoryConfiguration) -> DuckResult<Vec<Build>> {
+        let mut store = self.store.lock().unwrap();
+        let path = PathBuf::from(&config.path);
+        let retention = i64::from(config.get_retention()) * SECONDS_PER_DAY;
+
+        if let Some(store) = store.as_mut() {
+            if store.path == path {
+                store.retention = retention;
+                return Ok(vec![]);
+            }
+        }
+
+        debug!("Opening build history '{}'", path.display());
+        let opened = HistoryStore::open(path, retention, now())?;
+        let builds = opened.latest();
+        *store = Some(opened);
+
+        Ok(builds)
+    }
+
+    /// Closes the history file if one is open.
+    pub fn close(&self) {
+        let mut store = self.store.lock().unwrap();
+        if store.is_some() {
+            debug!("Closing build history");
+            *store = None;
+        }
+    }
+
+    /// Records a build status transition. Builds that have already
+    /// been recorded with the same status are ignored, so only the
+    /// first sighting of a build and changes to its status are kept.
+    pub fn record(&self, build: &Build) {
+        self.record_at(build, now());
+    }
+
+    fn record_at(&self, build: &Build, timestamp: i64) {
+        let mut store = self.store.lock().unwrap();
+        if let Some(store) = store.as_mut() {
+            if store.last_status(build.id) == Some(&build.status) {
+                return;
+            }
+            if let Err(e) = store.append(HistoryEntry {
+                timestamp,
+                build: build.clone(),
+            }) {
+                warn!("Could not write to build history: {}", e);
+            }
+        }
+    }
+
+    /// Gets all recorded transitions for the partition that the
+    /// build with the specified id belongs to.
+    pub fn for_build(&self, id: u64) -> Option<Vec<HistoryEntry>> {
+        let store = self.store.lock().unwrap();
+        let entries = &store.as_ref()?.entries;
+        let partition = entries.iter().find(|e| e.build.id == id)?.build.partition;
+        Some(
+            entries
+                .iter()
+                .filter(|e| e.build.partition == partition)
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Gets all recorded transitions within the specified time range.
+    pub fn between(&self, from: Option<i64>, to: Option<i64>) -> Vec<HistoryEntry> {
+        let from = from.unwrap_or(i64::MIN);
+        let to = to.unwrap_or(i64::MAX);
+        let store = self.store.lock().unwrap();
+        match store.as_ref() {
+            Some(store) => store
+                .entries
+                .iter()
+                .filter(|e| e.timestamp >= from && e.timestamp <= to)
+                .cloned()
+                .collect(),
+            None => vec![],
+        }
+    }
+}
+
+fn now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+///////////////////////////////////////////////////////////
+// Store
+
+struct HistoryStore {
+    path: PathBuf,
+    retention: i64,
+    file: File,
+    entries: Vec<HistoryEntry>,
+    // The last recorded status of each build.
+    statuses: HashMap<u64, BuildStatus>,
+    // The number of lines in the file that have
+    // been removed from memory but not from disk.
+    stale: usize,
+}
+
+impl HistoryStore {
+    fn open(path: PathBuf, retention: i64, now: i64) -> DuckResult<Self> {
+        let (mut entries, malformed) = read_entries(&path)?;
+
+        // Get rid of entries older than the retention period,
+        // and compact the file if anything was removed.
+        let count = entries.len();
+        entries.retain(|e| e.timestamp >= now - retention);
+        if entries.len() != count || malformed {
+            debug!("Compacting build history '{}'", path.display());
+            write_entries(&path, &entries)?;
+        }
+
+        let file = open_for_append(&path)?;
+        let statuses = get_statuses(&entries);
+        Ok(Self {
+            path,
+            retention,
+            file,
+            entries,
+            statuses,
+            stale: 0,
+        })
+    }
+
+    fn last_status(&self, id: u64) -> Option<&BuildStatus> {
+        self.statuses.get(&id)
+    }
+
+    fn append(&mut self, entry: HistoryEntry) -> DuckResult<()> {
+        let line = serde_json::to_string(&HistoryRecord::from(&entry))?;
+        writeln!(self.file, "{}", line)?;
+        self.file.flush()?;
+        let now = entry.timestamp;
+        self.statuses
+            .insert(entry.build.id, entry.build.status.clone());
+        self.entries.push(entry);
+        self.prune(now)
+    }
+
+    /// Removes entries older than the retention period.
+    /// The file is only compacted once it contains at least as many
+    /// removed entries as live ones, to avoid rewriting it on every append.
+    fn prune(&mut self, now: i64) -> DuckResult<()> {
+        let count = self.entries.len();
+        let retention = self.retention;
+        self.entries.retain(|e| e.timestamp >= now - retention);
+        self.stale += count - self.entries.len();
+
+        if self.stale > 0 && self.stale >= self.entries.len() {
+            debug!("Compacting build history '{}'", self.path.display());
+            write_entries(&self.path, &self.entries)?;
+            self.file = open_for_append(&self.path)?;
+            self.statuses = get_statuses(&self.entries);
+            self.stale = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Gets the latest recorded build for each partition.
+    fn latest(&self) -> Vec<Build> {
+        let mut last = HashMap::new();
+        for (index, entry) in self.entries.iter().enumerate() {
+            last.insert(entry.build.partition, index);
+        }
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(index, entry)| last[&entry.build.partition] == *index)
+            .map(|(_, entry)| entry.build.clone())
+            .collect()
+    }
+}
+
+fn get_statuses(entries: &[HistoryEntry]) -> HashMap<u64, BuildStatus> {
+    entries
+        .iter()
+        .map(|e| (e.build.id, e.build.status.clone()))
+        .collect()
+}
+
+fn open_for_append(path: &Path) -> DuckResult<File> {
+    Ok(OpenOptions::new().create(true).append(true).open(path)?)
+}
+
+/// Reads all entries from the history file, and
+/// whether or not any malformed entries were skipped.
+fn read_entries(path: &Path) -> DuckResult<(Vec<HistoryEntry>, bool)> {
+    let mut entries = Vec::new();
+    let mut malformed = false;
+    if !path.exists() {
+        return Ok((entries, malformed));
+    }
+
+    let reader = BufReader::new(File::open(path)?);
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        // A malformed line is most likely the result of an
+        // interrupted write, so skip it instead of failing.
+        match serde_json::from_str::<HistoryRecord>(&line) {
+            Ok(record) => match record.to_entry() {
+                Ok(entry) => entries.push(entry),
+                Err(e) => {
+                    warn!("Skipping invalid history entry: {}", e);
+                    malformed = true;
+                }
+            },
+            Err(e) => {
+                warn!("Skipping malformed history entry: {}", e);
+                malformed = true;
+            }
+        }
+    }
+
+    Ok((entries, malformed))
+}
+
+fn write_entries(path: &Path, entries: &[HistoryEntry]) -> DuckResult<()> {
+    let temp = path.with_extension("tmp");
+    {
+        let mut file = File::create(&temp)?;
+        for entry in entries.iter() {
+            writeln!(
+                file,
+                "{}",
+                serde_json::to_string(&HistoryRecord::from(entry))?
+            )?;
+        }
+        file.flush()?;
+    }
+    std::fs::rename(&temp, path)?;
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////
+// Persisted model
+
+// The build id and partition are hashes that are not guaranteed
+// to be stable between versions, so we recalculate them when
+// an entry is read instead of storing them.
+#[derive(Serialize, Deserialize)]
+struct HistoryRecord {
+    timestamp: i64,
+    provider: String,
+    collector: String,
+    origin: String,
+    build_id: String,
+    project_id: String,
+    project_name: String,
+    definition_id: String,
+    definition_name: String,
+    build_number: String,
+    status: BuildStatus,
+    branch: String,
+    url: String,
+    started_at: i64,
+    finished_at: Option<i64>,
+}
+
+impl From<&HistoryEntry> for HistoryRecord {
+    fn from(entry: &HistoryEntry) -> Self {
+        let build = &entry.build;
+        HistoryRecord {
+            timestamp: entry.timestamp,
+            provider: build.provider.clone(),
+            collector: build.collector.clone(),
+            origin: build.origin.clone(),
+            build_id: build.build_id.clone(),
+            project_id: build.project_id.clone(),
+            project_name: build.project_name.clone(),
+            definition_id: build.definition_id.clone(),
+            definition_name: build.definition_name.clone(),
+            build_number: build.build_number.clone(),
+            status: build.status.clone(),
+            branch: build.branch.clone(),
+            url: build.url.clone(),
+            started_at: build.started_at,
+            finished_at: build.finished_at,
+        }
+    }
+}
+
+impl HistoryRecord {
+    fn to_entry(&self) -> DuckResult<HistoryEntry> {
+        let build = BuildBuilder::new()
+            .provider(&self.provider[..])
+            .collector(&self.collector[..])
+            .origin(&self.origin[..])
+            .build_id(&self.build_id[..])
+            .project_id(&self.project_id[..])
+            .project_name(&self.project_name[..])
+            .definition_id(&self.definition_id[..])
+            .definition_name(&self.definition_name[..])
+            .build_number(&self.build_number[..])
+            .status(self.status.clone())
+            .branch(&self.branch[..])
+            .url(&self.url[..])
+            .started_at(self.started_at)
+            .finished_at(self.finished_at)
+            .build()
+            .map_err(|e| format_err!("{}", e))?;
+
+        Ok(HistoryEntry {
+            timestamp: self.timestamp,
+            build,
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TempFile {
+        path: PathBuf,
+    }
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "duck-history-{}-{}.jsonl",
+                name,
+                std::process::id()
+            ));
+            let _ = std::fs::remove_file(&path);
+            Self { path }
+        }
+
+        fn config(&self, retention: Option<u16>) -> HistoryConfiguration {
+            HistoryConfiguration {
+                path: self.path.to_str().unwrap().to_owned(),
+                retention,
+            }
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+
+    #[test]
+    fn should_load_latest_build_for_each_partition_when_opened() {
+        // Given
+        let file = TempFile::new("latest");
+        let history = HistoryRepository::new();
+        history.open(&file.config(None)).unwrap();
+        history.record(&BuildBuilder::dummy().build_id("1").branch("main").unwrap());
+        history.record(&BuildBuilder::dummy().build_id("2").branch("main").unwrap());
+        history.record(&BuildBuilder::dummy().build_id("3").branch("dev").unwrap());
+
+        // When
+        let builds = HistoryRepository::new().open(&file.config(None)).unwrap();
+
+        // Then
+        assert_eq!(2, builds.len());
+        assert_eq!("2", builds[0].build_id);
+        assert_eq!("3", builds[1].build_id);
+    }
+
+    #[test]
+    fn should_not_load_builds_if_history_is_already_open() {
+        // Given
+        let file = TempFile::new("reopen");
+        let history = HistoryRepository::new();
+        history.open(&file.config(None)).unwrap();
+        history.record(&BuildBuilder::dummy().unwrap());
+
+        // When
+        let builds = history.open(&file.config(None)).unwrap();
+
+        // Then
+        assert_eq!(0, builds.len());
+    }
+
+    #[test]
+    fn should_get_all_transitions_for_a_build() {
+        // Given
+        let file = TempFile::new("build");
+        let history = HistoryRepository::new();
+        history.open(&file.config(None)).unwrap();
+        history.record(
+            &BuildBuilder::dummy()
+                .build_id("1")
+                .status(BuildStatus::Running)
+                .unwrap(),
+        );
+        history.record(
+            &BuildBuilder::dummy()
+                .build_id("1")
+                .status(BuildStatus::Failed)
+                .unwrap(),
+        );
+        history.record(&BuildBuilder::dummy().build_id("2").branch("dev").unwrap());
+        let build = BuildBuilder::dummy().build_id("1").unwrap();
+
+        // When
+        let entries = history.for_build(build.id).unwrap();
+
+        // Then
+        assert_eq!(2, entries.len());
+        assert_eq!(BuildStatus::Running, entries[0].build.status);
+        assert_eq!(BuildStatus::Failed, entries[1].build.status);
+    }
+
+    #[test]
+    fn should_only_record_status_changes() {
+        // Given
+        let file = TempFile::new("changes");
+        let history = HistoryRepository::new();
+        history.open(&file.config(None)).unwrap();
+        let running = BuildBuilder::dummy()
+            .build_id("1")
+            .status(BuildStatus::Running)
+            .unwrap();
+        let failed = BuildBuilder::dummy()
+            .build_id("1")
+            .status(BuildStatus::Failed)
+            .unwrap();
+
+        // When
+        history.record(&running);
+        history.record(&running);
+        history.record(&failed);
+        history.record(&failed);
+
+        // Then
+        let entries = history.for_build(running.id).unwrap();
+        assert_eq!(2, entries.len());
+        assert_eq!(BuildStatus::Running, entries[0].build.status);
+        assert_eq!(BuildStatus::Failed, entries[1].build.status);
+        assert_eq!(2, read_entries(&file.path).unwrap().0.len());
+    }
+
+    #[test]
+    fn should_not_record_restored_builds_again() {
+        // Given
+        let file = TempFile::new("restored");
+        let build = BuildBuilder::dummy().unwrap();
+        let history = HistoryRepository::new();
+        history.open(&file.config(None)).unwrap();
+        history.record(&build);
+        history.close();
+
+        // When
+        let history = HistoryRepository::new();
+        history.open(&file.config(None)).unwrap();
+        history.record(&build);
+
+        // Then
+        assert_eq!(1, read_entries(&file.path).unwrap().0.len());
+    }
+
+    #[test]
+    fn should_return_none_for_unknown_build() {
+        // Given
+        let file = TempFile::new("unknown");
+        let history = HistoryRepository::new();
+        history.open(&file.config(None)).unwrap();
+
+        // When
+        let entries = history.for_build(1234);
+
+        // Then
+        assert!(entries.is_none());
+    }
+
+    #[test]
+    fn should_get_transitions_within_time_range() {
+        // Given
+        let file = TempFile::new("range");
+        let history = HistoryRepository::new();
+        history.open(&file.config(None)).unwrap();
+        let now = now();
+        history.record_at(&BuildBuilder::dummy().build_id("1").unwrap(), now - 300);
+        history.record_at(&BuildBuilder::dummy().build_id("2").unwrap(), now - 200);
+        history.record_at(&BuildBuilder::dummy().build_id("3").unwrap(), now - 100);
+
+        // When
+        let entries = history.between(Some(now - 250), Some(now - 150));
+
+        // Then
+        assert_eq!(1, entries.len());
+        assert_eq!("2", entries[0].build.build_id);
+    }
+
+    #[test]
+    fn should_remove_entries_older_than_retention_period() {
+        // Given
+        let file = TempFile::new("retention");
+        let history = HistoryRepository::new();
+        history.open(&file.config(Some(1))).unwrap();
+        let now = now();
+        history.record_at(
+            &BuildBuilder::dummy().build_id("1").unwrap(),
+            now - 2 * SECONDS_PER_DAY,
+        );
+        history.record_at(&BuildBuilder::dummy().build_id("2").unwrap(), now);
+
+        // When
+        let history = HistoryRepository::new();
+        history.open(&file.config(Some(1))).unwrap();
+
+        // Then
+        let entries = history.between(None, None);
+        assert_eq!(1, entries.len());
+        assert_eq!("2", entries[0].build.build_id);
+        assert_eq!(1, read_entries(&file.path).unwrap().0.len());
+    }
+
+    #[test]
+    fn should_remove_entries_older_than_retention_period_when_appending() {
+        // Given
+        let file = TempFile::new("retention-append");
+        let history = HistoryRepository::new();
+        history.open(&file.config(Some(1))).unwrap();
+        let now = now();
+        history.record_at(
+            &BuildBuilder::dummy().build_id("1").unwrap(),
+            now - 2 * SECONDS_PER_DAY,
+        );
+
+        // When
+        history.record_at(&BuildBuilder::dummy().build_id("2").unwrap(), now);
+        history.record_at(&BuildBuilder::dummy().build_id("3").unwrap(), now);
+
+        // Then
+        let entries = history.between(None, None);
+        assert_eq!(2, entries.len());
+        assert_eq!("2", entries[0].build.build_id);
+        assert_eq!("3", entries[1].build.build_id);
+        let (persisted, _) = read_entries(&file.path).unwrap();
+        assert_eq!(2, persisted.len());
+        assert_eq!("2", persisted[0].build.build_id);
+    }
+
+    #[test]
+    fn should_skip_malformed_entries() {
+        // Given
+        let file = TempFile::new("malformed");
+        let history = HistoryRepository::new();
+        history.open(&file.config(None)).unwrap();
+        history.record(&BuildBuilder::dummy().unwrap());
+        history.close();
+        let mut handle = OpenOptions::new().append(true).open(&file.path).unwrap();
+        write!(handle, "{{\"timestamp\":").unwrap();
+
+        // When
+        let history = HistoryRepository::new();
+        let builds = history.open(&file.config(None)).unwrap();
+        history.record(&BuildBuilder::dummy().build_id("2").unwrap());
+
+        // Then
+        assert_eq!(1, builds.len());
+        assert_eq!(2, read_entries(&file.path).unwrap().0.len());
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use crate::builds::Build;
+use crate::config::{
+    CollectorConfiguration, Configuration, GitHubConfiguration, WebhookCredentials,
+};
+
+/// Keeps track of the webhooks that builds can be pushed to
+/// and the pushed builds that the engine has not processed yet.
+pub struct IngestRepository {
+    webhooks: Mutex<HashMap<String, WebhookCredentials>>,
+    github: Mutex<HashMap<String, GitHubConfiguration>>,
+    queue: Mutex<Vec<Build>>,
+    refresh: Mutex<HashSet<String>>,
+}
+
+impl IngestRepository {
+    pub fn new() -> Self {
+        Self {
+            webhooks: Mutex::new(HashMap::new()),
+            github: Mutex::new(HashMap::new()),
+            queue: Mutex::new(Vec::new()),
+            refresh: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Registers all enabled webhook collectors in the configuration,
+    /// as well as all enabled GitHub collectors that have a webhook secret.
+    pub fn configure(&self, config: &Configuration) {
+        let mut webhooks = self.webhooks.lock().unwrap();
+        let mut github = self.github.lock().unwrap();
+        webhooks.clear();
+        github.clear();
+        for collector in config.collectors.iter() {
+            if !collector.is_enabled() {
+                continue;
+            }
+            match collector {
+                CollectorConfiguration::Webhook(webhook) => {
+                    webhooks.insert(webhook.id.clone(), webhook.credentials.clone());
+                }
+                CollectorConfiguration::GitHub(config) if config.webhook_secret.is_some() => {
+                    github.insert(config.id.clone(), config.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Gets the credentials for a webhook collector.
+    pub fn get_credentials(&self, id: &str) -> Option<WebhookCredentials> {
+        self.webhooks.lock().unwrap().get(id).cloned()
+    }
+
+    /// Gets the configuration for a GitHub collector that receives webhooks.
+    pub fn get_github(&self, id: &str) -> Option<GitHubConfiguration> {
+        self.github.lock().unwrap().get(id).cloned()
+    }
+
+    /// Requests that a collector should be polled as soon as possible.
+    pub fn request_refresh(&self, id: &str) {
+        self.refresh.lock().unwrap().insert(id.to_owned());
+    }
+
+    /// Takes all collectors that have been requested to be polled.
+    pub fn take_refresh_requests(&self) -> HashSet<String> {
+        std::mem::take(&mut *self.refresh.lock().unwrap())
+    }
+
+    /// Queues a build that has been pushed to Duck.
+    pub fn push(&self, build: Build) {
+        self.queue.lock().unwrap().push(build);
+    }
+
+    /// Takes all queued builds in the order they were pushed.
+    pub fn drain(&self) -> Vec<Build> {
+        self.queue.lock().unwrap().drain(..).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::BuildBuilder;
+    use crate::utils::text::TestVariableProvider;
+
+    fn create_configuration() -> Configuration {
+        Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "webhook": {
+                            "id": "scripts",
+                            "credentials": {
+                                "secret": "SECRET"
+                            }
+                        }
+                    },
+                    {
+                        "github": {
+                            "id": "github",
+                            "owner": "duckhq",
+                            "repository": "duck",
+                            "workflow": "ci.yml",
+                            "credentials": {
+                                "basic": {
+                                    "username": "patrik",
+                                    "password": "SECRET"
+                                }
+                            },
+                            "webhookSecret": "SECRET"
+                        }
+                    },
+                    {
+                        "github": {
+                            "id": "github_polled",
+                            "owner": "duckhq",
+                            "repository": "duck",
+                            "workflow": "ci.yml",
+                            "credentials": {
+                                "basic": {
+                                    "username": "patrik",
+                                    "password": "SECRET"
+                                }
+                            }
+                        }
+                    },
+                    {
+                        "webhook": {
+                            "id": "disabled",
+                            "enabled": false,
+                            "credentials": {
+                                "hmac": "SECRET"
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn should_register_enabled_webhooks() {
+        // Given
+        let repository = IngestRepository::new();
+        // When
+        repository.configure(&create_configuration());
+        // Then
+        assert!(repository.get_credentials("scripts").is_some());
+        assert!(repository.get_credentials("disabled").is_none());
+        assert!(repository.get_credentials("unknown").is_none());
+    }
+
+    #[test]
+    fn should_register_github_collectors_with_webhook_secret() {
+        // Given
+        let repository = IngestRepository::new();
+        // When
+        repository.configure(&create_configuration());
+        // Then
+        assert!(repository.get_github("github").is_some());
+        assert!(repository.get_github("github_polled").is_none());
+        assert!(repository.get_credentials("github").is_none());
+    }
+
+    #[test]
+    fn should_take_refresh_requests() {
+        // Given
+        let repository = IngestRepository::new();
+        repository.request_refresh("github");
+        repository.request_refresh("github");
+        // When
+        let result = repository.take_refresh_requests();
+        // Then
+        assert_eq!(1, result.len());
+        assert!(result.contains("github"));
+        assert!(repository.take_refresh_requests().is_empty());
+    }
+
+    #[test]
+    fn should_drain_builds_in_order() {
+        // Given
+        let repository = IngestRepository::new();
+        repository.push(BuildBuilder::dummy().build_id("1").unwrap());
+        repository.push(BuildBuilder::dummy().build_id("2").unwrap());
+        // When
+        let result = repository.drain();
+        // Then
+        assert_eq!(2, result.len());
+        assert_eq!("1", result[0].build_id);
+        assert_eq!("2", result[1].build_id);
+        assert!(repository.drain().is_empty());
+    }
+}
@@ -32,6 +32,14 @@ fn get_collector_loader(config: &CollectorConfiguration) -> &dyn CollectorLoader
         CollectorConfiguration::GitHub(config) => config,
         CollectorConfiguration::OctopusDeploy(config) => config,
         CollectorConfiguration::AppVeyor(config) => config,
+        CollectorConfiguration::Webhook(config) => config,
+        CollectorConfiguration::ArgoCD(config) => config,
+        CollectorConfiguration::Drone(config) => config,
+        CollectorConfiguration::Buildkite(config) => config,
+        CollectorConfiguration::CircleCI(config) => config,
+        CollectorConfiguration::Bitbucket(config) => config,
+        CollectorConfiguration::Jenkins(config) => config,
+        CollectorConfiguration::GitLab(config) => config,
         CollectorConfiguration::Duck(config) => config,
         CollectorConfiguration::Debugger(config) => config,
     }
@@ -71,5 +79,11 @@ fn get_observer_loader(config: &ObserverConfiguration) -> &dyn ObserverLoader {
         ObserverConfiguration::Hue(config) => config,
         ObserverConfiguration::Mattermost(config) => config,
         ObserverConfiguration::Slack(config) => config,
+        ObserverConfiguration::Teams(config) => config,
+        ObserverConfiguration::Discord(config) => config,
+        ObserverConfiguration::Webhook(config) => config,
+        ObserverConfiguration::Email(config) => config,
+        ObserverConfiguration::Mqtt(config) => config,
+        ObserverConfiguration::HomeAssistant(config) => config,
     }
 }
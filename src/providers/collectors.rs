@@ -4,12 +4,20 @@ use crate::builds::Build;
 use crate::DuckResult;
 
 mod appveyor;
+mod argocd;
 mod azure;
+mod bitbucket;
+mod buildkite;
+mod circleci;
 mod debugger;
+mod drone;
 mod duck;
-mod github;
+pub mod github;
+mod gitlab;
+mod jenkins;
 mod octopus;
 mod teamcity;
+mod webhook;
 
 pub trait CollectorLoader {
     fn load(&self) -> DuckResult<Box<dyn Collector>>;
@@ -22,6 +30,11 @@ pub trait Collector: Send {
         handle: WaitHandleListener,
         callback: &mut dyn FnMut(Build),
     ) -> DuckResult<()>;
+    /// Whether or not the collector should be polled for builds.
+    /// Collectors that have builds pushed to them are never polled.
+    fn is_polled(&self) -> bool {
+        true
+    }
 }
 
 pub struct CollectorInfo {
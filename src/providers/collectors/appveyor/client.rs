@@ -17,6 +17,11 @@ impl AppVeyorClient {
         }
     }
 
+    /// Gets all projects that the credentials have access to.
+    pub fn get_projects(&self, client: &impl HttpClient) -> DuckResult<Vec<AppVeyorProject>> {
+        self.get(client, "https://ci.appveyor.com/api/projects")
+    }
+
     pub fn get_builds(
         &self,
         client: &impl HttpClient,
@@ -30,9 +35,16 @@ impl AppVeyorClient {
             project = project,
             count = count
         );
+        self.get(client, &url)
+    }
 
+    fn get<T: serde::de::DeserializeOwned>(
+        &self,
+        client: &impl HttpClient,
+        url: &str,
+    ) -> DuckResult<T> {
         trace!("Sending request to: {}", url);
-        let mut builder = HttpRequestBuilder::get(&url);
+        let mut builder = HttpRequestBuilder::get(url);
         builder.add_header("Content-Type", "application/json");
         builder.add_header("Accept", "application/json");
 
@@ -57,6 +69,7 @@ impl AppVeyorClient {
 impl AppVeyorCredentials {
     fn authenticate(&self, builder: &mut HttpRequestBuilder) {
         match self {
+            AppVeyorCredentials::Anonymous => {}
             AppVeyorCredentials::Bearer(token) => {
                 builder.bearer(token);
             }
@@ -80,6 +93,7 @@ pub struct AppVeyorProject {
     pub project_id: u64,
     #[serde(alias = "name")]
     pub project_name: String,
+    pub slug: String,
     #[serde(alias = "repositoryName")]
     pub repository_name: String,
 }
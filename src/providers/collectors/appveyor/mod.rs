@@ -1,8 +1,12 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
 use waithandle::WaitHandleListener;
 
 use crate::builds::{Build, BuildBuilder};
 use crate::config::AppVeyorConfiguration;
 use crate::providers::collectors::{Collector, CollectorInfo, CollectorLoader};
+use crate::utils::branches::BranchMatcher;
 use crate::utils::http::*;
 use crate::DuckResult;
 
@@ -13,7 +17,7 @@ mod validation;
 
 impl CollectorLoader for AppVeyorConfiguration {
     fn load(&self) -> DuckResult<Box<dyn Collector>> {
-        Ok(Box::new(AppVeyorCollector::<ReqwestClient>::new(self)))
+        Ok(Box::new(AppVeyorCollector::<ReqwestClient>::new(self)?))
     }
 }
 
@@ -22,30 +26,82 @@ pub struct AppVeyorCollector<T: HttpClient + Default> {
     http: T,
     client: AppVeyorClient,
     account: String,
-    project: String,
+    projects: Option<Vec<String>>,
+    branches: BranchMatcher,
     count: u16,
 }
 
 impl<T: HttpClient + Default> AppVeyorCollector<T> {
-    pub fn new(config: &AppVeyorConfiguration) -> Self {
-        AppVeyorCollector::<T> {
+    pub fn new(config: &AppVeyorConfiguration) -> DuckResult<Self> {
+        Ok(AppVeyorCollector::<T> {
             http: Default::default(),
             client: AppVeyorClient::new(config),
             account: config.account.clone(),
-            project: config.project.clone(),
+            projects: config.get_projects(),
+            branches: BranchMatcher::new(
+                config.branches.as_deref().unwrap_or_default(),
+                config.excluded_branches.as_deref().unwrap_or_default(),
+            )?,
             count: config.get_count(),
             info: CollectorInfo {
                 id: config.id.clone(),
                 enabled: config.enabled.unwrap_or(true),
                 provider: "AppVeyor".to_owned(),
             },
-        }
+        })
     }
 
     #[cfg(test)]
     pub fn get_client(&self) -> &T {
         &self.http
     }
+
+    fn get_projects(&self) -> DuckResult<Vec<String>> {
+        if let Some(projects) = &self.projects {
+            return Ok(projects.clone());
+        }
+        Ok(self
+            .client
+            .get_projects(&self.http)?
+            .into_iter()
+            .filter(|p| p.account_name.eq_ignore_ascii_case(&self.account))
+            .map(|p| p.slug)
+            .collect())
+    }
+
+    fn create_build(
+        &self,
+        project: &str,
+        result: &AppVeyorResponse,
+        build: &AppVeyorBuild,
+    ) -> DuckResult<Build> {
+        Ok(BuildBuilder::new()
+            .build_id(build.build_id.to_string())
+            .provider("AppVeyor")
+            .origin(format!(
+                "https://ci.appveyor.com/project/{account}/{project}",
+                account = self.account,
+                project = project
+            ))
+            .collector(&self.info.id)
+            .project_id(&result.project.project_id.to_string())
+            .project_name(&result.project.repository_name)
+            .definition_id(&result.project.account_id.to_string())
+            .definition_name(&result.project.account_name)
+            .build_number(&build.build_number.to_string())
+            .status(build.get_status())
+            .url(format!(
+                "https://ci.appveyor.com/project/{account}/{project}/builds/{id}",
+                account = self.account,
+                project = project,
+                id = build.build_id
+            ))
+            .started_at(build.get_started_timestamp()?)
+            .finished_at(build.get_finished_timestamp()?)
+            .branch(&build.branch)
+            .build()
+            .unwrap())
+    }
 }
 
 impl<T: HttpClient + Default> Collector for AppVeyorCollector<T> {
@@ -58,46 +114,31 @@ impl<T: HttpClient + Default> Collector for AppVeyorCollector<T> {
         listener: WaitHandleListener,
         callback: &mut dyn FnMut(Build),
     ) -> DuckResult<()> {
-        let result =
-            self.client
-                .get_builds(&self.http, &self.account, &self.project, self.count)?;
-
-        for (count, build) in result.builds.iter().enumerate() {
+        for project in self.get_projects()?.iter() {
             if listener.check() {
-                break;
+                return Ok(());
             }
-            if count >= self.count as usize {
-                break;
+
+            let result = self
+                .client
+                .get_builds(&self.http, &self.account, project, self.count)?;
+
+            // The builds are ordered with the most recent first,
+            // so the first build for a branch is the latest one.
+            let mut seen = HashSet::<&str>::new();
+            for build in result.builds.iter() {
+                if listener.check() {
+                    return Ok(());
+                }
+                if self.branches.is_match(&build.branch) && seen.insert(&build.branch) {
+                    callback(self.create_build(project, &result, build)?);
+                }
             }
 
-            callback(
-                BuildBuilder::new()
-                    .build_id(build.build_id.to_string())
-                    .provider("AppVeyor")
-                    .origin(format!(
-                        "https://ci.appveyor.com/project/{account}/{project}",
-                        account = self.account,
-                        project = self.project
-                    ))
-                    .collector(&self.info.id)
-                    .project_id(&result.project.project_id.to_string())
-                    .project_name(&result.project.repository_name)
-                    .definition_id(&result.project.account_id.to_string())
-                    .definition_name(&result.project.account_name)
-                    .build_number(&build.build_number.to_string())
-                    .status(build.get_status())
-                    .url(format!(
-                        "https://ci.appveyor.com/project/{account}/{project}/builds/{id}",
-                        account = self.account,
-                        project = self.project,
-                        id = build.build_id
-                    ))
-                    .started_at(build.get_started_timestamp()?)
-                    .finished_at(build.get_finished_timestamp()?)
-                    .branch(&build.branch)
-                    .build()
-                    .unwrap(),
-            );
+            // Wait for a little time between calls.
+            if listener.wait(Duration::from_millis(300)) {
+                return Ok(());
+            }
         }
 
         Ok(())
@@ -112,15 +153,51 @@ mod tests {
     use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
     use reqwest::StatusCode;
 
+    static BUILDS_URL: &str = "https://ci.appveyor.com/api/projects/patriksvensson/spectre-commandline/history?recordsNumber=20";
+
     fn create_collector() -> AppVeyorCollector<MockHttpClient> {
-        AppVeyorCollector::<MockHttpClient>::new(&AppVeyorConfiguration {
+        create_collector_with(|_| {})
+    }
+
+    fn create_collector_with(
+        configure: impl FnOnce(&mut AppVeyorConfiguration),
+    ) -> AppVeyorCollector<MockHttpClient> {
+        let mut config = AppVeyorConfiguration {
             id: "appveyor".to_owned(),
             enabled: Some(true),
+            interval: None,
+            timeout: None,
             account: "patriksvensson".to_owned(),
-            project: "spectre-commandline".to_owned(),
+            project: Some("spectre-commandline".to_owned()),
+            projects: None,
+            branches: None,
+            excluded_branches: None,
             credentials: AppVeyorCredentials::Bearer("SECRET".to_owned()),
             count: Option::None,
-        })
+        };
+        configure(&mut config);
+        AppVeyorCollector::<MockHttpClient>::new(&config).unwrap()
+    }
+
+    fn collect(collector: &AppVeyorCollector<MockHttpClient>) -> Vec<Build> {
+        let (_, listener) = waithandle::new();
+        let mut result = Vec::<Build>::new();
+        collector
+            .collect(listener, &mut |build: Build| {
+                result.push(build);
+            })
+            .unwrap();
+        result
+    }
+
+    /// Gets the test builds where the second and
+    /// third builds are made from other branches.
+    fn get_builds_with_branches() -> String {
+        include_str!("test_data/builds.json")
+            .replacen(r#""branch": "master""#, r#""branch": "MASTER""#, 1)
+            .replacen(r#""branch": "master""#, r#""branch": "develop""#, 1)
+            .replacen(r#""branch": "master""#, r#""branch": "feature/foo""#, 1)
+            .replacen(r#""branch": "MASTER""#, r#""branch": "master""#, 1)
     }
 
     #[test]
@@ -140,12 +217,9 @@ mod tests {
         let client = appveyor.get_client();
 
         client.add_response(
-            MockHttpResponseBuilder::new(
-                HttpMethod::Get,
-                "https://ci.appveyor.com/api/projects/patriksvensson/spectre-commandline/history?recordsNumber=1"
-            )
-            .returns_status(StatusCode::OK)
-            .returns_body(include_str!("test_data/builds.json"))
+            MockHttpResponseBuilder::new(HttpMethod::Get, BUILDS_URL)
+                .returns_status(StatusCode::OK)
+                .returns_body(include_str!("test_data/builds.json")),
         );
 
         let (_, listener) = waithandle::new();
@@ -178,4 +252,110 @@ mod tests {
         assert_eq!(1583929960, result[0].started_at);
         assert_eq!(1583930062, result[0].finished_at.unwrap());
     }
+
+    #[test]
+    fn should_get_latest_build_per_branch() {
+        // Given
+        let appveyor = create_collector();
+        appveyor.get_client().add_response(
+            MockHttpResponseBuilder::new(HttpMethod::Get, BUILDS_URL)
+                .returns_status(StatusCode::OK)
+                .returns_body(get_builds_with_branches()),
+        );
+
+        // When
+        let result = collect(&appveyor);
+
+        // Then
+        assert_eq!(3, result.len());
+        assert_eq!("31395671", result[0].build_id);
+        assert_eq!("master", result[0].branch);
+        assert_eq!("31395124", result[1].build_id);
+        assert_eq!("develop", result[1].branch);
+        assert_eq!("31394481", result[2].build_id);
+        assert_eq!("feature/foo", result[2].branch);
+    }
+
+    #[test]
+    fn should_only_get_builds_for_matching_branches() {
+        // Given
+        let appveyor = create_collector_with(|config| {
+            config.excluded_branches = Some(vec!["feature/*".to_owned()]);
+        });
+        appveyor.get_client().add_response(
+            MockHttpResponseBuilder::new(HttpMethod::Get, BUILDS_URL)
+                .returns_status(StatusCode::OK)
+                .returns_body(get_builds_with_branches()),
+        );
+
+        // When
+        let result = collect(&appveyor);
+
+        // Then
+        assert_eq!(2, result.len());
+        assert_eq!("master", result[0].branch);
+        assert_eq!("develop", result[1].branch);
+    }
+
+    #[test]
+    fn should_get_builds_for_all_projects_in_account() {
+        // Given
+        let appveyor = create_collector_with(|config| {
+            config.project = None;
+        });
+        let client = appveyor.get_client();
+        client.add_response(
+            MockHttpResponseBuilder::new(HttpMethod::Get, "https://ci.appveyor.com/api/projects")
+                .returns_status(StatusCode::OK)
+                .returns_body(include_str!("test_data/projects.json")),
+        );
+        client.add_response(
+            MockHttpResponseBuilder::new(HttpMethod::Get, BUILDS_URL)
+                .returns_status(StatusCode::OK)
+                .returns_body(include_str!("test_data/builds.json")),
+        );
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://ci.appveyor.com/api/projects/patriksvensson/duck/history?recordsNumber=20",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/builds.json")),
+        );
+
+        // When
+        let result = collect(&appveyor);
+
+        // Then
+        let requests = client.get_sent_requests();
+        assert_eq!(2, result.len());
+        assert_eq!(3, requests.len());
+        assert!(requests[2].url.contains("/patriksvensson/duck/"));
+        assert_eq!(
+            "https://ci.appveyor.com/project/patriksvensson/duck",
+            result[1].origin
+        );
+    }
+
+    #[test]
+    fn should_not_authenticate_anonymous_requests() {
+        // Given
+        let appveyor = create_collector_with(|config| {
+            config.credentials = AppVeyorCredentials::Anonymous;
+        });
+        let client = appveyor.get_client();
+        client.add_response(
+            MockHttpResponseBuilder::new(HttpMethod::Get, BUILDS_URL)
+                .returns_status(StatusCode::OK)
+                .returns_body(include_str!("test_data/builds.json")),
+        );
+
+        // When
+        collect(&appveyor);
+
+        // Then
+        let requests = client.get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert!(!requests[0].headers.contains_key("Authorization"));
+    }
 }
@@ -1,4 +1,5 @@
-use crate::config::{AppVeyorConfiguration, Validate};
+use crate::config::{AppVeyorConfiguration, AppVeyorCredentials, Validate};
+use crate::utils::branches::BranchMatcher;
 use crate::DuckResult;
 
 impl Validate for AppVeyorConfiguration {
@@ -6,16 +7,37 @@ impl Validate for AppVeyorConfiguration {
         if self.account.is_empty() {
             return Err(format_err!("[{}] AppVeyor account is empty", self.id));
         }
-        if self.project.is_empty() {
-            return Err(format_err!("[{}] AppVeyor project is empty", self.id));
+        let projects = self.get_projects();
+        if let Some(projects) = &projects {
+            if projects.iter().any(|p| p.is_empty()) {
+                return Err(format_err!("[{}] AppVeyor project is empty", self.id));
+            }
         }
         match &self.credentials {
-            crate::config::AppVeyorCredentials::Bearer(token) => {
+            AppVeyorCredentials::Anonymous => {
+                if projects.is_none() {
+                    return Err(format_err!(
+                        "[{}] AppVeyor projects must be specified when using anonymous credentials",
+                        self.id
+                    ));
+                }
+            }
+            AppVeyorCredentials::Bearer(token) => {
                 if token.is_empty() {
                     return Err(format_err!("[{}] AppVeyor bearer token is empty", self.id));
                 }
             }
         };
+        if let Err(e) = BranchMatcher::new(
+            self.branches.as_deref().unwrap_or_default(),
+            self.excluded_branches.as_deref().unwrap_or_default(),
+        ) {
+            return Err(format_err!(
+                "[{}] AppVeyor branches are invalid. {}",
+                self.id,
+                e
+            ));
+        }
 
         Ok(())
     }
@@ -126,4 +148,46 @@ mod tests {
         }"#,
         );
     }
+
+    #[test]
+    #[should_panic(
+        expected = "[appveyor_spectrecli] AppVeyor projects must be specified when using anonymous credentials"
+    )]
+    fn should_return_error_if_projects_are_not_specified_for_anonymous_credentials() {
+        create_collectors_from_config(
+            r#"
+        {
+            "collectors": [
+                {
+                    "appveyor": {
+                        "id": "appveyor_spectrecli",
+                        "credentials": "anonymous",
+                        "account": "patriksvensson"
+                    }
+                }
+            ]
+        }"#,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "[appveyor_spectrecli] AppVeyor branches are invalid")]
+    fn should_return_error_if_branch_pattern_is_invalid() {
+        create_collectors_from_config(
+            r#"
+        {
+            "collectors": [
+                {
+                    "appveyor": {
+                        "id": "appveyor_spectrecli",
+                        "credentials": "anonymous",
+                        "account": "patriksvensson",
+                        "projects": [ "spectre-commandline", "duck" ],
+                        "branches": [ "regex:release/(" ]
+                    }
+                }
+            ]
+        }"#,
+        );
+    }
 }
@@ -0,0 +1,235 @@
+use log::trace;
+use url::Url;
+
+use crate::builds::BuildStatus;
+use crate::config::{ArgoCDConfiguration, ArgoCDCredentials};
+use crate::utils::date;
+use crate::utils::http::*;
+use crate::DuckResult;
+
+pub struct ArgoCDClient {
+    pub server_url: Url,
+    credentials: ArgoCDCredentials,
+}
+
+impl ArgoCDClient {
+    pub fn new(config: &ArgoCDConfiguration) -> Self {
+        Self {
+            server_url: Url::parse(&config.server_url[..]).unwrap(),
+            credentials: config.credentials.clone(),
+        }
+    }
+
+    pub fn get_applications(&self, client: &impl HttpClient) -> DuckResult<ArgoCDApplications> {
+        let url = self.get_url(&["api", "v1", "applications"])?;
+
+        trace!("Sending request to: {}", url);
+        let mut builder = HttpRequestBuilder::get(url.as_str());
+        builder.add_header("Content-Type", "application/json");
+        builder.add_header("Accept", "application/json");
+
+        self.credentials.authenticate(&mut builder);
+        let mut response = client.send(&builder)?;
+
+        trace!("Received response: {}", response.status());
+        if !response.status().is_success() {
+            return Err(format_err!(
+                "Received non 200 HTTP status code. ({})",
+                response.status()
+            ));
+        }
+
+        // Get the response body.
+        let body = response.body()?;
+        // Deserialize and return the value.
+        Ok(serde_json::from_str(&body[..])?)
+    }
+
+    pub fn get_application_url(&self, application: &ArgoCDApplication) -> DuckResult<String> {
+        let url = self.get_url(&["applications", &application.metadata.name])?;
+        Ok(url.to_string())
+    }
+
+    fn get_url(&self, parts: &[&str]) -> DuckResult<Url> {
+        let mut url = self.server_url.clone();
+        {
+            let mut segments = url
+                .path_segments_mut()
+                .map_err(|_| format_err!("Invalid Argo CD server URL"))?;
+            segments.pop_if_empty();
+            segments.extend(parts);
+        }
+        Ok(url)
+    }
+}
+
+impl ArgoCDCredentials {
+    fn authenticate(&self, builder: &mut HttpRequestBuilder) {
+        match self {
+            ArgoCDCredentials::Token(token) => {
+                builder.bearer(token);
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ArgoCDApplications {
+    // Argo CD returns null instead of an
+    // empty list when there are no applications.
+    pub items: Option<Vec<ArgoCDApplication>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ArgoCDApplication {
+    pub metadata: ArgoCDMetadata,
+    pub spec: ArgoCDSpec,
+    pub status: ArgoCDStatus,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ArgoCDMetadata {
+    pub name: String,
+    #[serde(rename = "creationTimestamp")]
+    pub creation_timestamp: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ArgoCDSpec {
+    pub source: Option<ArgoCDSource>,
+    pub destination: ArgoCDDestination,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ArgoCDSource {
+    #[serde(rename = "targetRevision")]
+    pub target_revision: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ArgoCDDestination {
+    pub server: Option<String>,
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ArgoCDStatus {
+    pub sync: ArgoCDSyncStatus,
+    pub health: ArgoCDHealthStatus,
+    #[serde(rename = "operationState")]
+    pub operation_state: Option<ArgoCDOperationState>,
+    #[serde(default)]
+    pub history: Vec<ArgoCDHistory>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ArgoCDSyncStatus {
+    pub status: String,
+    pub revision: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ArgoCDHealthStatus {
+    pub status: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ArgoCDOperationState {
+    pub phase: String,
+    #[serde(rename = "startedAt")]
+    pub started_at: String,
+    #[serde(rename = "finishedAt")]
+    pub finished_at: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ArgoCDHistory {
+    #[serde(rename = "deployedAt")]
+    pub deployed_at: String,
+}
+
+impl ArgoCDApplication {
+    pub fn get_environment(&self) -> String {
+        let destination = &self.spec.destination;
+        let cluster = destination
+            .name
+            .as_ref()
+            .or(destination.server.as_ref())
+            .map(|c| &c[..])
+            .unwrap_or("in-cluster");
+        match &destination.namespace {
+            Some(namespace) if !namespace.is_empty() => format!("{}/{}", cluster, namespace),
+            _ => cluster.to_owned(),
+        }
+    }
+
+    pub fn get_branch(&self) -> &str {
+        self.spec
+            .source
+            .as_ref()
+            .and_then(|s| s.target_revision.as_deref())
+            .filter(|r| !r.is_empty())
+            .unwrap_or("HEAD")
+    }
+
+    pub fn get_revision(&self) -> &str {
+        self.status.sync.revision.as_deref().unwrap_or("unknown")
+    }
+
+    pub fn get_build_number(&self) -> String {
+        // Git revisions are shortened,
+        // but Helm chart versions are not.
+        let revision = self.get_revision();
+        if revision.len() == 40 && revision.chars().all(|c| c.is_ascii_hexdigit()) {
+            revision[..7].to_owned()
+        } else {
+            revision.to_owned()
+        }
+    }
+
+    pub fn get_status(&self) -> BuildStatus {
+        let phase = self.status.operation_state.as_ref().map(|o| &o.phase[..]);
+        if let Some("Running") | Some("Terminating") = phase {
+            return BuildStatus::Running;
+        }
+        if let Some("Failed") | Some("Error") = phase {
+            return BuildStatus::Failed;
+        }
+        match (&self.status.sync.status[..], &self.status.health.status[..]) {
+            (_, "Progressing") => BuildStatus::Running,
+            ("OutOfSync", _) | (_, "Degraded") | (_, "Missing") => BuildStatus::Failed,
+            // A suspended application is waiting for
+            // something, such as a paused rollout.
+            (_, "Suspended") => BuildStatus::Blocked,
+            ("Synced", "Healthy") => BuildStatus::Success,
+            _ => BuildStatus::Unknown,
+        }
+    }
+
+    pub fn get_started_timestamp(&self) -> DuckResult<i64> {
+        let started_at = match &self.status.operation_state {
+            Some(operation) => &operation.started_at,
+            None => match self.status.history.last() {
+                Some(history) => &history.deployed_at,
+                None => &self.metadata.creation_timestamp,
+            },
+        };
+        let result = date::to_timestamp(started_at, date::ARGOCD_FORMAT)?;
+        Ok(result)
+    }
+
+    pub fn get_finished_timestamp(&self) -> DuckResult<Option<i64>> {
+        if self.get_status() == BuildStatus::Running {
+            return Ok(None);
+        }
+        let finished_at = match &self.status.operation_state {
+            Some(operation) => operation.finished_at.as_ref(),
+            None => self.status.history.last().map(|h| &h.deployed_at),
+        };
+        match finished_at {
+            Some(finished_at) => Ok(Some(date::to_timestamp(finished_at, date::ARGOCD_FORMAT)?)),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use waithandle::WaitHandleListener;
+
+use crate::builds::{Build, BuildBuilder};
+use crate::config::ArgoCDConfiguration;
+use crate::providers::collectors::{Collector, CollectorInfo, CollectorLoader};
+use crate::utils::http::{HttpClient, ReqwestClient};
+use crate::DuckResult;
+
+use self::client::{ArgoCDApplication, ArgoCDClient};
+
+mod client;
+mod validation;
+
+impl CollectorLoader for ArgoCDConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Collector>> {
+        Ok(Box::new(ArgoCDCollector::<ReqwestClient>::new(self)))
+    }
+}
+
+pub struct ArgoCDCollector<T: HttpClient + Default> {
+    client: ArgoCDClient,
+    http: T,
+    applications: Option<Vec<String>>,
+    info: CollectorInfo,
+}
+
+impl<T: HttpClient + Default> ArgoCDCollector<T> {
+    pub fn new(config: &ArgoCDConfiguration) -> Self {
+        return ArgoCDCollector {
+            client: ArgoCDClient::new(config),
+            http: Default::default(),
+            applications: config.applications.clone(),
+            info: CollectorInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                provider: "ArgoCD".to_owned(),
+            },
+        };
+    }
+
+    #[cfg(test)]
+    pub fn get_client(&self) -> &T {
+        &self.http
+    }
+
+    fn is_included(&self, application: &ArgoCDApplication) -> bool {
+        match &self.applications {
+            None => true,
+            Some(applications) => applications.contains(&application.metadata.name),
+        }
+    }
+}
+
+impl<T: HttpClient + Default> Collector for ArgoCDCollector<T> {
+    fn info(&self) -> &CollectorInfo {
+        &self.info
+    }
+
+    fn collect(
+        &self,
+        listener: WaitHandleListener,
+        callback: &mut dyn FnMut(Build),
+    ) -> DuckResult<()> {
+        let response = self.client.get_applications(&self.http)?;
+
+        for application in response.items.iter().flatten() {
+            if listener.check() {
+                return Ok(());
+            }
+            if !self.is_included(application) {
+                continue;
+            }
+
+            let environment = application.get_environment();
+            callback(
+                BuildBuilder::new()
+                    .build_id(application.get_revision())
+                    .provider("ArgoCD")
+                    .origin(self.client.server_url.as_str())
+                    .collector(&self.info.id)
+                    .project_id(&application.metadata.name)
+                    .project_name(&application.metadata.name)
+                    .definition_id(&environment)
+                    .definition_name(&environment)
+                    .build_number(application.get_build_number())
+                    .status(application.get_status())
+                    .url(self.client.get_application_url(application)?)
+                    .started_at(application.get_started_timestamp()?)
+                    .finished_at(application.get_finished_timestamp()?)
+                    .branch(application.get_branch())
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::BuildStatus;
+    use crate::config::*;
+    use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
+    use reqwest::StatusCode;
+    use test_case::test_case;
+
+    fn create_collector(applications: Option<Vec<&str>>) -> ArgoCDCollector<MockHttpClient> {
+        let collector = ArgoCDCollector::<MockHttpClient>::new(&ArgoCDConfiguration {
+            id: "argocd".to_owned(),
+            enabled: Some(true),
+            interval: None,
+            timeout: None,
+            server_url: "https://argocd.example.com".to_owned(),
+            credentials: ArgoCDCredentials::Token("SECRET".to_owned()),
+            applications: applications.map(|a| a.iter().map(|s| s.to_string()).collect()),
+        });
+
+        collector.get_client().add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://argocd.example.com/api/v1/applications",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/applications.json")),
+        );
+
+        collector
+    }
+
+    fn collect(collector: &ArgoCDCollector<MockHttpClient>) -> Vec<Build> {
+        let (_, listener) = waithandle::new();
+        let mut result = Vec::<Build>::new();
+        collector
+            .collect(listener, &mut |build: Build| {
+                result.push(build);
+            })
+            .unwrap();
+        result
+    }
+
+    fn create_application(
+        sync: &str,
+        health: &str,
+        phase: Option<&str>,
+    ) -> client::ArgoCDApplication {
+        let operation = match phase {
+            Some(phase) => format!(
+                r#", "operationState": {{ "phase": "{}", "startedAt": "2020-02-01T20:43:16Z" }}"#,
+                phase
+            ),
+            None => "".to_owned(),
+        };
+        serde_json::from_str(&format!(
+            r#"{{
+                "metadata": {{ "name": "duck", "creationTimestamp": "2020-02-01T20:43:16Z" }},
+                "spec": {{ "destination": {{ "namespace": "duck" }} }},
+                "status": {{
+                    "sync": {{ "status": "{}" }},
+                    "health": {{ "status": "{}" }}
+                    {}
+                }}
+            }}"#,
+            sync, health, operation
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn should_return_correct_provider_name() {
+        // Given
+        let argocd = create_collector(None);
+        // When
+        let provider = &argocd.info().provider;
+        // Then
+        assert_eq!("ArgoCD", provider);
+    }
+
+    #[test]
+    fn should_get_correct_data() {
+        // Given
+        let argocd = create_collector(None);
+
+        // When
+        let result = collect(&argocd);
+
+        // Then
+        assert_eq!(3, result.len());
+        assert_eq!(
+            "a91957a858320c0e17f3a0eca7cfacbff50ea29a",
+            result[0].build_id
+        );
+        assert_eq!("ArgoCD", result[0].provider);
+        assert_eq!("argocd", result[0].collector);
+        assert_eq!("https://argocd.example.com/", result[0].origin);
+        assert_eq!("duck-production", result[0].project_id);
+        assert_eq!("duck-production", result[0].project_name);
+        assert_eq!("production/duck", result[0].definition_id);
+        assert_eq!("production/duck", result[0].definition_name);
+        assert_eq!("a91957a", result[0].build_number);
+        assert_eq!(BuildStatus::Success, result[0].status);
+        assert_eq!("main", result[0].branch);
+        assert_eq!(
+            "https://argocd.example.com/applications/duck-production",
+            result[0].url
+        );
+        assert_eq!(1580589796, result[0].started_at);
+        assert_eq!(Some(1580590096), result[0].finished_at);
+        assert_eq!(
+            "https://kubernetes.default.svc/duck-staging",
+            result[1].definition_id
+        );
+        assert_eq!(BuildStatus::Running, result[1].status);
+        assert_eq!(None, result[1].finished_at);
+        assert_eq!("1.2.3", result[2].build_number);
+        assert_eq!("HEAD", result[2].branch);
+        assert_eq!(BuildStatus::Failed, result[2].status);
+    }
+
+    #[test]
+    fn should_only_include_specified_applications() {
+        // Given
+        let argocd = create_collector(Some(vec!["duck-staging"]));
+
+        // When
+        let result = collect(&argocd);
+
+        // Then
+        assert_eq!(1, result.len());
+        assert_eq!("duck-staging", result[0].project_id);
+    }
+
+    #[test]
+    fn should_authenticate_using_token() {
+        // Given
+        let argocd = create_collector(None);
+
+        // When
+        collect(&argocd);
+
+        // Then
+        let requests = argocd.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(
+            "Bearer SECRET",
+            requests[0].headers.get("Authorization").unwrap()
+        );
+    }
+
+    #[test_case("Synced", "Healthy", None, BuildStatus::Success ; "Synced and healthy")]
+    #[test_case("OutOfSync", "Healthy", None, BuildStatus::Failed ; "Out of sync")]
+    #[test_case("Synced", "Degraded", None, BuildStatus::Failed ; "Degraded")]
+    #[test_case("Synced", "Missing", None, BuildStatus::Failed ; "Missing")]
+    #[test_case("Synced", "Progressing", None, BuildStatus::Running ; "Progressing")]
+    #[test_case("OutOfSync", "Progressing", None, BuildStatus::Running ; "Out of sync and progressing")]
+    #[test_case("Synced", "Suspended", None, BuildStatus::Blocked ; "Suspended")]
+    #[test_case("Unknown", "Unknown", None, BuildStatus::Unknown ; "Unknown")]
+    #[test_case("OutOfSync", "Healthy", Some("Running"), BuildStatus::Running ; "Sync running")]
+    #[test_case("Synced", "Healthy", Some("Failed"), BuildStatus::Failed ; "Sync failed")]
+    #[test_case("Synced", "Healthy", Some("Succeeded"), BuildStatus::Success ; "Sync succeeded")]
+    fn should_map_application_status(
+        sync: &str,
+        health: &str,
+        phase: Option<&str>,
+        expected: BuildStatus,
+    ) {
+        // Given
+        let application = create_application(sync, health, phase);
+        // When
+        let result = application.get_status();
+        // Then
+        assert_eq!(expected, result);
+    }
+}
@@ -0,0 +1,117 @@
+use url::Url;
+
+use crate::config::{ArgoCDConfiguration, ArgoCDCredentials, Validate};
+use crate::DuckResult;
+
+impl Validate for ArgoCDConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        if let Err(e) = Url::parse(&self.server_url[..]) {
+            return Err(format_err!(
+                "[{}] Argo CD server URL is invalid: {}",
+                self.id,
+                e
+            ));
+        }
+        if let Some(applications) = &self.applications {
+            if applications.iter().any(|a| a.is_empty()) {
+                return Err(format_err!("[{}] An Argo CD application is empty", self.id));
+            }
+        }
+
+        match &self.credentials {
+            ArgoCDCredentials::Token(token) => {
+                if token.is_empty() {
+                    return Err(format_err!("[{}] Argo CD token is empty", self.id));
+                }
+            }
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(expected = "[argocd] Argo CD server URL is invalid")]
+    fn should_return_error_if_argocd_server_url_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "argocd": {
+                            "id": "argocd",
+                            "serverUrl": "not a url",
+                            "credentials": {
+                                "token": "SECRET"
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[argocd] An Argo CD application is empty")]
+    fn should_return_error_if_argocd_application_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "argocd": {
+                            "id": "argocd",
+                            "serverUrl": "https://argocd.example.com",
+                            "credentials": {
+                                "token": "SECRET"
+                            },
+                            "applications": [ "duck", "" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[argocd] Argo CD token is empty")]
+    fn should_return_error_if_argocd_token_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "argocd": {
+                            "id": "argocd",
+                            "serverUrl": "https://argocd.example.com",
+                            "credentials": {
+                                "token": ""
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+}
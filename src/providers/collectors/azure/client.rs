@@ -7,6 +7,7 @@ use crate::DuckResult;
 
 pub struct AzureDevOpsClient {
     server_url: Url,
+    release_url: Url,
     organization: String,
     project: String,
     credentials: AzureDevOpsCredentials,
@@ -19,6 +20,12 @@ impl AzureDevOpsClient {
                 Some(url) => Url::parse(&url[..]).unwrap(),
                 None => Url::parse("https://dev.azure.com").unwrap(),
             },
+            // Releases are served from another host in Azure DevOps
+            // Services, but from the same host in Azure DevOps Server.
+            release_url: match &config.server_url {
+                Some(url) => Url::parse(&url[..]).unwrap(),
+                None => Url::parse("https://vsrm.dev.azure.com").unwrap(),
+            },
             organization: config.organization.clone(),
             project: config.project.clone(),
             credentials: config.credentials.clone(),
@@ -51,9 +58,70 @@ impl AzureDevOpsClient {
             branch = branch,
             definitions = definitions.join(","),
         );
+        self.get(client, &url)
+    }
+
+    /// Gets the most recent builds for all branches,
+    /// which is used to discover branches matching a pattern.
+    pub fn get_recent_builds(
+        &self,
+        client: &impl HttpClient,
+        definitions: &[String],
+    ) -> DuckResult<AzureResponse> {
+        let url = format!(
+            "{server}{organization}/{project}/_apis/build/builds?api-version=5.0\
+             &definitions={definitions}&$top=200\
+             &queryOrder=startTimeDescending&deletedFilter=excludeDeleted\
+             &statusFilter=cancelling,completed,inProgress",
+            server = self.server_url,
+            organization = self.organization,
+            project = self.project,
+            definitions = definitions.join(","),
+        );
+        self.get(client, &url)
+    }
 
+    /// Gets the timeline of a build, which
+    /// contains the stages of YAML pipelines.
+    pub fn get_timeline(
+        &self,
+        client: &impl HttpClient,
+        build_id: u64,
+    ) -> DuckResult<AzureTimeline> {
+        let url = format!(
+            "{server}{organization}/{project}/_apis/build/builds/{id}/timeline?api-version=5.0",
+            server = self.server_url,
+            organization = self.organization,
+            project = self.project,
+            id = build_id,
+        );
+        self.get(client, &url)
+    }
+
+    pub fn get_deployments(
+        &self,
+        client: &impl HttpClient,
+        definition_id: &str,
+    ) -> DuckResult<AzureDeploymentResponse> {
+        let url = format!(
+            "{server}{organization}/{project}/_apis/release/deployments?api-version=5.0\
+             &definitionId={definition}&latestAttemptsOnly=true\
+             &queryOrder=descending&$top=100",
+            server = self.release_url,
+            organization = self.organization,
+            project = self.project,
+            definition = definition_id,
+        );
+        self.get(client, &url)
+    }
+
+    fn get<T: serde::de::DeserializeOwned>(
+        &self,
+        client: &impl HttpClient,
+        url: &str,
+    ) -> DuckResult<T> {
         trace!("Sending request to: {}", url);
-        let mut builder = HttpRequestBuilder::get(&url);
+        let mut builder = HttpRequestBuilder::get(url);
         builder.add_header("Content-Type", "application/json");
         builder.add_header("Accept", "application/json");
 
@@ -131,3 +199,65 @@ pub struct AzureBuildDefinition {
     pub id: u64,
     pub name: String,
 }
+
+#[derive(Deserialize, Debug)]
+pub struct AzureTimeline {
+    pub records: Vec<AzureTimelineRecord>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AzureTimelineRecord {
+    #[serde(rename = "type")]
+    pub record_type: String,
+    pub name: String,
+    pub identifier: Option<String>,
+    pub order: Option<u32>,
+    pub state: Option<String>,
+    pub result: Option<String>,
+    #[serde(alias = "startTime")]
+    pub start_time: Option<String>,
+    #[serde(alias = "finishTime")]
+    pub finish_time: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AzureDeploymentResponse {
+    pub value: Vec<AzureDeployment>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AzureDeployment {
+    pub id: u64,
+    pub release: AzureRelease,
+    #[serde(alias = "releaseDefinition")]
+    pub release_definition: AzureReleaseReference,
+    #[serde(alias = "releaseEnvironment")]
+    pub release_environment: AzureReleaseReference,
+    #[serde(alias = "definitionEnvironmentId")]
+    pub definition_environment_id: u64,
+    #[serde(alias = "projectReference")]
+    pub project: AzureProject,
+    #[serde(alias = "deploymentStatus")]
+    pub deployment_status: String,
+    #[serde(alias = "operationStatus")]
+    pub operation_status: String,
+    #[serde(alias = "queuedOn")]
+    pub queued_on: String,
+    #[serde(alias = "startedOn")]
+    pub started_on: Option<String>,
+    #[serde(alias = "completedOn")]
+    pub completed_on: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AzureRelease {
+    pub name: String,
+    #[serde(alias = "_links")]
+    pub links: AzureLinks,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AzureReleaseReference {
+    pub id: u64,
+    pub name: String,
+}
@@ -1,11 +1,14 @@
+use std::collections::HashSet;
 use std::time::Duration;
 
+use log::warn;
 use waithandle::WaitHandleListener;
 
 use crate::builds::{Build, BuildBuilder, BuildStatus};
-use crate::config::AzureDevOpsConfiguration;
-use crate::providers::collectors::azure::client::{AzureBuild, AzureDevOpsClient};
+use crate::config::{AzureDevOpsConfiguration, AzureDevOpsRelease};
+use crate::providers::collectors::azure::client::*;
 use crate::providers::collectors::{Collector, CollectorInfo, CollectorLoader};
+use crate::utils::branches::BranchMatcher;
 use crate::utils::date;
 use crate::utils::http::*;
 use crate::DuckResult;
@@ -15,54 +18,145 @@ mod validation;
 
 impl CollectorLoader for AzureDevOpsConfiguration {
     fn load(&self) -> DuckResult<Box<dyn Collector>> {
-        Ok(Box::new(AzureDevOpsCollector::<ReqwestClient>::new(self)))
+        Ok(Box::new(AzureDevOpsCollector::<ReqwestClient>::new(self)?))
     }
 }
 
 pub struct AzureDevOpsCollector<T: HttpClient + Default> {
     http: T,
     client: AzureDevOpsClient,
-    branches: Vec<String>,
+    branches: BranchMatcher,
     definitions: Vec<String>,
+    stages: bool,
+    releases: Vec<AzureDevOpsRelease>,
     info: CollectorInfo,
 }
 
 impl<T: HttpClient + Default> AzureDevOpsCollector<T> {
-    pub fn new(config: &AzureDevOpsConfiguration) -> Self {
-        AzureDevOpsCollector {
+    pub fn new(config: &AzureDevOpsConfiguration) -> DuckResult<Self> {
+        Ok(AzureDevOpsCollector {
             http: Default::default(),
             client: AzureDevOpsClient::new(config),
-            branches: config.branches.clone(),
+            branches: BranchMatcher::new(
+                &config.branches,
+                config.excluded_branches.as_deref().unwrap_or_default(),
+            )?,
             definitions: config.definitions.clone(),
+            stages: config.stages.unwrap_or(false),
+            releases: config.releases.clone().unwrap_or_default(),
             info: CollectorInfo {
                 id: config.id.clone(),
                 enabled: config.enabled.unwrap_or(true),
                 provider: "AzureDevOps".to_string(),
             },
-        }
+        })
     }
 
     #[cfg(test)]
     pub fn get_client(&self) -> &T {
         &self.http
     }
-}
 
-impl<T: HttpClient + Default> Collector for AzureDevOpsCollector<T> {
-    fn info(&self) -> &CollectorInfo {
-        &self.info
+    fn create_build(&self, build: &AzureBuild) -> DuckResult<BuildBuilder> {
+        Ok(BuildBuilder::new()
+            .build_id(build.id.to_string())
+            .provider("AzureDevOps")
+            .origin(&self.client.get_origin())
+            .collector(&self.info.id)
+            .project_id(&build.project.id)
+            .project_name(&build.project.name)
+            .definition_id(build.definition.id.to_string())
+            .definition_name(&build.definition.name)
+            .build_number(&build.build_number)
+            .status(build.get_build_status())
+            .url(&build.links.web.href)
+            .started_at(date::to_timestamp(
+                &build.start_time,
+                date::AZURE_DEVOPS_FORMAT,
+            )?)
+            .finished_at(match &build.finish_time {
+                Option::None => None,
+                Option::Some(value) => {
+                    Option::Some(date::to_timestamp(&value[..], date::AZURE_DEVOPS_FORMAT)?)
+                }
+            })
+            .branch(&build.branch))
     }
 
-    fn collect(
+    /// Reports a build, or each of its stages if
+    /// stages should be collected and there are any.
+    fn report_build(
         &self,
-        listener: WaitHandleListener,
+        build: &AzureBuild,
+        listener: &WaitHandleListener,
         callback: &mut dyn FnMut(Build),
     ) -> DuckResult<()> {
-        for branch in self.branches.iter() {
+        let builder = self.create_build(build)?;
+        if !self.stages {
+            callback(builder.build().unwrap());
+            return Ok(());
+        }
+
+        let timeline = self.client.get_timeline(&self.http, build.id)?;
+        let mut stages: Vec<&AzureTimelineRecord> = timeline
+            .records
+            .iter()
+            .filter(|r| r.record_type == "Stage")
+            .collect();
+        if stages.is_empty() {
+            // Classic pipelines don't have any stages.
+            callback(builder.build().unwrap());
+            return Ok(());
+        }
+
+        stages.sort_by_key(|r| r.order);
+        for stage in stages {
             if listener.check() {
                 return Ok(());
             }
 
+            let identifier = stage.identifier.as_ref().unwrap_or(&stage.name);
+            callback(
+                builder
+                    .definition_id(format!("{}/{}", build.definition.id, identifier))
+                    .definition_name(format!("{} / {}", build.definition.name, stage.name))
+                    .status(stage.get_build_status())
+                    .started_at(match &stage.start_time {
+                        Option::None => {
+                            date::to_timestamp(&build.start_time, date::AZURE_DEVOPS_FORMAT)?
+                        }
+                        Option::Some(value) => {
+                            date::to_timestamp(&value[..], date::AZURE_DEVOPS_FORMAT)?
+                        }
+                    })
+                    .finished_at(match &stage.finish_time {
+                        Option::None => None,
+                        Option::Some(value) => {
+                            Option::Some(date::to_timestamp(&value[..], date::AZURE_DEVOPS_FORMAT)?)
+                        }
+                    })
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn collect_branches(
+        &self,
+        branches: &[&str],
+        listener: &WaitHandleListener,
+        callback: &mut dyn FnMut(Build),
+    ) -> DuckResult<()> {
+        for branch in branches.iter() {
+            if listener.check() {
+                return Ok(());
+            }
+            if !self.branches.is_match(branch) {
+                continue;
+            }
+
             let builds = self
                 .client
                 .get_builds(&self.http, branch, &self.definitions)?;
@@ -71,34 +165,7 @@ impl<T: HttpClient + Default> Collector for AzureDevOpsCollector<T> {
                     return Ok(());
                 }
 
-                callback(
-                    BuildBuilder::new()
-                        .build_id(build.id.to_string())
-                        .provider("AzureDevOps")
-                        .origin(&self.client.get_origin())
-                        .collector(&self.info.id)
-                        .project_id(&build.project.id)
-                        .project_name(&build.project.name)
-                        .definition_id(build.definition.id.to_string())
-                        .definition_name(&build.definition.name)
-                        .build_number(&build.build_number)
-                        .status(build.get_build_status())
-                        .url(&build.links.web.href)
-                        .started_at(date::to_timestamp(
-                            &build.start_time,
-                            date::AZURE_DEVOPS_FORMAT,
-                        )?)
-                        .finished_at(match &build.finish_time {
-                            Option::None => None,
-                            Option::Some(value) => Option::Some(date::to_timestamp(
-                                &value[..],
-                                date::AZURE_DEVOPS_FORMAT,
-                            )?),
-                        })
-                        .branch(&build.branch)
-                        .build()
-                        .unwrap(),
-                );
+                self.report_build(build, listener, callback)?;
             }
 
             // Wait for a litle time between calls.
@@ -107,7 +174,133 @@ impl<T: HttpClient + Default> Collector for AzureDevOpsCollector<T> {
             }
         }
 
-        return Ok(());
+        Ok(())
+    }
+
+    fn discover_branches(
+        &self,
+        listener: &WaitHandleListener,
+        callback: &mut dyn FnMut(Build),
+    ) -> DuckResult<()> {
+        // The builds are ordered by start time, so the first build
+        // we see for a definition and branch is the latest one.
+        let builds = self
+            .client
+            .get_recent_builds(&self.http, &self.definitions)?;
+        let mut seen = HashSet::<(u64, &String)>::new();
+        for build in builds.value.iter() {
+            if listener.check() {
+                return Ok(());
+            }
+            if self.branches.is_match(&build.branch)
+                && seen.insert((build.definition.id, &build.branch))
+            {
+                self.report_build(build, listener, callback)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn collect_releases(
+        &self,
+        listener: &WaitHandleListener,
+        callback: &mut dyn FnMut(Build),
+    ) -> DuckResult<()> {
+        for release in self.releases.iter() {
+            if listener.check() {
+                return Ok(());
+            }
+
+            // The deployments are ordered with the most recent first.
+            let deployments = self
+                .client
+                .get_deployments(&self.http, &release.definition_id)?;
+            for environment in release.environments.iter() {
+                let deployment = match deployments
+                    .value
+                    .iter()
+                    .find(|d| d.is_environment(environment))
+                {
+                    Some(d) => d,
+                    None => {
+                        warn!(
+                            "No deployment found for environment '{}' in release definition '{}'",
+                            environment, release.definition_id
+                        );
+                        continue;
+                    }
+                };
+
+                callback(self.create_release_build(deployment)?);
+            }
+
+            // Wait for a litle time between calls.
+            if listener.wait(Duration::from_millis(300)) {
+                return Ok(());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn create_release_build(&self, deployment: &AzureDeployment) -> DuckResult<Build> {
+        let status = deployment.get_build_status();
+        Ok(BuildBuilder::new()
+            .build_id(deployment.id.to_string())
+            .provider("AzureDevOps")
+            .origin(self.client.get_origin())
+            .collector(&self.info.id)
+            .project_id(&deployment.project.id)
+            .project_name(&deployment.project.name)
+            .definition_id(format!("release/{}", deployment.release_definition.id))
+            .definition_name(&deployment.release_definition.name)
+            .build_number(&deployment.release.name)
+            .status(status.clone())
+            .url(&deployment.release.links.web.href)
+            .started_at(date::to_timestamp(
+                deployment
+                    .started_on
+                    .as_ref()
+                    .unwrap_or(&deployment.queued_on),
+                date::AZURE_DEVOPS_FORMAT,
+            )?)
+            .finished_at(match &deployment.completed_on {
+                Option::Some(value)
+                    if !matches!(
+                        status,
+                        BuildStatus::Running | BuildStatus::Queued | BuildStatus::Blocked
+                    ) =>
+                {
+                    Option::Some(date::to_timestamp(&value[..], date::AZURE_DEVOPS_FORMAT)?)
+                }
+                _ => None,
+            })
+            .branch(&deployment.release_environment.name)
+            .build()
+            .unwrap())
+    }
+}
+
+impl<T: HttpClient + Default> Collector for AzureDevOpsCollector<T> {
+    fn info(&self) -> &CollectorInfo {
+        &self.info
+    }
+
+    fn collect(
+        &self,
+        listener: WaitHandleListener,
+        callback: &mut dyn FnMut(Build),
+    ) -> DuckResult<()> {
+        // Only discover branches if there are any patterns.
+        if !self.definitions.is_empty() {
+            match self.branches.get_exact_branches() {
+                Some(branches) => self.collect_branches(&branches, &listener, callback)?,
+                None => self.discover_branches(&listener, callback)?,
+            }
+        }
+
+        self.collect_releases(&listener, callback)
     }
 }
 
@@ -130,6 +323,51 @@ impl AzureBuild {
     }
 }
 
+impl AzureTimelineRecord {
+    pub fn get_build_status(&self) -> BuildStatus {
+        match self.state.as_deref() {
+            Some("pending") => return BuildStatus::Queued,
+            Some("inProgress") => return BuildStatus::Running,
+            _ => {}
+        }
+        match self.result.as_deref() {
+            Some("succeeded") | Some("succeededWithIssues") => BuildStatus::Success,
+            Some("canceled") | Some("abandoned") => BuildStatus::Canceled,
+            Some("skipped") => BuildStatus::Skipped,
+            Some("failed") => BuildStatus::Failed,
+            _ => BuildStatus::Unknown,
+        }
+    }
+}
+
+impl AzureDeployment {
+    /// Checks whether or not the deployment was made to the
+    /// specified environment, either by name or by ID.
+    pub fn is_environment(&self, environment: &str) -> bool {
+        self.release_environment
+            .name
+            .eq_ignore_ascii_case(environment)
+            || self.definition_environment_id.to_string() == environment
+    }
+
+    pub fn get_build_status(&self) -> BuildStatus {
+        match &self.deployment_status[..] {
+            "succeeded" => BuildStatus::Success,
+            "partiallySucceeded" | "failed" => BuildStatus::Failed,
+            "inProgress" => BuildStatus::Running,
+            _ => match &self.operation_status[..] {
+                "Pending" | "Deferred" | "EvaluatingGates" | "GateFailed" => BuildStatus::Blocked,
+                "Canceled" | "Cancelling" => BuildStatus::Canceled,
+                "Rejected" | "PhaseFailed" => BuildStatus::Failed,
+                "Queued" | "Scheduled" | "QueuedForAgent" | "QueuedForPipeline" | "Approved" => {
+                    BuildStatus::Queued
+                }
+                _ => BuildStatus::Unknown,
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,18 +375,56 @@ mod tests {
     use crate::config::*;
     use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
     use reqwest::StatusCode;
+    use test_case::test_case;
+
+    static RECENT_BUILDS_URL: &str = "https://dev.azure.com/cake-build/cake/_apis/build/builds?api-version=5.0&definitions=5,6&$top=200&queryOrder=startTimeDescending&deletedFilter=excludeDeleted&statusFilter=cancelling,completed,inProgress";
 
     fn create_collector(server_url: Option<String>) -> AzureDevOpsCollector<MockHttpClient> {
-        AzureDevOpsCollector::<MockHttpClient>::new(&AzureDevOpsConfiguration {
+        create_collector_for_branches(server_url, &["refs/heads/develop"], None)
+    }
+
+    fn create_collector_for_branches(
+        server_url: Option<String>,
+        branches: &[&str],
+        excluded_branches: Option<Vec<&str>>,
+    ) -> AzureDevOpsCollector<MockHttpClient> {
+        create_collector_with(server_url, branches, excluded_branches, |_| {})
+    }
+
+    fn create_collector_with(
+        server_url: Option<String>,
+        branches: &[&str],
+        excluded_branches: Option<Vec<&str>>,
+        configure: impl FnOnce(&mut AzureDevOpsConfiguration),
+    ) -> AzureDevOpsCollector<MockHttpClient> {
+        let mut config = AzureDevOpsConfiguration {
             id: "azure".to_owned(),
             enabled: Some(true),
+            interval: None,
+            timeout: None,
             server_url,
             organization: "cake-build".to_owned(),
             project: "cake".to_owned(),
             credentials: AzureDevOpsCredentials::PersonalAccessToken("SECRET".to_owned()),
-            branches: vec!["refs/heads/develop".to_owned()],
+            branches: branches.iter().map(|b| b.to_string()).collect(),
+            excluded_branches: excluded_branches.map(|b| b.iter().map(|s| s.to_string()).collect()),
             definitions: vec!["5".to_owned(), "6".to_owned()],
-        })
+            stages: None,
+            releases: None,
+        };
+        configure(&mut config);
+        AzureDevOpsCollector::<MockHttpClient>::new(&config).unwrap()
+    }
+
+    fn collect(collector: &AzureDevOpsCollector<MockHttpClient>) -> Vec<Build> {
+        let (_, listener) = waithandle::new();
+        let mut result = Vec::<Build>::new();
+        collector
+            .collect(listener, &mut |build: Build| {
+                result.push(build);
+            })
+            .unwrap();
+        result
     }
 
     #[test]
@@ -260,4 +536,245 @@ mod tests {
         assert_eq!(1587697251, result[0].started_at);
         assert_eq!(1587697564, result[0].finished_at.unwrap());
     }
+
+    #[test]
+    fn should_discover_branches_matching_pattern() {
+        // Given
+        let collector = create_collector_for_branches(None, &["refs/heads/release/*"], None);
+        collector.get_client().add_response(
+            MockHttpResponseBuilder::new(HttpMethod::Get, RECENT_BUILDS_URL)
+                .returns_status(StatusCode::OK)
+                .returns_body(include_str!("test_data/recent_builds.json")),
+        );
+
+        // When
+        let result = collect(&collector);
+
+        // Then
+        assert_eq!(4, result.len());
+        assert_eq!("10105", result[0].build_id);
+        assert_eq!("5", result[0].definition_id);
+        assert_eq!("refs/heads/release/0.38", result[0].branch);
+        assert_eq!(BuildStatus::Running, result[0].status);
+        assert_eq!(None, result[0].finished_at);
+        assert_eq!("10104", result[1].build_id);
+        assert_eq!("6", result[1].definition_id);
+        assert_eq!("refs/heads/release/0.38", result[1].branch);
+        assert_eq!("10101", result[2].build_id);
+        assert_eq!("refs/heads/release/0.37", result[2].branch);
+        assert_eq!("10100", result[3].build_id);
+        assert_eq!("refs/heads/release/old-0.1", result[3].branch);
+    }
+
+    #[test]
+    fn should_not_include_excluded_branches() {
+        // Given
+        let collector = create_collector_for_branches(
+            None,
+            &["refs/heads/**"],
+            Some(vec!["refs/heads/release/old-*", "refs/heads/feature/*"]),
+        );
+        collector.get_client().add_response(
+            MockHttpResponseBuilder::new(HttpMethod::Get, RECENT_BUILDS_URL)
+                .returns_status(StatusCode::OK)
+                .returns_body(include_str!("test_data/recent_builds.json")),
+        );
+
+        // When
+        let result = collect(&collector);
+
+        // Then
+        assert_eq!(3, result.len());
+        assert!(result
+            .iter()
+            .all(|b| b.branch.starts_with("refs/heads/release/0.")));
+    }
+
+    #[test]
+    fn should_not_discover_branches_if_all_branches_are_exact() {
+        // Given
+        let collector = create_collector_for_branches(
+            None,
+            &["refs/heads/develop", "refs/heads/master"],
+            Some(vec!["refs/heads/master"]),
+        );
+        collector.get_client().add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://dev.azure.com/cake-build/cake/_apis/build/builds?api-version=5.0&branchName=refs/heads/develop&definitions=5,6&maxBuildsPerDefinition=1&queryOrder=startTimeDescending&deletedFilter=excludeDeleted&statusFilter=cancelling,completed,inProgress"
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/builds.json"))
+        );
+
+        // When
+        let result = collect(&collector);
+
+        // Then
+        let requests = collector.get_client().get_sent_requests();
+        assert_eq!(2, result.len());
+        assert_eq!(1, requests.len());
+        assert!(requests[0].url.contains("branchName=refs/heads/develop"));
+    }
+
+    #[test]
+    fn should_report_stages_of_yaml_pipelines() {
+        // Given
+        let collector = create_collector_with(None, &["refs/heads/develop"], None, |config| {
+            config.definitions = vec!["5".to_owned()];
+            config.stages = Some(true);
+        });
+        let client = collector.get_client();
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://dev.azure.com/cake-build/cake/_apis/build/builds?api-version=5.0&branchName=refs/heads/develop&definitions=5&maxBuildsPerDefinition=1&queryOrder=startTimeDescending&deletedFilter=excludeDeleted&statusFilter=cancelling,completed,inProgress"
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/builds.json"))
+        );
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://dev.azure.com/cake-build/cake/_apis/build/builds/10059/timeline?api-version=5.0",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/timeline.json")),
+        );
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://dev.azure.com/cake-build/cake/_apis/build/builds/10062/timeline?api-version=5.0",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(r#"{ "records": [] }"#),
+        );
+
+        // When
+        let result = collect(&collector);
+
+        // Then
+        assert_eq!(4, result.len());
+        assert_eq!("10059", result[0].build_id);
+        assert_eq!("5/Build", result[0].definition_id);
+        assert_eq!(
+            "Azure Pipelines - Build Cake Centos 7 / Build",
+            result[0].definition_name
+        );
+        assert_eq!("refs/heads/develop", result[0].branch);
+        assert_eq!(BuildStatus::Success, result[0].status);
+        assert_eq!(1587697251, result[0].started_at);
+        assert_eq!(1587697401, result[0].finished_at.unwrap());
+        assert_eq!("5/DeployStaging", result[1].definition_id);
+        assert_eq!(BuildStatus::Failed, result[1].status);
+        assert_eq!("5/DeployProduction", result[2].definition_id);
+        assert_eq!(BuildStatus::Queued, result[2].status);
+        assert_eq!(1587697251, result[2].started_at);
+        assert_eq!(None, result[2].finished_at);
+        assert_eq!("10062", result[3].build_id);
+        assert_eq!("6", result[3].definition_id);
+    }
+
+    #[test]
+    fn should_collect_latest_release_deployment_per_environment() {
+        // Given
+        let collector = create_collector_with(None, &["refs/heads/develop"], None, |config| {
+            config.releases = Some(vec![AzureDevOpsRelease {
+                definition_id: "3".to_owned(),
+                environments: vec!["production".to_owned(), "6".to_owned()],
+            }]);
+        });
+        let client = collector.get_client();
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://dev.azure.com/cake-build/cake/_apis/build/builds?api-version=5.0&branchName=refs/heads/develop&definitions=5,6&maxBuildsPerDefinition=1&queryOrder=startTimeDescending&deletedFilter=excludeDeleted&statusFilter=cancelling,completed,inProgress"
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(r#"{ "count": 0, "value": [] }"#)
+        );
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://vsrm.dev.azure.com/cake-build/cake/_apis/release/deployments?api-version=5.0&definitionId=3&latestAttemptsOnly=true&queryOrder=descending&$top=100",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/deployments.json")),
+        );
+
+        // When
+        let result = collect(&collector);
+
+        // Then
+        assert_eq!(2, result.len());
+        assert_eq!("57", result[0].build_id);
+        assert_eq!("AzureDevOps", result[0].provider);
+        assert_eq!("https://dev.azure.com/cake-build/cake", result[0].origin);
+        assert_eq!("af63183c-ac1f-4dbb-93bc-4fa862ea5809", result[0].project_id);
+        assert_eq!("Cake", result[0].project_name);
+        assert_eq!("release/3", result[0].definition_id);
+        assert_eq!("Deploy website", result[0].definition_name);
+        assert_eq!("Release-25", result[0].build_number);
+        assert_eq!("Production", result[0].branch);
+        assert_eq!(BuildStatus::Blocked, result[0].status);
+        assert_eq!(
+            "https://dev.azure.com/cake-build/af63183c-ac1f-4dbb-93bc-4fa862ea5809/_release?releaseId=25&_a=release-summary",
+            result[0].url
+        );
+        assert_eq!(1587809701, result[0].started_at);
+        assert_eq!(None, result[0].finished_at);
+        assert_eq!("56", result[1].build_id);
+        assert_eq!("Staging", result[1].branch);
+        assert_eq!(BuildStatus::Running, result[1].status);
+        assert_eq!(1587809402, result[1].started_at);
+    }
+
+    #[test]
+    fn should_only_collect_releases_if_there_are_no_definitions() {
+        // Given
+        let collector = create_collector_with(None, &[], None, |config| {
+            config.definitions = vec![];
+            config.releases = Some(vec![AzureDevOpsRelease {
+                definition_id: "3".to_owned(),
+                environments: vec!["production".to_owned()],
+            }]);
+        });
+        collector.get_client().add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://vsrm.dev.azure.com/cake-build/cake/_apis/release/deployments?api-version=5.0&definitionId=3&latestAttemptsOnly=true&queryOrder=descending&$top=100",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/deployments.json")),
+        );
+
+        // When
+        let result = collect(&collector);
+
+        // Then
+        assert_eq!(1, result.len());
+        assert_eq!("Production", result[0].branch);
+        assert_eq!(1, collector.get_client().get_sent_requests().len());
+    }
+
+    #[test_case("succeeded", "Approved", BuildStatus::Success ; "succeeded")]
+    #[test_case("partiallySucceeded", "PhaseFailed", BuildStatus::Failed ; "partially succeeded")]
+    #[test_case("failed", "PhaseFailed", BuildStatus::Failed ; "failed")]
+    #[test_case("inProgress", "PhaseInProgress", BuildStatus::Running ; "in progress")]
+    #[test_case("notDeployed", "Pending", BuildStatus::Blocked ; "pending approval")]
+    #[test_case("notDeployed", "Queued", BuildStatus::Queued ; "queued")]
+    #[test_case("notDeployed", "Canceled", BuildStatus::Canceled ; "canceled")]
+    #[test_case("notDeployed", "Rejected", BuildStatus::Failed ; "rejected")]
+    fn should_get_release_deployment_status(status: &str, operation: &str, expected: BuildStatus) {
+        // Given
+        let mut response: AzureDeploymentResponse =
+            serde_json::from_str(include_str!("test_data/deployments.json")).unwrap();
+        let mut deployment = response.value.remove(0);
+        deployment.deployment_status = status.to_owned();
+        deployment.operation_status = operation.to_owned();
+        // When
+        let result = deployment.get_build_status();
+        // Then
+        assert_eq!(expected, result);
+    }
 }
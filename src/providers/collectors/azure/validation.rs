@@ -1,4 +1,5 @@
 use crate::config::{AzureDevOpsConfiguration, AzureDevOpsCredentials, Validate};
+use crate::utils::branches::BranchMatcher;
 use crate::DuckResult;
 
 impl Validate for AzureDevOpsConfiguration {
@@ -12,18 +13,47 @@ impl Validate for AzureDevOpsConfiguration {
         if self.project.is_empty() {
             return Err(format_err!("[{}] Azure DevOps project is empty", self.id));
         }
-        if self.definitions.is_empty() {
+        // Build definitions and branches are optional
+        // if only release deployments are collected.
+        let has_releases = self.releases.iter().flatten().next().is_some();
+        if self.definitions.is_empty() && !has_releases {
             return Err(format_err!(
                 "[{}] Azure DevOps configuration have not specified any build definitions",
                 self.id
             ));
         }
-        if self.branches.is_empty() {
+        if self.branches.is_empty() && !self.definitions.is_empty() {
             return Err(format_err!(
                 "[{}] Azure DevOps configuration have not specified any branches",
                 self.id
             ));
         }
+        if let Err(e) = BranchMatcher::new(
+            &self.branches,
+            self.excluded_branches.as_deref().unwrap_or_default(),
+        ) {
+            return Err(format_err!(
+                "[{}] Azure DevOps branches are invalid. {}",
+                self.id,
+                e
+            ));
+        }
+
+        for release in self.releases.iter().flatten() {
+            if release.definition_id.is_empty() {
+                return Err(format_err!(
+                    "[{}] Azure DevOps release definition ID is empty",
+                    self.id
+                ));
+            }
+            if release.environments.is_empty() {
+                return Err(format_err!(
+                    "[{}] Azure DevOps release definition '{}' have not specified any environments",
+                    self.id,
+                    release.definition_id
+                ));
+            }
+        }
 
         match &self.credentials {
             AzureDevOpsCredentials::Anonymous => {}
@@ -209,4 +239,132 @@ mod tests {
 
         providers::create_collectors(&config).unwrap();
     }
+
+    #[test]
+    #[should_panic(expected = "[foo] Azure DevOps branches are invalid")]
+    fn should_return_error_if_azure_devops_branch_pattern_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "azure": {
+                            "id": "foo",
+                            "organization": "cake-build",
+                            "project": "Cake",
+                            "credentials": "anonymous",
+                            "definitions": [ "1", "3", "5" ],
+                            "branches": [ "refs/heads/master" ],
+                            "excludedBranches": [ "regex:refs/heads/(" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] Azure DevOps release definition ID is empty")]
+    fn should_return_error_if_azure_devops_release_definition_id_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "azure": {
+                            "id": "foo",
+                            "organization": "cake-build",
+                            "project": "Cake",
+                            "credentials": "anonymous",
+                            "definitions": [ "1", "3", "5" ],
+                            "branches": [ "refs/heads/master" ],
+                            "releases": [
+                                {
+                                    "definitionId": "",
+                                    "environments": [ "Production" ]
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "[foo] Azure DevOps release definition \'3\' have not specified any environments"
+    )]
+    fn should_return_error_if_azure_devops_release_environments_are_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "azure": {
+                            "id": "foo",
+                            "organization": "cake-build",
+                            "project": "Cake",
+                            "credentials": "anonymous",
+                            "definitions": [ "1", "3", "5" ],
+                            "branches": [ "refs/heads/master" ],
+                            "releases": [
+                                {
+                                    "definitionId": "3",
+                                    "environments": [ ]
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    fn should_not_return_error_if_azure_devops_only_collects_releases() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "azure": {
+                            "id": "foo",
+                            "organization": "cake-build",
+                            "project": "Cake",
+                            "credentials": "anonymous",
+                            "definitions": [ ],
+                            "branches": [ ],
+                            "releases": [
+                                {
+                                    "definitionId": "3",
+                                    "environments": [ "Production" ]
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
 }
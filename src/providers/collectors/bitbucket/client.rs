@@ -0,0 +1,143 @@
+use log::trace;
+
+use crate::builds::BuildStatus;
+use crate::config::{BitbucketConfiguration, BitbucketCredentials};
+use crate::utils::date;
+use crate::utils::http::*;
+use crate::DuckResult;
+
+pub struct BitbucketClient {
+    pub workspace: String,
+    pub repository: String,
+    credentials: BitbucketCredentials,
+}
+
+impl BitbucketClient {
+    pub fn new(config: &BitbucketConfiguration) -> Self {
+        Self {
+            workspace: config.workspace.clone(),
+            repository: config.repository.clone(),
+            credentials: config.credentials.clone(),
+        }
+    }
+
+    pub fn get_pipelines(&self, client: &impl HttpClient) -> DuckResult<BitbucketResponse> {
+        let url = format!(
+            "https://api.bitbucket.org/2.0/repositories/{workspace}/{repo}/pipelines/?sort=-created_on&pagelen=50",
+            workspace = self.workspace,
+            repo = self.repository
+        );
+
+        trace!("Sending request to: {}", url);
+        let mut builder = HttpRequestBuilder::get(&url);
+        builder.add_header("Content-Type", "application/json");
+        builder.add_header("Accept", "application/json");
+
+        self.credentials.authenticate(&mut builder);
+        let mut response = client.send(&builder)?;
+
+        trace!("Received response: {}", response.status());
+        if !response.status().is_success() {
+            return Err(format_err!(
+                "Received non 200 HTTP status code. ({})",
+                response.status()
+            ));
+        }
+
+        // Get the response body.
+        let body = response.body()?;
+        // Deserialize and return the value.
+        Ok(serde_json::from_str(&body[..])?)
+    }
+
+    pub fn get_pipeline_url(&self, pipeline: &BitbucketPipeline) -> String {
+        format!(
+            "https://bitbucket.org/{workspace}/{repo}/addon/pipelines/home#!/results/{number}",
+            workspace = self.workspace,
+            repo = self.repository,
+            number = pipeline.build_number
+        )
+    }
+}
+
+impl BitbucketCredentials {
+    fn authenticate(&self, builder: &mut HttpRequestBuilder) {
+        match self {
+            BitbucketCredentials::Anonymous => {}
+            BitbucketCredentials::AppPassword { username, password } => {
+                builder.basic_auth(username, Some(password));
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct BitbucketResponse {
+    pub values: Vec<BitbucketPipeline>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct BitbucketPipeline {
+    pub build_number: u64,
+    pub state: BitbucketPipelineState,
+    pub target: BitbucketPipelineTarget,
+    pub created_on: String,
+    pub completed_on: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct BitbucketPipelineState {
+    pub name: String,
+    pub result: Option<BitbucketPipelineResult>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct BitbucketPipelineResult {
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct BitbucketPipelineTarget {
+    pub ref_type: Option<String>,
+    pub ref_name: Option<String>,
+}
+
+impl BitbucketPipeline {
+    pub fn get_branch(&self) -> Option<&String> {
+        match self.target.ref_type.as_deref() {
+            Some("branch") => self.target.ref_name.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn get_status(&self) -> BuildStatus {
+        match &self.state.name[..] {
+            "PENDING" => BuildStatus::Queued,
+            "IN_PROGRESS" => BuildStatus::Running,
+            "COMPLETED" => match &self.state.result {
+                None => BuildStatus::Unknown,
+                Some(result) => match &result.name[..] {
+                    "SUCCESSFUL" => BuildStatus::Success,
+                    "STOPPED" | "EXPIRED" => BuildStatus::Canceled,
+                    _ => BuildStatus::Failed,
+                },
+            },
+            _ => BuildStatus::Unknown,
+        }
+    }
+
+    pub fn get_started_timestamp(&self) -> DuckResult<i64> {
+        let result = date::to_timestamp(&self.created_on, date::BITBUCKET_FORMAT)?;
+        Ok(result)
+    }
+
+    pub fn get_finished_timestamp(&self) -> DuckResult<Option<i64>> {
+        match &self.completed_on {
+            Some(completed_on) => {
+                let result = date::to_timestamp(completed_on, date::BITBUCKET_FORMAT)?;
+                Ok(Some(result))
+            }
+            None => Ok(None),
+        }
+    }
+}
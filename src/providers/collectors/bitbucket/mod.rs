@@ -0,0 +1,274 @@
+use waithandle::WaitHandleListener;
+
+use crate::builds::{Build, BuildBuilder};
+use crate::config::BitbucketConfiguration;
+use crate::providers::collectors::{Collector, CollectorInfo, CollectorLoader};
+use crate::utils::http::{HttpClient, ReqwestClient};
+use crate::DuckResult;
+
+use self::client::BitbucketClient;
+
+mod client;
+mod validation;
+
+impl CollectorLoader for BitbucketConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Collector>> {
+        Ok(Box::new(BitbucketCollector::<ReqwestClient>::new(self)))
+    }
+}
+
+pub struct BitbucketCollector<T: HttpClient + Default> {
+    client: BitbucketClient,
+    http: T,
+    branches: Option<Vec<String>>,
+    info: CollectorInfo,
+}
+
+impl<T: HttpClient + Default> BitbucketCollector<T> {
+    pub fn new(config: &BitbucketConfiguration) -> Self {
+        return BitbucketCollector {
+            client: BitbucketClient::new(config),
+            http: Default::default(),
+            branches: config.branches.clone(),
+            info: CollectorInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                provider: "Bitbucket".to_owned(),
+            },
+        };
+    }
+
+    #[cfg(test)]
+    pub fn get_client(&self) -> &T {
+        &self.http
+    }
+
+    fn is_included(&self, branch: &str) -> bool {
+        match &self.branches {
+            None => true,
+            Some(branches) => branches.iter().any(|b| b == branch),
+        }
+    }
+}
+
+impl<T: HttpClient + Default> Collector for BitbucketCollector<T> {
+    fn info(&self) -> &CollectorInfo {
+        &self.info
+    }
+
+    fn collect(
+        &self,
+        listener: WaitHandleListener,
+        callback: &mut dyn FnMut(Build),
+    ) -> DuckResult<()> {
+        let response = self.client.get_pipelines(&self.http)?;
+
+        // Convert the pipelines to a Duck build representation.
+        let mut builds = Vec::<Build>::new();
+        for pipeline in response.values.iter() {
+            if listener.check() {
+                return Ok(());
+            }
+
+            // Pipelines that were not triggered
+            // for a branch are not of interest.
+            let branch = match pipeline.get_branch() {
+                Some(branch) if self.is_included(branch) => branch,
+                _ => continue,
+            };
+
+            builds.push(
+                BuildBuilder::new()
+                    .build_id(pipeline.build_number.to_string())
+                    .provider("Bitbucket")
+                    .origin(format!(
+                        "{}/{}",
+                        &self.client.workspace, &self.client.repository
+                    ))
+                    .collector(&self.info.id)
+                    .project_id(format!(
+                        "{}_{}",
+                        &self.client.workspace, &self.client.repository
+                    ))
+                    .project_name(format!(
+                        "{}/{}",
+                        &self.client.workspace, &self.client.repository
+                    ))
+                    .definition_id("pipeline")
+                    .definition_name("Pipeline")
+                    .build_number(pipeline.build_number.to_string())
+                    .status(pipeline.get_status())
+                    .url(self.client.get_pipeline_url(pipeline))
+                    .started_at(pipeline.get_started_timestamp()?)
+                    .finished_at(pipeline.get_finished_timestamp()?)
+                    .branch(branch)
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        // Sort the builds by date.
+        builds.sort_by_key(|b| std::cmp::Reverse(b.started_at));
+
+        // Now only keep the latest ones of the branches.
+        let mut branches = std::collections::HashSet::<&String>::new();
+        for build in builds.iter() {
+            if !branches.contains(&build.branch) {
+                branches.insert(&build.branch);
+                callback(build.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::BuildStatus;
+    use crate::config::*;
+    use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
+    use reqwest::StatusCode;
+    use test_case::test_case;
+
+    fn create_collector(branches: Option<Vec<&str>>) -> BitbucketCollector<MockHttpClient> {
+        let collector = BitbucketCollector::<MockHttpClient>::new(&BitbucketConfiguration {
+            id: "bitbucket".to_owned(),
+            enabled: Some(true),
+            interval: None,
+            timeout: None,
+            workspace: "duckhq".to_owned(),
+            repository: "duck".to_owned(),
+            credentials: BitbucketCredentials::AppPassword {
+                username: "patrik".to_owned(),
+                password: "SECRET".to_owned(),
+            },
+            branches: branches.map(|b| b.iter().map(|s| s.to_string()).collect()),
+        });
+
+        collector.get_client().add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://api.bitbucket.org/2.0/repositories/duckhq/duck/pipelines/?sort=-created_on&pagelen=50",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/pipelines.json")),
+        );
+
+        collector
+    }
+
+    fn collect(collector: &BitbucketCollector<MockHttpClient>) -> Vec<Build> {
+        let (_, listener) = waithandle::new();
+        let mut result = Vec::<Build>::new();
+        collector
+            .collect(listener, &mut |build: Build| {
+                result.push(build);
+            })
+            .unwrap();
+        result
+    }
+
+    #[test]
+    fn should_return_correct_provider_name() {
+        // Given
+        let bitbucket = create_collector(None);
+        // When
+        let provider = &bitbucket.info().provider;
+        // Then
+        assert_eq!("Bitbucket", provider);
+    }
+
+    #[test]
+    fn should_get_correct_data() {
+        // Given
+        let bitbucket = create_collector(None);
+
+        // When
+        let result = collect(&bitbucket);
+
+        // Then
+        assert_eq!(3, result.len());
+        assert_eq!("45", result[0].build_id);
+        assert_eq!("Bitbucket", result[0].provider);
+        assert_eq!("bitbucket", result[0].collector);
+        assert_eq!("duckhq_duck", result[0].project_id);
+        assert_eq!("duckhq/duck", result[0].project_name);
+        assert_eq!("45", result[0].build_number);
+        assert_eq!(BuildStatus::Running, result[0].status);
+        assert_eq!("master", result[0].branch);
+        assert_eq!(
+            "https://bitbucket.org/duckhq/duck/addon/pipelines/home#!/results/45",
+            result[0].url
+        );
+        assert_eq!(1580590216, result[0].started_at);
+        assert_eq!(None, result[0].finished_at);
+        assert_eq!("feature/login", result[1].branch);
+        assert_eq!(BuildStatus::Failed, result[1].status);
+        assert_eq!(1580590096, result[1].finished_at.unwrap());
+        assert_eq!("develop", result[2].branch);
+        assert_eq!(BuildStatus::Canceled, result[2].status);
+    }
+
+    #[test]
+    fn should_only_include_specified_branches() {
+        // Given
+        let bitbucket = create_collector(Some(vec!["master", "develop"]));
+
+        // When
+        let result = collect(&bitbucket);
+
+        // Then
+        assert_eq!(2, result.len());
+        assert_eq!("master", result[0].branch);
+        assert_eq!("develop", result[1].branch);
+    }
+
+    #[test]
+    fn should_authenticate_using_app_password() {
+        // Given
+        let bitbucket = create_collector(None);
+
+        // When
+        collect(&bitbucket);
+
+        // Then
+        let requests = bitbucket.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(
+            "Basic cGF0cmlrOlNFQ1JFVA==",
+            requests[0].headers.get("Authorization").unwrap()
+        );
+    }
+
+    #[test_case("PENDING", None, BuildStatus::Queued)]
+    #[test_case("IN_PROGRESS", None, BuildStatus::Running)]
+    #[test_case("COMPLETED", Some("SUCCESSFUL"), BuildStatus::Success)]
+    #[test_case("COMPLETED", Some("FAILED"), BuildStatus::Failed)]
+    #[test_case("COMPLETED", Some("STOPPED"), BuildStatus::Canceled)]
+    #[test_case("COMPLETED", None, BuildStatus::Unknown)]
+    #[test_case("PAUSED", None, BuildStatus::Unknown)]
+    fn should_map_pipeline_state(state: &str, result: Option<&str>, expected: BuildStatus) {
+        // Given
+        let pipeline = client::BitbucketPipeline {
+            build_number: 1,
+            state: client::BitbucketPipelineState {
+                name: state.to_owned(),
+                result: result.map(|name| client::BitbucketPipelineResult {
+                    name: name.to_owned(),
+                }),
+            },
+            target: client::BitbucketPipelineTarget {
+                ref_type: Some("branch".to_owned()),
+                ref_name: Some("master".to_owned()),
+            },
+            created_on: "2020-02-01T20:43:16.085Z".to_owned(),
+            completed_on: None,
+        };
+        // When
+        let result = pipeline.get_status();
+        // Then
+        assert_eq!(expected, result);
+    }
+}
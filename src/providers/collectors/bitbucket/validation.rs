@@ -0,0 +1,144 @@
+use crate::config::{BitbucketConfiguration, BitbucketCredentials, Validate};
+use crate::DuckResult;
+
+impl Validate for BitbucketConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        if self.workspace.is_empty() {
+            return Err(format_err!("[{}] Bitbucket workspace is empty", self.id));
+        }
+        if self.repository.is_empty() {
+            return Err(format_err!("[{}] Bitbucket repository is empty", self.id));
+        }
+
+        match &self.credentials {
+            BitbucketCredentials::Anonymous => {}
+            BitbucketCredentials::AppPassword { username, password } => {
+                if username.is_empty() {
+                    return Err(format_err!("[{}] Bitbucket username is empty", self.id));
+                }
+                if password.is_empty() {
+                    return Err(format_err!("[{}] Bitbucket app password is empty", self.id));
+                }
+            }
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(expected = "[bitbucket] Bitbucket workspace is empty")]
+    fn should_return_error_if_bitbucket_workspace_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "bitbucket": {
+                            "id": "bitbucket",
+                            "workspace": "",
+                            "repository": "duck",
+                            "credentials": "anonymous"
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[bitbucket] Bitbucket repository is empty")]
+    fn should_return_error_if_bitbucket_repository_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "bitbucket": {
+                            "id": "bitbucket",
+                            "workspace": "duckhq",
+                            "repository": "",
+                            "credentials": "anonymous"
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[bitbucket] Bitbucket username is empty")]
+    fn should_return_error_if_bitbucket_username_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "bitbucket": {
+                            "id": "bitbucket",
+                            "workspace": "duckhq",
+                            "repository": "duck",
+                            "credentials": {
+                                "appPassword": {
+                                    "username": "",
+                                    "password": "SECRET"
+                                }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[bitbucket] Bitbucket app password is empty")]
+    fn should_return_error_if_bitbucket_app_password_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "bitbucket": {
+                            "id": "bitbucket",
+                            "workspace": "duckhq",
+                            "repository": "duck",
+                            "credentials": {
+                                "appPassword": {
+                                    "username": "patrik",
+                                    "password": ""
+                                }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+}
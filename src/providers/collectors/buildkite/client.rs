@@ -0,0 +1,111 @@
+use log::trace;
+
+use crate::builds::BuildStatus;
+use crate::config::{BuildkiteConfiguration, BuildkiteCredentials};
+use crate::utils::date;
+use crate::utils::http::*;
+use crate::DuckResult;
+
+pub struct BuildkiteClient {
+    pub organization: String,
+    credentials: BuildkiteCredentials,
+}
+
+impl BuildkiteClient {
+    pub fn new(config: &BuildkiteConfiguration) -> Self {
+        Self {
+            organization: config.organization.clone(),
+            credentials: config.credentials.clone(),
+        }
+    }
+
+    pub fn get_builds(
+        &self,
+        client: &impl HttpClient,
+        pipeline: &str,
+    ) -> DuckResult<Vec<BuildkiteBuild>> {
+        let url = format!(
+            "https://api.buildkite.com/v2/organizations/{organization}/pipelines/{pipeline}/builds?per_page=50",
+            organization = self.organization,
+            pipeline = pipeline
+        );
+
+        trace!("Sending request to: {}", url);
+        let mut builder = HttpRequestBuilder::get(&url);
+        builder.add_header("Content-Type", "application/json");
+        builder.add_header("Accept", "application/json");
+
+        self.credentials.authenticate(&mut builder);
+        let mut response = client.send(&builder)?;
+
+        trace!("Received response: {}", response.status());
+        if !response.status().is_success() {
+            return Err(format_err!(
+                "Received non 200 HTTP status code. ({})",
+                response.status()
+            ));
+        }
+
+        // Get the response body.
+        let body = response.body()?;
+        // Deserialize and return the value.
+        Ok(serde_json::from_str(&body[..])?)
+    }
+}
+
+impl BuildkiteCredentials {
+    fn authenticate(&self, builder: &mut HttpRequestBuilder) {
+        match self {
+            BuildkiteCredentials::Token(token) => {
+                builder.bearer(token);
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct BuildkiteBuild {
+    pub id: String,
+    pub number: u64,
+    pub state: String,
+    pub branch: String,
+    pub web_url: String,
+    pub created_at: String,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+}
+
+impl BuildkiteBuild {
+    pub fn get_status(&self) -> BuildStatus {
+        match &self.state[..] {
+            "passed" => BuildStatus::Success,
+            "failed" | "failing" | "waiting_failed" => BuildStatus::Failed,
+            "running" => BuildStatus::Running,
+            "scheduled" | "creating" | "waiting" => BuildStatus::Queued,
+            // A blocked build is waiting for someone
+            // to unblock it before it can continue.
+            "blocked" => BuildStatus::Blocked,
+            "canceled" | "canceling" => BuildStatus::Canceled,
+            "skipped" | "not_run" => BuildStatus::Skipped,
+            _ => BuildStatus::Unknown,
+        }
+    }
+
+    pub fn get_started_timestamp(&self) -> DuckResult<i64> {
+        // Builds that have not started yet
+        // only have a creation date.
+        let started_at = self.started_at.as_ref().unwrap_or(&self.created_at);
+        let result = date::to_timestamp(started_at, date::BUILDKITE_FORMAT)?;
+        Ok(result)
+    }
+
+    pub fn get_finished_timestamp(&self) -> DuckResult<Option<i64>> {
+        match &self.finished_at {
+            Some(finished_at) => {
+                let result = date::to_timestamp(finished_at, date::BUILDKITE_FORMAT)?;
+                Ok(Some(result))
+            }
+            None => Ok(None),
+        }
+    }
+}
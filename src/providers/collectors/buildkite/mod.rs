@@ -0,0 +1,253 @@
+use waithandle::WaitHandleListener;
+
+use crate::builds::{Build, BuildBuilder};
+use crate::config::BuildkiteConfiguration;
+use crate::providers::collectors::{Collector, CollectorInfo, CollectorLoader};
+use crate::utils::http::{HttpClient, ReqwestClient};
+use crate::DuckResult;
+
+use self::client::BuildkiteClient;
+
+mod client;
+mod validation;
+
+impl CollectorLoader for BuildkiteConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Collector>> {
+        Ok(Box::new(BuildkiteCollector::<ReqwestClient>::new(self)))
+    }
+}
+
+pub struct BuildkiteCollector<T: HttpClient + Default> {
+    client: BuildkiteClient,
+    http: T,
+    pipelines: Vec<String>,
+    info: CollectorInfo,
+}
+
+impl<T: HttpClient + Default> BuildkiteCollector<T> {
+    pub fn new(config: &BuildkiteConfiguration) -> Self {
+        return BuildkiteCollector {
+            client: BuildkiteClient::new(config),
+            http: Default::default(),
+            pipelines: config.pipelines.clone(),
+            info: CollectorInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                provider: "Buildkite".to_owned(),
+            },
+        };
+    }
+
+    #[cfg(test)]
+    pub fn get_client(&self) -> &T {
+        &self.http
+    }
+}
+
+impl<T: HttpClient + Default> Collector for BuildkiteCollector<T> {
+    fn info(&self) -> &CollectorInfo {
+        &self.info
+    }
+
+    fn collect(
+        &self,
+        listener: WaitHandleListener,
+        callback: &mut dyn FnMut(Build),
+    ) -> DuckResult<()> {
+        for pipeline in self.pipelines.iter() {
+            if listener.check() {
+                return Ok(());
+            }
+
+            let response = self.client.get_builds(&self.http, pipeline)?;
+
+            // Convert the builds to a Duck build representation.
+            let mut builds = Vec::<Build>::new();
+            for build in response.iter() {
+                builds.push(
+                    BuildBuilder::new()
+                        .build_id(&build.id)
+                        .provider("Buildkite")
+                        .origin(format!("{}/{}", &self.client.organization, pipeline))
+                        .collector(&self.info.id)
+                        .project_id(format!("{}_{}", &self.client.organization, pipeline))
+                        .project_name(format!("{}/{}", &self.client.organization, pipeline))
+                        .definition_id("pipeline")
+                        .definition_name("Pipeline")
+                        .build_number(build.number.to_string())
+                        .status(build.get_status())
+                        .url(&build.web_url)
+                        .started_at(build.get_started_timestamp()?)
+                        .finished_at(build.get_finished_timestamp()?)
+                        .branch(&build.branch)
+                        .build()
+                        .unwrap(),
+                );
+            }
+
+            // Sort the builds by date.
+            builds.sort_by_key(|b| std::cmp::Reverse(b.started_at));
+
+            // Now only keep the latest ones of the branches.
+            let mut branches = std::collections::HashSet::<&String>::new();
+            for build in builds.iter() {
+                if !branches.contains(&build.branch) {
+                    branches.insert(&build.branch);
+                    callback(build.clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::BuildStatus;
+    use crate::config::*;
+    use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
+    use reqwest::StatusCode;
+    use test_case::test_case;
+
+    fn create_collector() -> BuildkiteCollector<MockHttpClient> {
+        let collector = BuildkiteCollector::<MockHttpClient>::new(&BuildkiteConfiguration {
+            id: "buildkite".to_owned(),
+            enabled: Some(true),
+            interval: None,
+            timeout: None,
+            organization: "duckhq".to_owned(),
+            pipelines: vec!["duck".to_owned(), "duck-docs".to_owned()],
+            credentials: BuildkiteCredentials::Token("SECRET".to_owned()),
+        });
+
+        collector.get_client().add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://api.buildkite.com/v2/organizations/duckhq/pipelines/duck/builds?per_page=50",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/builds.json")),
+        );
+        collector.get_client().add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://api.buildkite.com/v2/organizations/duckhq/pipelines/duck-docs/builds?per_page=50",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/builds_docs.json")),
+        );
+
+        collector
+    }
+
+    fn collect(collector: &BuildkiteCollector<MockHttpClient>) -> Vec<Build> {
+        let (_, listener) = waithandle::new();
+        let mut result = Vec::<Build>::new();
+        collector
+            .collect(listener, &mut |build: Build| {
+                result.push(build);
+            })
+            .unwrap();
+        result
+    }
+
+    #[test]
+    fn should_return_correct_provider_name() {
+        // Given
+        let buildkite = create_collector();
+        // When
+        let provider = &buildkite.info().provider;
+        // Then
+        assert_eq!("Buildkite", provider);
+    }
+
+    #[test]
+    fn should_get_correct_data() {
+        // Given
+        let buildkite = create_collector();
+
+        // When
+        let result = collect(&buildkite);
+
+        // Then
+        assert_eq!(3, result.len());
+        assert_eq!("f62a1b4d-10f9-4790-bc1c-e2c3a0c80983", result[0].build_id);
+        assert_eq!("Buildkite", result[0].provider);
+        assert_eq!("buildkite", result[0].collector);
+        assert_eq!("duckhq/duck", result[0].origin);
+        assert_eq!("duckhq_duck", result[0].project_id);
+        assert_eq!("duckhq/duck", result[0].project_name);
+        assert_eq!("pipeline", result[0].definition_id);
+        assert_eq!("Pipeline", result[0].definition_name);
+        assert_eq!("87", result[0].build_number);
+        assert_eq!(BuildStatus::Blocked, result[0].status);
+        assert_eq!("master", result[0].branch);
+        assert_eq!("https://buildkite.com/duckhq/duck/builds/87", result[0].url);
+        assert_eq!(1580590216, result[0].started_at);
+        assert_eq!(None, result[0].finished_at);
+        assert_eq!("feature/login", result[1].branch);
+        assert_eq!(BuildStatus::Queued, result[1].status);
+        assert_eq!("duckhq_duck-docs", result[2].project_id);
+        assert_eq!(BuildStatus::Success, result[2].status);
+        assert_eq!(1580590096, result[2].finished_at.unwrap());
+    }
+
+    #[test]
+    fn should_use_creation_date_for_builds_that_have_not_started() {
+        // Given
+        let buildkite = create_collector();
+
+        // When
+        let result = collect(&buildkite);
+
+        // Then
+        assert_eq!(None, result[1].finished_at);
+        assert_eq!(1580589910, result[1].started_at);
+    }
+
+    #[test]
+    fn should_authenticate_using_token() {
+        // Given
+        let buildkite = create_collector();
+
+        // When
+        collect(&buildkite);
+
+        // Then
+        let requests = buildkite.get_client().get_sent_requests();
+        assert_eq!(2, requests.len());
+        assert_eq!(
+            "Bearer SECRET",
+            requests[0].headers.get("Authorization").unwrap()
+        );
+    }
+
+    #[test_case("passed", BuildStatus::Success)]
+    #[test_case("failed", BuildStatus::Failed)]
+    #[test_case("failing", BuildStatus::Failed)]
+    #[test_case("running", BuildStatus::Running)]
+    #[test_case("scheduled", BuildStatus::Queued)]
+    #[test_case("blocked", BuildStatus::Blocked)]
+    #[test_case("canceled", BuildStatus::Canceled)]
+    #[test_case("skipped", BuildStatus::Skipped)]
+    #[test_case("unknown_state", BuildStatus::Unknown)]
+    fn should_map_build_state(state: &str, expected: BuildStatus) {
+        // Given
+        let build = client::BuildkiteBuild {
+            id: "id".to_owned(),
+            number: 1,
+            state: state.to_owned(),
+            branch: "master".to_owned(),
+            web_url: "https://buildkite.com/duckhq/duck/builds/1".to_owned(),
+            created_at: "2020-02-01T20:43:16.085Z".to_owned(),
+            started_at: None,
+            finished_at: None,
+        };
+        // When
+        let result = build.get_status();
+        // Then
+        assert_eq!(expected, result);
+    }
+}
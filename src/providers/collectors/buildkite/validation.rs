@@ -0,0 +1,141 @@
+use crate::config::{BuildkiteConfiguration, BuildkiteCredentials, Validate};
+use crate::DuckResult;
+
+impl Validate for BuildkiteConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        if self.organization.is_empty() {
+            return Err(format_err!("[{}] Buildkite organization is empty", self.id));
+        }
+        if self.pipelines.is_empty() {
+            return Err(format_err!("[{}] Buildkite pipelines are empty", self.id));
+        }
+        if self.pipelines.iter().any(|p| p.is_empty()) {
+            return Err(format_err!("[{}] Buildkite pipeline is empty", self.id));
+        }
+
+        match &self.credentials {
+            BuildkiteCredentials::Token(token) => {
+                if token.is_empty() {
+                    return Err(format_err!("[{}] Buildkite token is empty", self.id));
+                }
+            }
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(expected = "[buildkite] Buildkite organization is empty")]
+    fn should_return_error_if_buildkite_organization_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "buildkite": {
+                            "id": "buildkite",
+                            "organization": "",
+                            "pipelines": [ "duck" ],
+                            "credentials": {
+                                "token": "SECRET"
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[buildkite] Buildkite pipelines are empty")]
+    fn should_return_error_if_buildkite_pipelines_are_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "buildkite": {
+                            "id": "buildkite",
+                            "organization": "duckhq",
+                            "pipelines": [],
+                            "credentials": {
+                                "token": "SECRET"
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[buildkite] Buildkite pipeline is empty")]
+    fn should_return_error_if_buildkite_pipeline_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "buildkite": {
+                            "id": "buildkite",
+                            "organization": "duckhq",
+                            "pipelines": [ "" ],
+                            "credentials": {
+                                "token": "SECRET"
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[buildkite] Buildkite token is empty")]
+    fn should_return_error_if_buildkite_token_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "buildkite": {
+                            "id": "buildkite",
+                            "organization": "duckhq",
+                            "pipelines": [ "duck" ],
+                            "credentials": {
+                                "token": ""
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+}
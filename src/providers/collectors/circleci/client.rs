@@ -0,0 +1,165 @@
+use log::trace;
+
+use crate::builds::BuildStatus;
+use crate::config::{CircleCIConfiguration, CircleCICredentials};
+use crate::utils::date;
+use crate::utils::http::*;
+use crate::DuckResult;
+
+pub struct CircleCIClient {
+    pub project: String,
+    credentials: CircleCICredentials,
+}
+
+impl CircleCIClient {
+    pub fn new(config: &CircleCIConfiguration) -> Self {
+        Self {
+            project: config.project.clone(),
+            credentials: config.credentials.clone(),
+        }
+    }
+
+    pub fn get_pipelines(
+        &self,
+        client: &impl HttpClient,
+        branch: Option<&str>,
+    ) -> DuckResult<CircleCIResponse<CircleCIPipeline>> {
+        let mut url = format!(
+            "https://circleci.com/api/v2/project/{project}/pipeline",
+            project = self.project
+        );
+        if let Some(branch) = branch {
+            let branch: String = url::form_urlencoded::byte_serialize(branch.as_bytes()).collect();
+            url = format!("{}?branch={}", url, branch);
+        }
+        self.get(client, &url)
+    }
+
+    pub fn get_workflows(
+        &self,
+        client: &impl HttpClient,
+        pipeline: &CircleCIPipeline,
+    ) -> DuckResult<CircleCIResponse<CircleCIWorkflow>> {
+        self.get(
+            client,
+            &format!(
+                "https://circleci.com/api/v2/pipeline/{id}/workflow",
+                id = pipeline.id
+            ),
+        )
+    }
+
+    pub fn get_workflow_url(
+        &self,
+        pipeline: &CircleCIPipeline,
+        workflow: &CircleCIWorkflow,
+    ) -> String {
+        format!(
+            "https://app.circleci.com/pipelines/{project}/{number}/workflows/{id}",
+            project = self.project,
+            number = pipeline.number,
+            id = workflow.id
+        )
+    }
+
+    fn get<T: serde::de::DeserializeOwned>(
+        &self,
+        client: &impl HttpClient,
+        url: &str,
+    ) -> DuckResult<T> {
+        trace!("Sending request to: {}", url);
+        let mut builder = HttpRequestBuilder::get(url);
+        builder.add_header("Accept", "application/json");
+
+        self.credentials.authenticate(&mut builder);
+        let mut response = client.send(&builder)?;
+
+        trace!("Received response: {}", response.status());
+        if !response.status().is_success() {
+            return Err(format_err!(
+                "Received non 200 HTTP status code. ({})",
+                response.status()
+            ));
+        }
+
+        // Get the response body.
+        let body = response.body()?;
+        // Deserialize and return the value.
+        Ok(serde_json::from_str(&body[..])?)
+    }
+}
+
+impl CircleCICredentials {
+    fn authenticate(&self, builder: &mut HttpRequestBuilder) {
+        match self {
+            CircleCICredentials::Token(token) => {
+                builder.add_header("Circle-Token", token);
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CircleCIResponse<T> {
+    pub items: Vec<T>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CircleCIPipeline {
+    pub id: String,
+    pub number: u64,
+    pub vcs: Option<CircleCIVcs>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CircleCIVcs {
+    pub branch: Option<String>,
+}
+
+impl CircleCIPipeline {
+    pub fn get_branch(&self) -> Option<&String> {
+        self.vcs.as_ref().and_then(|vcs| vcs.branch.as_ref())
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CircleCIWorkflow {
+    pub id: String,
+    pub name: String,
+    pub status: String,
+    pub created_at: String,
+    pub stopped_at: Option<String>,
+}
+
+impl CircleCIWorkflow {
+    pub fn get_status(&self) -> BuildStatus {
+        match &self.status[..] {
+            "success" => BuildStatus::Success,
+            "running" => BuildStatus::Running,
+            // A workflow is 'failing' when one of its jobs has
+            // failed while others are still running. It will
+            // end up as failed, so there is no reason to wait.
+            "failing" | "failed" | "error" | "unauthorized" => BuildStatus::Failed,
+            // Workflows that are on hold are waiting for an approval.
+            "on_hold" => BuildStatus::Blocked,
+            "not_run" => BuildStatus::Skipped,
+            "canceled" => BuildStatus::Canceled,
+            _ => BuildStatus::Unknown,
+        }
+    }
+
+    pub fn get_started_timestamp(&self) -> DuckResult<i64> {
+        let result = date::to_timestamp(&self.created_at, date::CIRCLECI_FORMAT)?;
+        Ok(result)
+    }
+
+    pub fn get_finished_timestamp(&self) -> DuckResult<Option<i64>> {
+        match &self.stopped_at {
+            Some(stopped_at) => {
+                let result = date::to_timestamp(stopped_at, date::CIRCLECI_FORMAT)?;
+                Ok(Some(result))
+            }
+            None => Ok(None),
+        }
+    }
+}
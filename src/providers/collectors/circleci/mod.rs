@@ -0,0 +1,322 @@
+use std::collections::HashSet;
+
+use waithandle::WaitHandleListener;
+
+use crate::builds::{Build, BuildBuilder};
+use crate::config::CircleCIConfiguration;
+use crate::providers::collectors::{Collector, CollectorInfo, CollectorLoader};
+use crate::utils::http::{HttpClient, ReqwestClient};
+use crate::DuckResult;
+
+use self::client::{CircleCIClient, CircleCIPipeline};
+
+mod client;
+mod validation;
+
+impl CollectorLoader for CircleCIConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Collector>> {
+        Ok(Box::new(CircleCICollector::<ReqwestClient>::new(self)))
+    }
+}
+
+pub struct CircleCICollector<T: HttpClient + Default> {
+    client: CircleCIClient,
+    http: T,
+    branches: Option<Vec<String>>,
+    info: CollectorInfo,
+}
+
+impl<T: HttpClient + Default> CircleCICollector<T> {
+    pub fn new(config: &CircleCIConfiguration) -> Self {
+        return CircleCICollector {
+            client: CircleCIClient::new(config),
+            http: Default::default(),
+            branches: config.branches.clone(),
+            info: CollectorInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                provider: "CircleCI".to_owned(),
+            },
+        };
+    }
+
+    #[cfg(test)]
+    pub fn get_client(&self) -> &T {
+        &self.http
+    }
+
+    fn get_latest_pipelines(&self) -> DuckResult<Vec<CircleCIPipeline>> {
+        let mut result = Vec::<CircleCIPipeline>::new();
+        match &self.branches {
+            Some(branches) => {
+                for branch in branches.iter() {
+                    let response = self.client.get_pipelines(&self.http, Some(branch))?;
+                    if let Some(pipeline) = response.items.into_iter().next() {
+                        result.push(pipeline);
+                    }
+                }
+            }
+            None => {
+                // Pipelines are returned with the most recent
+                // first, so keep the first one for every branch.
+                let response = self.client.get_pipelines(&self.http, None)?;
+                let mut branches = HashSet::<String>::new();
+                for pipeline in response.items.into_iter() {
+                    let branch = match pipeline.get_branch() {
+                        Some(branch) => branch.clone(),
+                        None => continue,
+                    };
+                    if branches.insert(branch) {
+                        result.push(pipeline);
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<T: HttpClient + Default> Collector for CircleCICollector<T> {
+    fn info(&self) -> &CollectorInfo {
+        &self.info
+    }
+
+    fn collect(
+        &self,
+        listener: WaitHandleListener,
+        callback: &mut dyn FnMut(Build),
+    ) -> DuckResult<()> {
+        // The project name is the slug without the VCS prefix.
+        let project_name = match self.client.project.find('/') {
+            Some(index) => &self.client.project[index + 1..],
+            None => &self.client.project[..],
+        };
+
+        for pipeline in self.get_latest_pipelines()? {
+            if listener.check() {
+                return Ok(());
+            }
+
+            let branch = match pipeline.get_branch() {
+                Some(branch) => branch,
+                None => continue,
+            };
+
+            let mut workflows = self.client.get_workflows(&self.http, &pipeline)?.items;
+
+            // A rerun workflow shares the name of the original one,
+            // so only keep the most recent workflow for every name.
+            workflows.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+            let mut names = HashSet::<&String>::new();
+            for workflow in workflows.iter() {
+                if !names.insert(&workflow.name) {
+                    continue;
+                }
+
+                callback(
+                    BuildBuilder::new()
+                        .build_id(&workflow.id)
+                        .provider("CircleCI")
+                        .origin(&self.client.project)
+                        .collector(&self.info.id)
+                        .project_id(self.client.project.replace('/', "_"))
+                        .project_name(project_name)
+                        .definition_id(&workflow.name)
+                        .definition_name(&workflow.name)
+                        .build_number(pipeline.number.to_string())
+                        .status(workflow.get_status())
+                        .url(self.client.get_workflow_url(&pipeline, workflow))
+                        .started_at(workflow.get_started_timestamp()?)
+                        .finished_at(workflow.get_finished_timestamp()?)
+                        .branch(branch)
+                        .build()
+                        .unwrap(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::BuildStatus;
+    use crate::config::*;
+    use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
+    use reqwest::StatusCode;
+    use test_case::test_case;
+
+    fn create_collector(branches: Option<Vec<&str>>) -> CircleCICollector<MockHttpClient> {
+        let collector = CircleCICollector::<MockHttpClient>::new(&CircleCIConfiguration {
+            id: "circleci".to_owned(),
+            enabled: Some(true),
+            interval: None,
+            timeout: None,
+            project: "gh/duckhq/duck".to_owned(),
+            credentials: CircleCICredentials::Token("SECRET".to_owned()),
+            branches: branches.map(|b| b.iter().map(|s| s.to_string()).collect()),
+        });
+
+        let client = collector.get_client();
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://circleci.com/api/v2/project/gh/duckhq/duck/pipeline",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/pipelines.json")),
+        );
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://circleci.com/api/v2/project/gh/duckhq/duck/pipeline?branch=feature%2Flogin",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/pipelines_feature.json")),
+        );
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://circleci.com/api/v2/pipeline/5034460f-c7c4-4c43-9457-de07e2029e7b/workflow",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/workflows_master.json")),
+        );
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://circleci.com/api/v2/pipeline/2a8c9e41-61b4-4d56-9f3b-3f7f0b7c1a2d/workflow",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/workflows_feature.json")),
+        );
+
+        collector
+    }
+
+    fn collect(collector: &CircleCICollector<MockHttpClient>) -> Vec<Build> {
+        let (_, listener) = waithandle::new();
+        let mut result = Vec::<Build>::new();
+        collector
+            .collect(listener, &mut |build: Build| {
+                result.push(build);
+            })
+            .unwrap();
+        result
+    }
+
+    #[test]
+    fn should_return_correct_provider_name() {
+        // Given
+        let circleci = create_collector(None);
+        // When
+        let provider = &circleci.info().provider;
+        // Then
+        assert_eq!("CircleCI", provider);
+    }
+
+    #[test]
+    fn should_get_correct_data() {
+        // Given
+        let circleci = create_collector(None);
+
+        // When
+        let result = collect(&circleci);
+
+        // Then
+        assert_eq!(3, result.len());
+        assert_eq!("fda08377-fe7e-46b1-8992-3a7aaecac9c3", result[0].build_id);
+        assert_eq!("CircleCI", result[0].provider);
+        assert_eq!("circleci", result[0].collector);
+        assert_eq!("gh/duckhq/duck", result[0].origin);
+        assert_eq!("gh_duckhq_duck", result[0].project_id);
+        assert_eq!("duckhq/duck", result[0].project_name);
+        assert_eq!("deploy", result[0].definition_id);
+        assert_eq!("deploy", result[0].definition_name);
+        assert_eq!("128", result[0].build_number);
+        assert_eq!(BuildStatus::Blocked, result[0].status);
+        assert_eq!("master", result[0].branch);
+        assert_eq!(
+            "https://app.circleci.com/pipelines/gh/duckhq/duck/128/workflows/fda08377-fe7e-46b1-8992-3a7aaecac9c3",
+            result[0].url
+        );
+        assert_eq!(1580590216, result[0].started_at);
+        assert_eq!(None, result[0].finished_at);
+        assert_eq!("build", result[1].definition_id);
+        assert_eq!(BuildStatus::Success, result[1].status);
+        assert_eq!(1580590096, result[1].finished_at.unwrap());
+        assert_eq!("feature/login", result[2].branch);
+        assert_eq!(BuildStatus::Failed, result[2].status);
+    }
+
+    #[test]
+    fn should_only_include_latest_workflow_with_the_same_name() {
+        // Given
+        let circleci = create_collector(None);
+
+        // When
+        let result = collect(&circleci);
+
+        // Then
+        let builds: Vec<&Build> = result
+            .iter()
+            .filter(|b| b.branch == "master" && b.definition_id == "build")
+            .collect();
+        assert_eq!(1, builds.len());
+        assert_eq!("d4b8d9a1-1b2f-4d60-8a8e-0c6a5c3a6e10", builds[0].build_id);
+    }
+
+    #[test]
+    fn should_get_latest_pipeline_for_specified_branches() {
+        // Given
+        let circleci = create_collector(Some(vec!["feature/login"]));
+
+        // When
+        let result = collect(&circleci);
+
+        // Then
+        assert_eq!(1, result.len());
+        assert_eq!("feature/login", result[0].branch);
+        assert_eq!("127", result[0].build_number);
+    }
+
+    #[test]
+    fn should_authenticate_using_token() {
+        // Given
+        let circleci = create_collector(None);
+
+        // When
+        collect(&circleci);
+
+        // Then
+        let requests = circleci.get_client().get_sent_requests();
+        assert_eq!(3, requests.len());
+        assert_eq!("SECRET", requests[0].headers.get("Circle-Token").unwrap());
+    }
+
+    #[test_case("success", BuildStatus::Success)]
+    #[test_case("running", BuildStatus::Running)]
+    #[test_case("failing", BuildStatus::Failed)]
+    #[test_case("failed", BuildStatus::Failed)]
+    #[test_case("error", BuildStatus::Failed)]
+    #[test_case("on_hold", BuildStatus::Blocked)]
+    #[test_case("not_run", BuildStatus::Skipped)]
+    #[test_case("canceled", BuildStatus::Canceled)]
+    #[test_case("unknown_status", BuildStatus::Unknown)]
+    fn should_map_workflow_status(status: &str, expected: BuildStatus) {
+        // Given
+        let workflow = client::CircleCIWorkflow {
+            id: "id".to_owned(),
+            name: "build".to_owned(),
+            status: status.to_owned(),
+            created_at: "2020-02-01T20:43:16.085Z".to_owned(),
+            stopped_at: None,
+        };
+        // When
+        let result = workflow.get_status();
+        // Then
+        assert_eq!(expected, result);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::config::{CircleCIConfiguration, CircleCICredentials, Validate};
+use crate::DuckResult;
+
+impl Validate for CircleCIConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        if self.project.is_empty() {
+            return Err(format_err!("[{}] CircleCI project slug is empty", self.id));
+        }
+        if self.project.split('/').filter(|p| !p.is_empty()).count() != 3 {
+            return Err(format_err!(
+                "[{}] CircleCI project slug is invalid",
+                self.id
+            ));
+        }
+
+        match &self.credentials {
+            CircleCICredentials::Token(token) => {
+                if token.is_empty() {
+                    return Err(format_err!("[{}] CircleCI token is empty", self.id));
+                }
+            }
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(expected = "[circleci] CircleCI project slug is empty")]
+    fn should_return_error_if_circleci_project_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "circleci": {
+                            "id": "circleci",
+                            "project": "",
+                            "credentials": {
+                                "token": "SECRET"
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[circleci] CircleCI project slug is invalid")]
+    fn should_return_error_if_circleci_project_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "circleci": {
+                            "id": "circleci",
+                            "project": "duckhq/duck",
+                            "credentials": {
+                                "token": "SECRET"
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[circleci] CircleCI token is empty")]
+    fn should_return_error_if_circleci_token_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "circleci": {
+                            "id": "circleci",
+                            "project": "gh/duckhq/duck",
+                            "credentials": {
+                                "token": ""
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+}
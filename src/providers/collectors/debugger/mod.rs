@@ -98,6 +98,8 @@ mod tests {
         DebuggerCollector::<MockHttpClient>::new(&DebuggerConfiguration {
             id: "debug".to_owned(),
             enabled: Some(true),
+            interval: None,
+            timeout: None,
             server_url: "http://localhost:5000".to_owned(),
         })
     }
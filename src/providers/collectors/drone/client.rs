@@ -0,0 +1,130 @@
+use log::trace;
+use url::Url;
+
+use crate::builds::BuildStatus;
+use crate::config::{DroneConfiguration, DroneCredentials};
+use crate::utils::http::*;
+use crate::DuckResult;
+
+pub struct DroneClient {
+    pub server_url: Url,
+    pub owner: String,
+    pub repository: String,
+    credentials: DroneCredentials,
+}
+
+impl DroneClient {
+    pub fn new(config: &DroneConfiguration) -> Self {
+        Self {
+            server_url: Url::parse(&config.server_url[..]).unwrap(),
+            owner: config.owner.clone(),
+            repository: config.repository.clone(),
+            credentials: config.credentials.clone(),
+        }
+    }
+
+    pub fn get_builds(&self, client: &impl HttpClient) -> DuckResult<Vec<DroneBuild>> {
+        let url = self.get_url(&["api", "repos", &self.owner, &self.repository, "builds"])?;
+
+        trace!("Sending request to: {}", url);
+        let mut builder = HttpRequestBuilder::get(url.as_str());
+        builder.add_header("Content-Type", "application/json");
+        builder.add_header("Accept", "application/json");
+
+        self.credentials.authenticate(&mut builder);
+        let mut response = client.send(&builder)?;
+
+        trace!("Received response: {}", response.status());
+        if !response.status().is_success() {
+            return Err(format_err!(
+                "Received non 200 HTTP status code. ({})",
+                response.status()
+            ));
+        }
+
+        // Get the response body.
+        let body = response.body()?;
+        // Deserialize and return the value.
+        Ok(serde_json::from_str(&body[..])?)
+    }
+
+    pub fn get_build_url(&self, build: &DroneBuild) -> DuckResult<String> {
+        let number = build.number.to_string();
+        let url = self.get_url(&[&self.owner, &self.repository, &number])?;
+        Ok(url.to_string())
+    }
+
+    fn get_url(&self, parts: &[&str]) -> DuckResult<Url> {
+        let mut url = self.server_url.clone();
+        {
+            let mut segments = url
+                .path_segments_mut()
+                .map_err(|_| format_err!("Invalid Drone server URL"))?;
+            segments.pop_if_empty();
+            segments.extend(parts);
+        }
+        Ok(url)
+    }
+}
+
+impl DroneCredentials {
+    fn authenticate(&self, builder: &mut HttpRequestBuilder) {
+        match self {
+            DroneCredentials::Anonymous => {}
+            DroneCredentials::Token(token) => {
+                builder.bearer(token);
+            }
+        }
+    }
+}
+
+// Woodpecker is a fork of Drone that kept the same API,
+// but renamed some of the fields along the way.
+#[derive(Deserialize, Debug)]
+pub struct DroneBuild {
+    pub id: u64,
+    pub number: u64,
+    pub status: String,
+    pub event: String,
+    #[serde(alias = "branch")]
+    pub target: String,
+    #[serde(alias = "created_at")]
+    pub created: i64,
+    #[serde(default, alias = "started_at")]
+    pub started: i64,
+    #[serde(default, alias = "finished_at")]
+    pub finished: i64,
+}
+
+impl DroneBuild {
+    pub fn get_status(&self) -> BuildStatus {
+        match &self.status[..] {
+            "success" => BuildStatus::Success,
+            "failure" | "error" => BuildStatus::Failed,
+            "running" => BuildStatus::Running,
+            "pending" | "waiting_on_dependencies" => BuildStatus::Queued,
+            // Builds that are blocked are waiting for approval,
+            // and declined builds were never approved.
+            "blocked" => BuildStatus::Blocked,
+            "declined" | "killed" => BuildStatus::Canceled,
+            "skipped" => BuildStatus::Skipped,
+            _ => BuildStatus::Unknown,
+        }
+    }
+
+    pub fn get_started_timestamp(&self) -> i64 {
+        if self.started == 0 {
+            self.created
+        } else {
+            self.started
+        }
+    }
+
+    pub fn get_finished_timestamp(&self) -> Option<i64> {
+        if self.finished == 0 {
+            None
+        } else {
+            Some(self.finished)
+        }
+    }
+}
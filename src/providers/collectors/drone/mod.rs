@@ -0,0 +1,296 @@
+use waithandle::WaitHandleListener;
+
+use crate::builds::{Build, BuildBuilder};
+use crate::config::DroneConfiguration;
+use crate::providers::collectors::{Collector, CollectorInfo, CollectorLoader};
+use crate::utils::http::{HttpClient, ReqwestClient};
+use crate::DuckResult;
+
+use self::client::{DroneBuild, DroneClient};
+
+mod client;
+mod validation;
+
+impl CollectorLoader for DroneConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Collector>> {
+        Ok(Box::new(DroneCollector::<ReqwestClient>::new(self)))
+    }
+}
+
+pub struct DroneCollector<T: HttpClient + Default> {
+    client: DroneClient,
+    http: T,
+    branches: Option<Vec<String>>,
+    info: CollectorInfo,
+}
+
+impl<T: HttpClient + Default> DroneCollector<T> {
+    pub fn new(config: &DroneConfiguration) -> Self {
+        return DroneCollector {
+            client: DroneClient::new(config),
+            http: Default::default(),
+            branches: config.branches.clone(),
+            info: CollectorInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                provider: "Drone".to_owned(),
+            },
+        };
+    }
+
+    #[cfg(test)]
+    pub fn get_client(&self) -> &T {
+        &self.http
+    }
+
+    fn is_included(&self, build: &DroneBuild) -> bool {
+        // Pull requests and tags do not tell us
+        // anything about the state of a branch.
+        if build.event == "pull_request" || build.event == "tag" {
+            return false;
+        }
+        match &self.branches {
+            None => true,
+            Some(branches) => branches.contains(&build.target),
+        }
+    }
+}
+
+impl<T: HttpClient + Default> Collector for DroneCollector<T> {
+    fn info(&self) -> &CollectorInfo {
+        &self.info
+    }
+
+    fn collect(
+        &self,
+        listener: WaitHandleListener,
+        callback: &mut dyn FnMut(Build),
+    ) -> DuckResult<()> {
+        let response = self.client.get_builds(&self.http)?;
+
+        // Convert the builds to a Duck build representation.
+        let mut builds = Vec::<Build>::new();
+        for build in response.iter().filter(|b| self.is_included(b)) {
+            if listener.check() {
+                return Ok(());
+            }
+
+            builds.push(
+                BuildBuilder::new()
+                    .build_id(build.id.to_string())
+                    .provider("Drone")
+                    .origin(self.client.server_url.as_str())
+                    .collector(&self.info.id)
+                    .project_id(format!(
+                        "{}_{}",
+                        &self.client.owner, &self.client.repository
+                    ))
+                    .project_name(format!(
+                        "{}/{}",
+                        &self.client.owner, &self.client.repository
+                    ))
+                    .definition_id("build")
+                    .definition_name("Build")
+                    .build_number(build.number.to_string())
+                    .status(build.get_status())
+                    .url(self.client.get_build_url(build)?)
+                    .started_at(build.get_started_timestamp())
+                    .finished_at(build.get_finished_timestamp())
+                    .branch(&build.target)
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        // Sort the builds by date.
+        builds.sort_by_key(|b| std::cmp::Reverse(b.started_at));
+
+        // Now only keep the latest ones of the branches.
+        let mut branches = std::collections::HashSet::<&String>::new();
+        for build in builds.iter() {
+            if !branches.contains(&build.branch) {
+                branches.insert(&build.branch);
+                callback(build.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::BuildStatus;
+    use crate::config::*;
+    use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
+    use reqwest::StatusCode;
+    use test_case::test_case;
+
+    fn create_collector(branches: Option<Vec<&str>>, body: &str) -> DroneCollector<MockHttpClient> {
+        let collector = DroneCollector::<MockHttpClient>::new(&DroneConfiguration {
+            id: "drone".to_owned(),
+            enabled: Some(true),
+            interval: None,
+            timeout: None,
+            server_url: "https://drone.example.com/".to_owned(),
+            owner: "duckhq".to_owned(),
+            repository: "duck".to_owned(),
+            credentials: DroneCredentials::Token("SECRET".to_owned()),
+            branches: branches.map(|b| b.iter().map(|s| s.to_string()).collect()),
+        });
+
+        collector.get_client().add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://drone.example.com/api/repos/duckhq/duck/builds",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(body),
+        );
+
+        collector
+    }
+
+    fn collect(collector: &DroneCollector<MockHttpClient>) -> Vec<Build> {
+        let (_, listener) = waithandle::new();
+        let mut result = Vec::<Build>::new();
+        collector
+            .collect(listener, &mut |build: Build| {
+                result.push(build);
+            })
+            .unwrap();
+        result
+    }
+
+    #[test]
+    fn should_return_correct_provider_name() {
+        // Given
+        let drone = create_collector(None, include_str!("test_data/builds.json"));
+        // When
+        let provider = &drone.info().provider;
+        // Then
+        assert_eq!("Drone", provider);
+    }
+
+    #[test]
+    fn should_get_correct_data() {
+        // Given
+        let drone = create_collector(None, include_str!("test_data/builds.json"));
+
+        // When
+        let result = collect(&drone);
+
+        // Then
+        assert_eq!(3, result.len());
+        assert_eq!("100210", result[0].build_id);
+        assert_eq!("Drone", result[0].provider);
+        assert_eq!("drone", result[0].collector);
+        assert_eq!("https://drone.example.com/", result[0].origin);
+        assert_eq!("duckhq_duck", result[0].project_id);
+        assert_eq!("duckhq/duck", result[0].project_name);
+        assert_eq!("45", result[0].build_number);
+        assert_eq!(BuildStatus::Blocked, result[0].status);
+        assert_eq!("master", result[0].branch);
+        assert_eq!("https://drone.example.com/duckhq/duck/45", result[0].url);
+        assert_eq!(1580590216, result[0].started_at);
+        assert_eq!(None, result[0].finished_at);
+        assert_eq!("feature/login", result[1].branch);
+        assert_eq!(BuildStatus::Canceled, result[1].status);
+        assert_eq!(1580590096, result[1].finished_at.unwrap());
+        assert_eq!("develop", result[2].branch);
+        assert_eq!(BuildStatus::Running, result[2].status);
+    }
+
+    #[test]
+    fn should_get_correct_data_from_woodpecker() {
+        // Given
+        let drone = create_collector(None, include_str!("test_data/woodpecker.json"));
+
+        // When
+        let result = collect(&drone);
+
+        // Then
+        assert_eq!(2, result.len());
+        assert_eq!("master", result[0].branch);
+        assert_eq!(BuildStatus::Success, result[0].status);
+        assert_eq!(1580589796, result[0].started_at);
+        assert_eq!(1580590096, result[0].finished_at.unwrap());
+        assert_eq!("develop", result[1].branch);
+        assert_eq!(BuildStatus::Failed, result[1].status);
+    }
+
+    #[test]
+    fn should_ignore_pull_requests_and_tags() {
+        // Given
+        let drone = create_collector(None, include_str!("test_data/builds.json"));
+
+        // When
+        let result = collect(&drone);
+
+        // Then
+        assert!(result.iter().all(|b| b.build_number != "44"));
+        assert!(result.iter().all(|b| b.build_number != "43"));
+    }
+
+    #[test]
+    fn should_only_include_specified_branches() {
+        // Given
+        let drone = create_collector(
+            Some(vec!["master", "develop"]),
+            include_str!("test_data/builds.json"),
+        );
+
+        // When
+        let result = collect(&drone);
+
+        // Then
+        assert_eq!(2, result.len());
+        assert_eq!("master", result[0].branch);
+        assert_eq!("develop", result[1].branch);
+    }
+
+    #[test]
+    fn should_authenticate_using_token() {
+        // Given
+        let drone = create_collector(None, include_str!("test_data/builds.json"));
+
+        // When
+        collect(&drone);
+
+        // Then
+        let requests = drone.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(
+            "Bearer SECRET",
+            requests[0].headers.get("Authorization").unwrap()
+        );
+    }
+
+    #[test_case("success", BuildStatus::Success)]
+    #[test_case("failure", BuildStatus::Failed)]
+    #[test_case("error", BuildStatus::Failed)]
+    #[test_case("running", BuildStatus::Running)]
+    #[test_case("pending", BuildStatus::Queued)]
+    #[test_case("blocked", BuildStatus::Blocked)]
+    #[test_case("declined", BuildStatus::Canceled)]
+    #[test_case("killed", BuildStatus::Canceled)]
+    #[test_case("skipped", BuildStatus::Skipped)]
+    fn should_map_build_status(status: &str, expected: BuildStatus) {
+        // Given
+        let build = DroneBuild {
+            id: 1,
+            number: 1,
+            status: status.to_owned(),
+            event: "push".to_owned(),
+            target: "master".to_owned(),
+            created: 1580589796,
+            started: 0,
+            finished: 0,
+        };
+        // When
+        let result = build.get_status();
+        // Then
+        assert_eq!(expected, result);
+    }
+}
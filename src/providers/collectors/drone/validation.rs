@@ -0,0 +1,146 @@
+use url::Url;
+
+use crate::config::{DroneConfiguration, DroneCredentials, Validate};
+use crate::DuckResult;
+
+impl Validate for DroneConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        if let Err(e) = Url::parse(&self.server_url[..]) {
+            return Err(format_err!(
+                "[{}] Drone server URL is invalid: {}",
+                self.id,
+                e
+            ));
+        }
+        if self.owner.is_empty() {
+            return Err(format_err!("[{}] Drone repository owner is empty", self.id));
+        }
+        if self.repository.is_empty() {
+            return Err(format_err!("[{}] Drone repository name is empty", self.id));
+        }
+
+        match &self.credentials {
+            DroneCredentials::Anonymous => {}
+            DroneCredentials::Token(token) => {
+                if token.is_empty() {
+                    return Err(format_err!("[{}] Drone token is empty", self.id));
+                }
+            }
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(expected = "[drone] Drone server URL is invalid")]
+    fn should_return_error_if_drone_server_url_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "drone": {
+                            "id": "drone",
+                            "serverUrl": "not a url",
+                            "owner": "duckhq",
+                            "repository": "duck",
+                            "credentials": "anonymous"
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[drone] Drone repository owner is empty")]
+    fn should_return_error_if_drone_owner_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "drone": {
+                            "id": "drone",
+                            "serverUrl": "https://drone.example.com",
+                            "owner": "",
+                            "repository": "duck",
+                            "credentials": "anonymous"
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[drone] Drone repository name is empty")]
+    fn should_return_error_if_drone_repository_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "drone": {
+                            "id": "drone",
+                            "serverUrl": "https://drone.example.com",
+                            "owner": "duckhq",
+                            "repository": "",
+                            "credentials": "anonymous"
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[drone] Drone token is empty")]
+    fn should_return_error_if_drone_token_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "drone": {
+                            "id": "drone",
+                            "serverUrl": "https://drone.example.com",
+                            "owner": "duckhq",
+                            "repository": "duck",
+                            "credentials": {
+                                "token": ""
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+}
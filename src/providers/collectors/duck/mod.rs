@@ -116,6 +116,8 @@ mod tests {
         DuckCollector::<MockHttpClient>::new(&DuckConfiguration {
             id: "duck_other".to_owned(),
             enabled: Some(true),
+            interval: None,
+            timeout: None,
             server_url: "http://localhost:15826".to_owned(),
             view,
         })
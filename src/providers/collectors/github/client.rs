@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
 use log::trace;
 
 use crate::builds::BuildStatus;
@@ -6,34 +9,71 @@ use crate::utils::date;
 use crate::utils::http::*;
 use crate::DuckResult;
 
+use super::app::GitHubAppAuthenticator;
+
+static DEFAULT_API_URL: &str = "https://api.github.com";
+
 pub struct GitHubClient {
     pub owner: String,
     pub repository: String,
-    pub workflow: String,
-    credentials: GitHubCredentials,
-    etag: std::sync::Mutex<Option<String>>,
-    cached: std::sync::Mutex<Option<String>>,
+    api_url: String,
+    authenticator: GitHubAuthenticator,
+    cache: Mutex<HashMap<String, GitHubCachedResponse>>,
+}
+
+enum GitHubAuthenticator {
+    Basic { username: String, password: String },
+    Token(String),
+    App(Box<GitHubAppAuthenticator>),
+}
+
+/// A response that we can return if GitHub says 304 Not Modified.
+struct GitHubCachedResponse {
+    etag: String,
+    body: String,
 }
 
 impl GitHubClient {
-    pub fn new(config: &GitHubConfiguration) -> Self {
-        Self {
+    pub fn new(config: &GitHubConfiguration) -> DuckResult<Self> {
+        Ok(Self {
             owner: config.owner.clone(),
             repository: config.repository.clone(),
-            workflow: config.workflow.clone(),
-            credentials: config.credentials.clone(),
-            etag: std::sync::Mutex::new(None),
-            cached: std::sync::Mutex::new(None),
-        }
+            api_url: config
+                .api_url
+                .as_deref()
+                .unwrap_or(DEFAULT_API_URL)
+                .trim_end_matches('/')
+                .to_owned(),
+            authenticator: GitHubAuthenticator::new(&config.credentials)?,
+            cache: Mutex::new(HashMap::new()),
+        })
     }
 
-    pub fn get_builds(&self, client: &impl HttpClient) -> DuckResult<GitHubResponse> {
-        let url = format!(
-            "https://api.github.com/repos/{owner}/{repo}/actions/workflows/{workflow}/runs?page=0&per_page=25",
-            owner = self.owner,
-            repo = self.repository,
-            workflow = self.workflow
-        );
+    /// Gets the workflow runs for a specific workflow,
+    /// or for all workflows in the repository.
+    pub fn get_runs(
+        &self,
+        client: &impl HttpClient,
+        workflow: Option<&str>,
+    ) -> DuckResult<GitHubResponse> {
+        let url = match workflow {
+            Some(workflow) => format!(
+                "{api}/repos/{owner}/{repo}/actions/workflows/{workflow}/runs?page=0&per_page=25",
+                api = self.api_url,
+                owner = self.owner,
+                repo = self.repository,
+                workflow = workflow
+            ),
+            // Only the latest page of runs is read. Runs are returned
+            // newest first, so workflows that haven't run within the
+            // latest 100 runs in the repository won't be collected.
+            None => format!(
+                "{api}/repos/{owner}/{repo}/actions/runs?page=0&per_page=100",
+                api = self.api_url,
+                owner = self.owner,
+                repo = self.repository,
+            ),
+        };
 
         trace!("Sending request to: {}", url);
         let mut builder = HttpRequestBuilder::get(&url);
@@ -41,35 +81,26 @@ impl GitHubClient {
         builder.add_header("Accept", "application/json");
 
         // Do we have an etag?
-        let mut etag = self.etag.lock().unwrap();
-        if etag.is_some() {
-            let etag_value = etag.as_ref().unwrap();
-            trace!("Using etag {}", etag_value);
-            builder.add_header("If-None-Match", etag_value);
+        let mut cache = self.cache.lock().unwrap();
+        if let Some(cached) = cache.get(&url) {
+            trace!("Using etag {}", cached.etag);
+            builder.add_header("If-None-Match", &cached.etag);
         }
 
-        self.credentials.authenticate(&mut builder);
+        self.authenticator
+            .authenticate(client, &self.api_url, &mut builder)?;
         let mut response = client.send(&builder)?;
 
-        // Got an etag?
-        let new_etag = response.headers().get("ETag");
-        if let Some(new_etag) = new_etag {
-            let new_etag_value = new_etag.to_str()?;
-            trace!("Got a new etag: {}", new_etag_value);
-            *etag = Some(new_etag_value.to_owned());
-        }
-
         trace!("Received response: {}", response.status());
         if !response.status().is_success() {
             // Not modified?
             if response.status() == reqwest::StatusCode::NOT_MODIFIED {
-                let cached = self.cached.lock().unwrap();
-                if cached.is_none() {
-                    return Err(format_err!(
+                return match cache.get(&url) {
+                    Some(cached) => Ok(serde_json::from_str(&cached.body[..])?),
+                    None => Err(format_err!(
                         "Got a 304 not modified, but we didn't have a response cached"
-                    ));
-                }
-                return Ok(serde_json::from_str(&cached.as_ref().unwrap()[..])?);
+                    )),
+                };
             }
             return Err(format_err!(
                 "Received non 200 HTTP status code. ({})",
@@ -77,32 +108,79 @@ impl GitHubClient {
             ));
         }
 
+        // Got an etag?
+        let etag = match response.headers().get("ETag") {
+            Some(etag) => Some(etag.to_str()?.to_owned()),
+            None => None,
+        };
+
         // Get the response body.
         let body = response.body()?;
 
         // Cache the response. We need this if we return a 304 Not Modified.
-        trace!("Cached the response from GitHub.");
-        let mut cached = self.cached.lock().unwrap();
-        *cached = Some(body.clone());
+        if let Some(etag) = etag {
+            trace!("Got a new etag: {}", etag);
+            cache.insert(
+                url,
+                GitHubCachedResponse {
+                    etag,
+                    body: body.clone(),
+                },
+            );
+        }
 
         // Deserialize and return the value.
         Ok(serde_json::from_str(&body[..])?)
     }
 }
 
-impl GitHubCredentials {
-    fn authenticate(&self, builder: &mut HttpRequestBuilder) {
+impl GitHubAuthenticator {
+    fn new(credentials: &GitHubCredentials) -> DuckResult<Self> {
+        Ok(match credentials {
+            GitHubCredentials::Basic { username, password } => GitHubAuthenticator::Basic {
+                username: username.clone(),
+                password: password.clone(),
+            },
+            GitHubCredentials::Token(token) => GitHubAuthenticator::Token(token.clone()),
+            GitHubCredentials::App {
+                app_id,
+                installation_id,
+                private_key,
+            } => GitHubAuthenticator::App(Box::new(GitHubAppAuthenticator::new(
+                app_id,
+                installation_id,
+                private_key,
+            )?)),
+        })
+    }
+
+    fn authenticate(
+        &self,
+        client: &impl HttpClient,
+        api_url: &str,
+        builder: &mut HttpRequestBuilder,
+    ) -> DuckResult<()> {
         match self {
-            GitHubCredentials::Basic { username, password } => {
+            GitHubAuthenticator::Basic { username, password } => {
                 builder.basic_auth(username, Some(password));
             }
-        }
+            GitHubAuthenticator::Token(token) => {
+                builder.add_header("Authorization", &format!("token {}", token));
+            }
+            GitHubAuthenticator::App(app) => {
+                builder.add_header(
+                    "Authorization",
+                    &format!("token {}", app.get_token(client, api_url)?),
+                );
+            }
+        };
+        Ok(())
     }
 }
 
 #[derive(Deserialize, Debug)]
 pub struct GitHubResponse {
-    pub total_count: u16,
+    pub total_count: u64,
     pub workflow_runs: Vec<GitHubWorkflowRun>,
 }
 
@@ -118,6 +196,8 @@ pub struct GitHubWorkflowRun {
     pub html_url: String,
     pub created_at: String,
     pub updated_at: String,
+    pub workflow_id: Option<u64>,
+    pub path: Option<String>,
 }
 
 impl GitHubWorkflowRun {
@@ -133,12 +213,32 @@ impl GitHubWorkflowRun {
                     _ => Ok(BuildStatus::Failed),
                 },
             },
-            "queued" => Ok(BuildStatus::Queued),
+            "queued" | "requested" | "pending" => Ok(BuildStatus::Queued),
+            "waiting" => Ok(BuildStatus::Blocked),
             "in_progress" => Ok(BuildStatus::Running),
             status => Err(format_err!("Unknown build status '{}'", status)),
         }
     }
 
+    /// Checks whether or not the run belongs to a workflow, which
+    /// is either specified by its file name or by its ID.
+    pub fn is_workflow(&self, workflow: &str) -> bool {
+        self.get_workflow_file() == Some(workflow)
+            || self.workflow_id.map(|id| id.to_string()).as_deref() == Some(workflow)
+    }
+
+    /// Gets the file name of the run's workflow,
+    /// or the workflow ID if the path is unknown.
+    pub fn get_workflow(&self) -> Option<String> {
+        self.get_workflow_file()
+            .map(|file| file.to_owned())
+            .or_else(|| self.workflow_id.map(|id| id.to_string()))
+    }
+
+    fn get_workflow_file(&self) -> Option<&str> {
+        self.path.as_deref().and_then(|p| p.rsplit('/').next())
+    }
+
     pub fn get_started_timestamp(&self) -> DuckResult<i64> {
         let result = date::to_timestamp(&self.created_at, date::GITHUB_FORMAT)?;
         Ok(result)
@@ -1,15 +1,40 @@
+use url::Url;
+
 use crate::config::{GitHubConfiguration, GitHubCredentials, Validate};
 use crate::DuckResult;
 
+use super::app;
+
 impl Validate for GitHubConfiguration {
     fn validate(&self) -> DuckResult<()> {
+        if let Some(api_url) = &self.api_url {
+            if let Err(e) = Url::parse(api_url) {
+                return Err(format_err!(
+                    "[{}] GitHub API URL is invalid: {}",
+                    self.id,
+                    e
+                ));
+            }
+        }
         if self.owner.is_empty() {
             return Err(format_err!("[{}] GitHub owner is empty", self.id));
         }
         if self.repository.is_empty() {
             return Err(format_err!("[{}] GitHub repository is empty", self.id));
         }
-        if self.workflow.is_empty() {
+        if let Err(e) = self.get_branch_matcher() {
+            return Err(format_err!(
+                "[{}] GitHub branches are invalid. {}",
+                self.id,
+                e
+            ));
+        }
+        if self
+            .workflow
+            .iter()
+            .chain(self.workflows.iter().flatten())
+            .any(|w| w.is_empty())
+        {
             return Err(format_err!("[{}] GitHub workflow is empty", self.id));
         }
 
@@ -22,8 +47,41 @@ impl Validate for GitHubConfiguration {
                     return Err(format_err!("[{}] GitHub password is empty", self.id));
                 }
             }
+            GitHubCredentials::Token(token) => {
+                if token.is_empty() {
+                    return Err(format_err!("[{}] GitHub token is empty", self.id));
+                }
+            }
+            GitHubCredentials::App {
+                app_id,
+                installation_id,
+                private_key,
+            } => {
+                if app_id.is_empty() {
+                    return Err(format_err!("[{}] GitHub App ID is empty", self.id));
+                }
+                if installation_id.is_empty() {
+                    return Err(format_err!(
+                        "[{}] GitHub App installation ID is empty",
+                        self.id
+                    ));
+                }
+                if let Err(e) = app::read_private_key(private_key) {
+                    return Err(format_err!(
+                        "[{}] GitHub App private key is invalid: {}",
+                        self.id,
+                        e
+                    ));
+                }
+            }
         };
 
+        if let Some(secret) = &self.webhook_secret {
+            if secret.is_empty() {
+                return Err(format_err!("[{}] GitHub webhook secret is empty", self.id));
+            }
+        }
+
         Ok(())
     }
 }
@@ -221,4 +279,253 @@ mod tests {
 
         providers::create_collectors(&config).unwrap();
     }
+
+    #[test]
+    #[should_panic(expected = "[duck_pullrequests] GitHub webhook secret is empty")]
+    fn should_return_error_if_github_webhook_secret_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "github": {
+                            "id": "duck_pullrequests",
+                            "owner": "spectresystems",
+                            "repository": "duck",
+                            "workflow": "pull_request.yml",
+                            "credentials": {
+                                "basic": {
+                                    "username": "patriksvensson",
+                                    "password": "hunter1!"
+                                }
+                            },
+                            "webhookSecret": ""
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[duck_pullrequests] GitHub API URL is invalid")]
+    fn should_return_error_if_github_api_url_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "github": {
+                            "id": "duck_pullrequests",
+                            "apiUrl": "not a url",
+                            "owner": "spectresystems",
+                            "repository": "duck",
+                            "workflow": "pull_request.yml",
+                            "credentials": {
+                                "basic": {
+                                    "username": "patriksvensson",
+                                    "password": "hunter1!"
+                                }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[duck_pullrequests] GitHub workflow is empty")]
+    fn should_return_error_if_any_github_workflow_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "github": {
+                            "id": "duck_pullrequests",
+                            "owner": "spectresystems",
+                            "repository": "duck",
+                            "workflows": [ "ci.yml", "" ],
+                            "credentials": {
+                                "basic": {
+                                    "username": "patriksvensson",
+                                    "password": "hunter1!"
+                                }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[duck_pullrequests] GitHub token is empty")]
+    fn should_return_error_if_github_token_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "github": {
+                            "id": "duck_pullrequests",
+                            "owner": "spectresystems",
+                            "repository": "duck",
+                            "workflow": "pull_request.yml",
+                            "credentials": { "token": "" }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[duck_pullrequests] GitHub App ID is empty")]
+    fn should_return_error_if_github_app_id_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "github": {
+                            "id": "duck_pullrequests",
+                            "owner": "spectresystems",
+                            "repository": "duck",
+                            "workflow": "pull_request.yml",
+                            "credentials": {
+                                "app": {
+                                    "appId": "",
+                                    "installationId": "5678",
+                                    "privateKey": "KEY"
+                                }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[duck_pullrequests] GitHub App installation ID is empty")]
+    fn should_return_error_if_github_app_installation_id_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "github": {
+                            "id": "duck_pullrequests",
+                            "owner": "spectresystems",
+                            "repository": "duck",
+                            "workflow": "pull_request.yml",
+                            "credentials": {
+                                "app": {
+                                    "appId": "1234",
+                                    "installationId": "",
+                                    "privateKey": "KEY"
+                                }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[duck_pullrequests] GitHub App private key is invalid")]
+    fn should_return_error_if_github_app_private_key_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "github": {
+                            "id": "duck_pullrequests",
+                            "owner": "spectresystems",
+                            "repository": "duck",
+                            "workflow": "pull_request.yml",
+                            "credentials": {
+                                "app": {
+                                    "appId": "1234",
+                                    "installationId": "5678",
+                                    "privateKey": "KEY"
+                                }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[duck_pullrequests] GitHub branches are invalid")]
+    fn should_return_error_if_github_branch_pattern_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "github": {
+                            "id": "duck_pullrequests",
+                            "owner": "spectresystems",
+                            "repository": "duck",
+                            "workflow": "pull_request.yml",
+                            "branches": [ "regex:release/(" ],
+                            "credentials": {
+                                "basic": {
+                                    "username": "patriksvensson",
+                                    "password": "hunter1!"
+                                }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
 }
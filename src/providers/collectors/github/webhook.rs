@@ -0,0 +1,304 @@
+use log::trace;
+
+use crate::builds::Build;
+use crate::config::GitHubConfiguration;
+use crate::DuckResult;
+
+use super::client::GitHubWorkflowRun;
+
+/// The outcome of an event that GitHub sent to Duck.
+pub enum GitHubEvent {
+    /// A workflow run was updated.
+    Build(Box<Build>),
+    /// Something changed that can't be mapped to a build,
+    /// so the collector should be polled right away.
+    Refresh,
+    /// The event was not of interest.
+    Ignored,
+}
+
+#[derive(Deserialize, Debug)]
+struct GitHubWorkflowRunEvent {
+    workflow_run: GitHubWorkflowRun,
+    repository: GitHubRepository,
+}
+
+#[derive(Deserialize, Debug)]
+struct GitHubCheckSuiteEvent {
+    check_suite: GitHubCheckSuite,
+    repository: GitHubRepository,
+}
+
+#[derive(Deserialize, Debug)]
+struct GitHubCheckSuite {
+    app: GitHubApp,
+}
+
+#[derive(Deserialize, Debug)]
+struct GitHubApp {
+    slug: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct GitHubRepository {
+    name: String,
+    owner: GitHubOwner,
+}
+
+#[derive(Deserialize, Debug)]
+struct GitHubOwner {
+    login: String,
+}
+
+impl GitHubRepository {
+    fn is_configured(&self, config: &GitHubConfiguration) -> bool {
+        self.owner.login.eq_ignore_ascii_case(&config.owner)
+            && self.name.eq_ignore_ascii_case(&config.repository)
+    }
+}
+
+/// Parses an event sent by GitHub. The event name is
+/// the value of the `X-GitHub-Event` header.
+pub fn parse_event(
+    config: &GitHubConfiguration,
+    event: &str,
+    payload: &[u8],
+) -> DuckResult<GitHubEvent> {
+    match event {
+        "workflow_run" => {
+            let event: GitHubWorkflowRunEvent = serde_json::from_slice(payload)?;
+            let run = &event.workflow_run;
+            let workflow = match config.get_workflow(run) {
+                Some(workflow)
+                    if event.repository.is_configured(config)
+                        && config.get_branch_matcher()?.is_match(&run.branch) =>
+                {
+                    workflow
+                }
+                _ => {
+                    trace!("Ignoring workflow run {}", run.id);
+                    return Ok(GitHubEvent::Ignored);
+                }
+            };
+            let build = super::create_build(config, &workflow, run)?;
+            Ok(GitHubEvent::Build(Box::new(build)))
+        }
+        "check_suite" => {
+            // Check suites don't tell us which workflow they
+            // belong to, so we need to ask GitHub about that.
+            let event: GitHubCheckSuiteEvent = serde_json::from_slice(payload)?;
+            if event.repository.is_configured(config)
+                && event.check_suite.app.slug == "github-actions"
+            {
+                return Ok(GitHubEvent::Refresh);
+            }
+            Ok(GitHubEvent::Ignored)
+        }
+        _ => Ok(GitHubEvent::Ignored),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::BuildStatus;
+    use crate::config::GitHubCredentials;
+
+    fn create_config(workflow: &str) -> GitHubConfiguration {
+        GitHubConfiguration {
+            id: "github".to_owned(),
+            enabled: Some(true),
+            interval: None,
+            timeout: None,
+            api_url: None,
+            owner: "spectresystems".to_owned(),
+            repository: "duck".to_owned(),
+            workflow: Some(workflow.to_owned()),
+            workflows: None,
+            branches: None,
+            excluded_branches: None,
+            credentials: GitHubCredentials::Basic {
+                username: "foo".to_owned(),
+                password: "lol".to_owned(),
+            },
+            webhook_secret: Some("SECRET".to_owned()),
+        }
+    }
+
+    fn parse(config: &GitHubConfiguration, event: &str, payload: &str) -> GitHubEvent {
+        parse_event(config, event, payload.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn should_convert_workflow_run_to_build() {
+        // Given
+        let config = create_config("pull_request.yml");
+
+        // When
+        let result = parse(
+            &config,
+            "workflow_run",
+            include_str!("test_data/workflow_run.json"),
+        );
+
+        // Then
+        let build = match result {
+            GitHubEvent::Build(build) => build,
+            _ => panic!("Expected a build"),
+        };
+        assert_eq!("33801182", build.build_id);
+        assert_eq!("GitHub", build.provider);
+        assert_eq!("github", build.collector);
+        assert_eq!("spectresystems_duck", build.project_id);
+        assert_eq!("pull_request.yml", build.definition_id);
+        assert_eq!("52", build.build_number);
+        assert_eq!(BuildStatus::Failed, build.status);
+        assert_eq!("feature/GH-30", build.branch);
+        assert_eq!(1580601850, build.started_at);
+        assert_eq!(1580602219, build.finished_at.unwrap());
+    }
+
+    #[test]
+    fn should_match_workflow_run_by_workflow_id() {
+        // Given
+        let config = create_config("2138716");
+
+        // When
+        let result = parse(
+            &config,
+            "workflow_run",
+            include_str!("test_data/workflow_run.json"),
+        );
+
+        // Then
+        assert!(matches!(result, GitHubEvent::Build(_)));
+    }
+
+    #[test]
+    fn should_ignore_workflow_run_for_other_workflow() {
+        // Given
+        let config = create_config("release.yml");
+
+        // When
+        let result = parse(
+            &config,
+            "workflow_run",
+            include_str!("test_data/workflow_run.json"),
+        );
+
+        // Then
+        assert!(matches!(result, GitHubEvent::Ignored));
+    }
+
+    #[test]
+    fn should_accept_workflow_run_from_any_workflow_if_none_is_specified() {
+        // Given
+        let mut config = create_config("pull_request.yml");
+        config.workflow = None;
+
+        // When
+        let result = parse(
+            &config,
+            "workflow_run",
+            include_str!("test_data/workflow_run.json"),
+        );
+
+        // Then
+        match result {
+            GitHubEvent::Build(build) => assert_eq!("pull_request.yml", build.definition_id),
+            _ => panic!("Expected a build"),
+        };
+    }
+
+    #[test]
+    fn should_ignore_workflow_run_for_excluded_branch() {
+        // Given
+        let mut config = create_config("pull_request.yml");
+        config.excluded_branches = Some(vec!["feature/GH-30".to_owned()]);
+
+        // When
+        let result = parse(
+            &config,
+            "workflow_run",
+            include_str!("test_data/workflow_run.json"),
+        );
+
+        // Then
+        assert!(matches!(result, GitHubEvent::Ignored));
+    }
+
+    #[test]
+    fn should_ignore_workflow_run_for_other_repository() {
+        // Given
+        let mut config = create_config("pull_request.yml");
+        config.repository = "spectre.console".to_owned();
+
+        // When
+        let result = parse(
+            &config,
+            "workflow_run",
+            include_str!("test_data/workflow_run.json"),
+        );
+
+        // Then
+        assert!(matches!(result, GitHubEvent::Ignored));
+    }
+
+    #[test]
+    fn should_request_refresh_for_github_actions_check_suite() {
+        // Given
+        let config = create_config("pull_request.yml");
+
+        // When
+        let result = parse(
+            &config,
+            "check_suite",
+            include_str!("test_data/check_suite.json"),
+        );
+
+        // Then
+        assert!(matches!(result, GitHubEvent::Refresh));
+    }
+
+    #[test]
+    fn should_ignore_check_suite_from_other_apps() {
+        // Given
+        let config = create_config("pull_request.yml");
+        let payload =
+            include_str!("test_data/check_suite.json").replace("github-actions", "travis-ci");
+
+        // When
+        let result = parse(&config, "check_suite", &payload);
+
+        // Then
+        assert!(matches!(result, GitHubEvent::Ignored));
+    }
+
+    #[test]
+    fn should_ignore_other_events() {
+        // Given
+        let config = create_config("pull_request.yml");
+
+        // When
+        let result = parse(
+            &config,
+            "ping",
+            r#"{ "zen": "Keep it logically awesome." }"#,
+        );
+
+        // Then
+        assert!(matches!(result, GitHubEvent::Ignored));
+    }
+
+    #[test]
+    fn should_return_error_if_payload_is_invalid() {
+        // Given
+        let config = create_config("pull_request.yml");
+
+        // When
+        let result = parse_event(&config, "workflow_run", b"{}");
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,133 @@
+use log::trace;
+use url::Url;
+
+use crate::builds::BuildStatus;
+use crate::config::{GitLabConfiguration, GitLabCredentials};
+use crate::utils::date;
+use crate::utils::http::*;
+use crate::DuckResult;
+
+pub struct GitLabClient {
+    server_url: Url,
+    pub project: String,
+    credentials: GitLabCredentials,
+}
+
+impl GitLabClient {
+    pub fn new(config: &GitLabConfiguration) -> Self {
+        Self {
+            server_url: match &config.server_url {
+                Some(url) => Url::parse(&url[..]).unwrap(),
+                None => Url::parse("https://gitlab.com").unwrap(),
+            },
+            project: config.project.clone(),
+            credentials: config.credentials.clone(),
+        }
+    }
+
+    pub fn get_origin(&self) -> String {
+        format!("{}{}", self.server_url.as_str(), self.project)
+    }
+
+    pub fn get_pipelines(
+        &self,
+        client: &impl HttpClient,
+        branch: Option<&str>,
+    ) -> DuckResult<Vec<GitLabPipeline>> {
+        // The project path needs to be URL encoded.
+        let project: String =
+            url::form_urlencoded::byte_serialize(self.project.as_bytes()).collect();
+        let mut url = format!(
+            "{server}api/v4/projects/{project}/pipelines?per_page=50",
+            server = self.server_url,
+            project = project
+        );
+        if let Some(branch) = branch {
+            let branch: String = url::form_urlencoded::byte_serialize(branch.as_bytes()).collect();
+            url = format!("{}&ref={}", url, branch);
+        }
+
+        trace!("Sending request to: {}", url);
+        let mut builder = HttpRequestBuilder::get(&url);
+        builder.add_header("Content-Type", "application/json");
+        builder.add_header("Accept", "application/json");
+
+        self.credentials.authenticate(&mut builder);
+        let mut response = client.send(&builder)?;
+
+        trace!("Received response: {}", response.status());
+        if !response.status().is_success() {
+            return Err(format_err!(
+                "Received non 200 HTTP status code. ({})",
+                response.status()
+            ));
+        }
+
+        // Get the response body.
+        let body = response.body()?;
+        // Deserialize and return the value.
+        Ok(serde_json::from_str(&body[..])?)
+    }
+}
+
+impl GitLabCredentials {
+    fn authenticate(&self, builder: &mut HttpRequestBuilder) {
+        match self {
+            GitLabCredentials::Anonymous => {}
+            GitLabCredentials::PrivateToken(token) => {
+                builder.add_header("PRIVATE-TOKEN", token);
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GitLabPipeline {
+    pub id: u64,
+    pub iid: Option<u64>,
+    #[serde(rename = "ref")]
+    pub branch: String,
+    pub status: String,
+    pub source: Option<String>,
+    pub web_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl GitLabPipeline {
+    pub fn get_status(&self) -> BuildStatus {
+        match &self.status[..] {
+            "success" => BuildStatus::Success,
+            "failed" => BuildStatus::Failed,
+            "canceled" => BuildStatus::Canceled,
+            "skipped" => BuildStatus::Skipped,
+            "running" => BuildStatus::Running,
+            "created"
+            | "waiting_for_resource"
+            | "preparing"
+            | "pending"
+            | "scheduled"
+            | "manual" => BuildStatus::Queued,
+            _ => BuildStatus::Unknown,
+        }
+    }
+
+    pub fn get_started_timestamp(&self) -> DuckResult<i64> {
+        let result = date::to_timestamp(&self.created_at, date::GITLAB_FORMAT)?;
+        Ok(result)
+    }
+
+    pub fn get_finished_timestamp(&self) -> DuckResult<Option<i64>> {
+        match &self.status[..] {
+            "success" | "failed" | "canceled" | "skipped" => {
+                let result = date::to_timestamp(&self.updated_at, date::GITLAB_FORMAT)?;
+                Ok(Some(result))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub fn get_build_number(&self) -> u64 {
+        self.iid.unwrap_or(self.id)
+    }
+}
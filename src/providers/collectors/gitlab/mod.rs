@@ -0,0 +1,301 @@
+use std::time::Duration;
+
+use waithandle::WaitHandleListener;
+
+use crate::builds::{Build, BuildBuilder};
+use crate::config::GitLabConfiguration;
+use crate::providers::collectors::{Collector, CollectorInfo, CollectorLoader};
+use crate::utils::http::{HttpClient, ReqwestClient};
+use crate::DuckResult;
+
+use self::client::{GitLabClient, GitLabPipeline};
+
+mod client;
+mod validation;
+
+impl CollectorLoader for GitLabConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Collector>> {
+        Ok(Box::new(GitLabCollector::<ReqwestClient>::new(self)))
+    }
+}
+
+pub struct GitLabCollector<T: HttpClient + Default> {
+    client: GitLabClient,
+    http: T,
+    branches: Option<Vec<String>>,
+    sources: Option<Vec<String>>,
+    info: CollectorInfo,
+}
+
+impl<T: HttpClient + Default> GitLabCollector<T> {
+    pub fn new(config: &GitLabConfiguration) -> Self {
+        return GitLabCollector {
+            client: GitLabClient::new(config),
+            http: Default::default(),
+            branches: config.branches.clone(),
+            sources: config.sources.clone(),
+            info: CollectorInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                provider: "GitLab".to_owned(),
+            },
+        };
+    }
+
+    #[cfg(test)]
+    pub fn get_client(&self) -> &T {
+        &self.http
+    }
+
+    fn get_pipelines(&self, listener: &WaitHandleListener) -> DuckResult<Vec<GitLabPipeline>> {
+        match &self.branches {
+            None => self.client.get_pipelines(&self.http, None),
+            Some(branches) => {
+                let mut result = Vec::new();
+                for branch in branches.iter() {
+                    if listener.check() {
+                        break;
+                    }
+
+                    result.extend(self.client.get_pipelines(&self.http, Some(branch))?);
+
+                    // Wait for a litle time between calls.
+                    if listener.wait(Duration::from_millis(300)) {
+                        break;
+                    }
+                }
+                Ok(result)
+            }
+        }
+    }
+
+    fn is_included(&self, pipeline: &GitLabPipeline) -> bool {
+        match &self.sources {
+            None => true,
+            Some(sources) => match &pipeline.source {
+                Some(source) => sources.contains(source),
+                None => false,
+            },
+        }
+    }
+}
+
+impl<T: HttpClient + Default> Collector for GitLabCollector<T> {
+    fn info(&self) -> &CollectorInfo {
+        &self.info
+    }
+
+    fn collect(
+        &self,
+        listener: WaitHandleListener,
+        callback: &mut dyn FnMut(Build),
+    ) -> DuckResult<()> {
+        let pipelines = self.get_pipelines(&listener)?;
+
+        // Convert the pipelines to a Duck build representation.
+        let mut builds = Vec::<Build>::new();
+        for pipeline in pipelines.iter().filter(|p| self.is_included(p)) {
+            if listener.check() {
+                return Ok(());
+            }
+
+            builds.push(
+                BuildBuilder::new()
+                    .build_id(pipeline.id.to_string())
+                    .provider("GitLab")
+                    .origin(self.client.get_origin())
+                    .collector(&self.info.id)
+                    .project_id(&self.client.project)
+                    .project_name(&self.client.project)
+                    .definition_id("pipeline")
+                    .definition_name("Pipeline")
+                    .build_number(pipeline.get_build_number().to_string())
+                    .status(pipeline.get_status())
+                    .url(&pipeline.web_url)
+                    .started_at(pipeline.get_started_timestamp()?)
+                    .finished_at(pipeline.get_finished_timestamp()?)
+                    .branch(&pipeline.branch)
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        // Sort the builds by date.
+        builds.sort_by_key(|b| std::cmp::Reverse(b.started_at));
+
+        // Now only keep the latest ones of the branches.
+        let mut branches = std::collections::HashSet::<&String>::new();
+        for build in builds.iter() {
+            if !branches.contains(&build.branch) {
+                branches.insert(&build.branch);
+                callback(build.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::BuildStatus;
+    use crate::config::*;
+    use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
+    use reqwest::StatusCode;
+    use test_case::test_case;
+
+    fn create_collector(
+        branches: Option<Vec<&str>>,
+        sources: Option<Vec<&str>>,
+    ) -> GitLabCollector<MockHttpClient> {
+        GitLabCollector::<MockHttpClient>::new(&GitLabConfiguration {
+            id: "gitlab".to_owned(),
+            enabled: Some(true),
+            interval: None,
+            timeout: None,
+            server_url: Some("https://gitlab.example.com".to_owned()),
+            project: "duckhq/duck".to_owned(),
+            credentials: GitLabCredentials::PrivateToken("SECRET".to_owned()),
+            branches: branches.map(|b| b.iter().map(|s| s.to_string()).collect()),
+            sources: sources.map(|s| s.iter().map(|s| s.to_string()).collect()),
+        })
+    }
+
+    fn collect(collector: &GitLabCollector<MockHttpClient>) -> Vec<Build> {
+        let (_, listener) = waithandle::new();
+        let mut result = Vec::<Build>::new();
+        collector
+            .collect(listener, &mut |build: Build| {
+                result.push(build);
+            })
+            .unwrap();
+        result
+    }
+
+    #[test]
+    fn should_return_correct_provider_name() {
+        // Given
+        let gitlab = create_collector(None, None);
+        // When
+        let provider = &gitlab.info().provider;
+        // Then
+        assert_eq!("GitLab", provider);
+    }
+
+    #[test]
+    fn should_get_correct_data() {
+        // Given
+        let gitlab = create_collector(None, None);
+        let client = gitlab.get_client();
+
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://gitlab.example.com/api/v4/projects/duckhq%2Fduck/pipelines?per_page=50",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/pipelines.json")),
+        );
+
+        // When
+        let result = collect(&gitlab);
+
+        // Then
+        assert_eq!(3, result.len());
+        assert_eq!("47", result[0].build_id);
+        assert_eq!("GitLab", result[0].provider);
+        assert_eq!("gitlab", result[0].collector);
+        assert_eq!("https://gitlab.example.com/duckhq/duck", result[0].origin);
+        assert_eq!("duckhq/duck", result[0].project_id);
+        assert_eq!("duckhq/duck", result[0].project_name);
+        assert_eq!("12", result[0].build_number);
+        assert_eq!(BuildStatus::Queued, result[0].status);
+        assert_eq!("feature/foo", result[0].branch);
+        assert_eq!(
+            "https://gitlab.example.com/duckhq/duck/-/pipelines/47",
+            result[0].url
+        );
+        assert_eq!(1580590216, result[0].started_at);
+        assert_eq!(None, result[0].finished_at);
+        assert_eq!("46", result[1].build_id);
+        assert_eq!(BuildStatus::Running, result[1].status);
+        assert_eq!("45", result[2].build_id);
+        assert_eq!(BuildStatus::Failed, result[2].status);
+        assert_eq!(1580590156, result[2].finished_at.unwrap());
+    }
+
+    #[test]
+    fn should_only_include_pipelines_from_specified_sources() {
+        // Given
+        let gitlab = create_collector(None, Some(vec!["push"]));
+        let client = gitlab.get_client();
+
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://gitlab.example.com/api/v4/projects/duckhq%2Fduck/pipelines?per_page=50",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/pipelines.json")),
+        );
+
+        // When
+        let result = collect(&gitlab);
+
+        // Then
+        assert_eq!(3, result.len());
+        assert_eq!("develop", result[2].branch);
+        assert_eq!("44", result[2].build_id);
+        assert_eq!(BuildStatus::Success, result[2].status);
+    }
+
+    #[test]
+    fn should_request_pipelines_for_specified_branches() {
+        // Given
+        let gitlab = create_collector(Some(vec!["master"]), None);
+        let client = gitlab.get_client();
+
+        client.add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                "https://gitlab.example.com/api/v4/projects/duckhq%2Fduck/pipelines?per_page=50&ref=master",
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(include_str!("test_data/pipelines.json")),
+        );
+
+        // When
+        collect(&gitlab);
+
+        // Then
+        let requests = client.get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!("SECRET", requests[0].headers.get("PRIVATE-TOKEN").unwrap());
+    }
+
+    #[test_case("success", BuildStatus::Success)]
+    #[test_case("failed", BuildStatus::Failed)]
+    #[test_case("canceled", BuildStatus::Canceled)]
+    #[test_case("skipped", BuildStatus::Skipped)]
+    #[test_case("running", BuildStatus::Running)]
+    #[test_case("manual", BuildStatus::Queued)]
+    #[test_case("waiting_for_callback", BuildStatus::Unknown)]
+    fn should_map_pipeline_status(status: &str, expected: BuildStatus) {
+        // Given
+        let pipeline = client::GitLabPipeline {
+            id: 1,
+            iid: Some(1),
+            branch: "master".to_owned(),
+            status: status.to_owned(),
+            source: Some("push".to_owned()),
+            web_url: "https://gitlab.example.com/duckhq/duck/-/pipelines/1".to_owned(),
+            created_at: "2020-02-01T20:43:16.085Z".to_owned(),
+            updated_at: "2020-02-01T20:43:16.085Z".to_owned(),
+        };
+        // When
+        let result = pipeline.get_status();
+        // Then
+        assert_eq!(expected, result);
+    }
+}
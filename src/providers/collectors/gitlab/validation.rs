@@ -0,0 +1,148 @@
+use url::Url;
+
+use crate::config::{GitLabConfiguration, GitLabCredentials, Validate};
+use crate::DuckResult;
+
+impl Validate for GitLabConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        if let Some(server_url) = &self.server_url {
+            if let Err(e) = Url::parse(&server_url[..]) {
+                return Err(format_err!(
+                    "[{}] GitLab server URL is invalid: {}",
+                    self.id,
+                    e
+                ));
+            }
+        }
+        if self.project.is_empty() {
+            return Err(format_err!("[{}] GitLab project is empty", self.id));
+        }
+        if let Some(branches) = &self.branches {
+            if branches.iter().any(|b| b.is_empty()) {
+                return Err(format_err!("[{}] A GitLab branch is empty", self.id));
+            }
+        }
+
+        match &self.credentials {
+            GitLabCredentials::Anonymous => {}
+            GitLabCredentials::PrivateToken(token) => {
+                if token.is_empty() {
+                    return Err(format_err!("[{}] GitLab private token is empty", self.id));
+                }
+            }
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(expected = "[gitlab] GitLab server URL is invalid")]
+    fn should_return_error_if_gitlab_server_url_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "gitlab": {
+                            "id": "gitlab",
+                            "serverUrl": "not a url",
+                            "project": "duckhq/duck",
+                            "credentials": {
+                                "privateToken": "SECRET"
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[gitlab] GitLab project is empty")]
+    fn should_return_error_if_gitlab_project_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "gitlab": {
+                            "id": "gitlab",
+                            "project": "",
+                            "credentials": {
+                                "privateToken": "SECRET"
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[gitlab] A GitLab branch is empty")]
+    fn should_return_error_if_gitlab_branch_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "gitlab": {
+                            "id": "gitlab",
+                            "project": "duckhq/duck",
+                            "credentials": "anonymous",
+                            "branches": [ "master", "" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[gitlab] GitLab private token is empty")]
+    fn should_return_error_if_gitlab_private_token_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "gitlab": {
+                            "id": "gitlab",
+                            "project": "duckhq/duck",
+                            "credentials": {
+                                "privateToken": ""
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+}
@@ -0,0 +1,134 @@
+use log::trace;
+use url::Url;
+
+use crate::builds::BuildStatus;
+use crate::config::{JenkinsConfiguration, JenkinsCredentials};
+use crate::utils::http::*;
+use crate::DuckResult;
+
+const TREE: &str = "_class,name,fullName,\
+                    lastBuild[number,url,result,building,timestamp,duration],\
+                    jobs[_class,name,fullName,\
+                    lastBuild[number,url,result,building,timestamp,duration]]";
+
+pub struct JenkinsClient {
+    pub server_url: Url,
+    credentials: JenkinsCredentials,
+}
+
+impl JenkinsClient {
+    pub fn new(config: &JenkinsConfiguration) -> Self {
+        Self {
+            server_url: Url::parse(&config.server_url[..]).unwrap(),
+            credentials: config.credentials.clone(),
+        }
+    }
+
+    pub fn get_job(&self, client: &impl HttpClient, name: &str) -> DuckResult<JenkinsJob> {
+        // A job named 'foo/bar' lives at '/job/foo/job/bar'.
+        let mut url = self.server_url.clone();
+        {
+            let mut segments = url
+                .path_segments_mut()
+                .map_err(|_| format_err!("Invalid Jenkins server URL"))?;
+            segments.pop_if_empty();
+            for part in name.split('/').filter(|p| !p.is_empty()) {
+                segments.push("job").push(part);
+            }
+            segments.push("api").push("json");
+        }
+        url.set_query(Some(&format!("tree={}", TREE)));
+
+        trace!("Sending request to: {}", url);
+        let mut builder = HttpRequestBuilder::get(url.as_str());
+        builder.add_header("Accept", "application/json");
+
+        self.credentials.authenticate(&mut builder);
+        let mut response = client.send(&builder)?;
+
+        trace!("Received response: {}", response.status());
+        if !response.status().is_success() {
+            return Err(format_err!(
+                "Received non 200 HTTP status code. ({})",
+                response.status()
+            ));
+        }
+
+        // Get the response body.
+        let body = response.body()?;
+        // Deserialize and return the value.
+        Ok(serde_json::from_str(&body[..])?)
+    }
+}
+
+impl JenkinsCredentials {
+    fn authenticate(&self, builder: &mut HttpRequestBuilder) {
+        match self {
+            JenkinsCredentials::Anonymous => {}
+            JenkinsCredentials::Basic { username, token } => {
+                builder.basic_auth(username, Some(token));
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JenkinsJob {
+    #[serde(rename = "_class")]
+    pub class: String,
+    pub name: String,
+    #[serde(rename = "fullName")]
+    pub full_name: String,
+    #[serde(rename = "lastBuild")]
+    pub last_build: Option<JenkinsBuild>,
+    #[serde(default)]
+    pub jobs: Vec<JenkinsJob>,
+}
+
+impl JenkinsJob {
+    pub fn is_multibranch(&self) -> bool {
+        self.class == "org.jenkinsci.plugins.workflow.multibranch.WorkflowMultiBranchProject"
+    }
+
+    pub fn is_folder(&self) -> bool {
+        self.class == "com.cloudbees.hudson.plugins.folder.Folder"
+            || self.class == "jenkins.branch.OrganizationFolder"
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JenkinsBuild {
+    pub number: u64,
+    pub url: String,
+    pub result: Option<String>,
+    pub building: bool,
+    pub timestamp: i64,
+    pub duration: i64,
+}
+
+impl JenkinsBuild {
+    pub fn get_status(&self) -> BuildStatus {
+        if self.building {
+            return BuildStatus::Running;
+        }
+        match self.result.as_deref() {
+            Some("SUCCESS") => BuildStatus::Success,
+            Some("FAILURE") | Some("UNSTABLE") => BuildStatus::Failed,
+            Some("ABORTED") => BuildStatus::Canceled,
+            Some("NOT_BUILT") => BuildStatus::Skipped,
+            _ => BuildStatus::Unknown,
+        }
+    }
+
+    pub fn get_started_timestamp(&self) -> i64 {
+        self.timestamp / 1000
+    }
+
+    pub fn get_finished_timestamp(&self) -> Option<i64> {
+        if self.building {
+            None
+        } else {
+            Some((self.timestamp + self.duration) / 1000)
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use std::time::Duration;
+
+use log::trace;
+use percent_encoding::percent_decode_str;
+use waithandle::WaitHandleListener;
+
+use crate::builds::{Build, BuildBuilder};
+use crate::config::JenkinsConfiguration;
+use crate::providers::collectors::{Collector, CollectorInfo, CollectorLoader};
+use crate::utils::http::{HttpClient, ReqwestClient};
+use crate::DuckResult;
+
+use self::client::{JenkinsBuild, JenkinsClient, JenkinsJob};
+
+mod client;
+mod validation;
+
+impl CollectorLoader for JenkinsConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Collector>> {
+        Ok(Box::new(JenkinsCollector::<ReqwestClient>::new(self)))
+    }
+}
+
+pub struct JenkinsCollector<T: HttpClient + Default> {
+    client: JenkinsClient,
+    http: T,
+    jobs: Vec<String>,
+    info: CollectorInfo,
+}
+
+impl<T: HttpClient + Default> JenkinsCollector<T> {
+    pub fn new(config: &JenkinsConfiguration) -> Self {
+        return JenkinsCollector {
+            client: JenkinsClient::new(config),
+            http: Default::default(),
+            jobs: config.jobs.clone(),
+            info: CollectorInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                provider: "Jenkins".to_owned(),
+            },
+        };
+    }
+
+    #[cfg(test)]
+    pub fn get_client(&self) -> &T {
+        &self.http
+    }
+
+    fn collect_job(
+        &self,
+        job: &JenkinsJob,
+        listener: &WaitHandleListener,
+        callback: &mut dyn FnMut(Build),
+    ) -> DuckResult<()> {
+        if job.is_multibranch() {
+            // The child jobs of a multibranch pipeline are branches,
+            // and the job names are the URL encoded branch names.
+            for branch in job.jobs.iter() {
+                if let Some(build) = &branch.last_build {
+                    let name = percent_decode_str(&branch.name).decode_utf8_lossy();
+                    callback(self.create_build(job, job, build, &name));
+                }
+            }
+        } else if job.is_folder() {
+            for child in job.jobs.iter() {
+                if listener.check() {
+                    return Ok(());
+                }
+                if child.is_folder() || child.is_multibranch() {
+                    trace!("Getting jobs for {}...", child.full_name);
+                    let child = self.client.get_job(&self.http, &child.full_name)?;
+                    self.collect_job(&child, listener, callback)?;
+                } else if let Some(build) = &child.last_build {
+                    callback(self.create_build(job, child, build, "default"));
+                }
+            }
+        } else if let Some(build) = &job.last_build {
+            callback(self.create_build(job, job, build, "default"));
+        }
+
+        Ok(())
+    }
+
+    fn create_build(
+        &self,
+        project: &JenkinsJob,
+        definition: &JenkinsJob,
+        build: &JenkinsBuild,
+        branch: &str,
+    ) -> Build {
+        BuildBuilder::new()
+            .build_id(build.number.to_string())
+            .provider("Jenkins")
+            .origin(self.client.server_url.as_str())
+            .collector(&self.info.id)
+            .project_id(&project.full_name)
+            .project_name(&project.full_name)
+            .definition_id(&definition.full_name)
+            .definition_name(&definition.name)
+            .build_number(build.number.to_string())
+            .status(build.get_status())
+            .url(&build.url)
+            .started_at(build.get_started_timestamp())
+            .finished_at(build.get_finished_timestamp())
+            .branch(branch)
+            .build()
+            .unwrap()
+    }
+}
+
+impl<T: HttpClient + Default> Collector for JenkinsCollector<T> {
+    fn info(&self) -> &CollectorInfo {
+        &self.info
+    }
+
+    fn collect(
+        &self,
+        listener: WaitHandleListener,
+        callback: &mut dyn FnMut(Build),
+    ) -> DuckResult<()> {
+        for name in self.jobs.iter() {
+            if listener.check() {
+                return Ok(());
+            }
+
+            trace!("Getting jobs for {}...", name);
+            let job = self.client.get_job(&self.http, name)?;
+            self.collect_job(&job, &listener, callback)?;
+
+            // Wait for a little time between calls.
+            if listener.wait(Duration::from_millis(300)) {
+                return Ok(());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::BuildStatus;
+    use crate::config::*;
+    use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
+    use reqwest::StatusCode;
+    use test_case::test_case;
+
+    static TREE: &str = "tree=_class,name,fullName,\
+                         lastBuild[number,url,result,building,timestamp,duration],\
+                         jobs[_class,name,fullName,\
+                         lastBuild[number,url,result,building,timestamp,duration]]";
+
+    fn create_collector(jobs: Vec<&str>) -> JenkinsCollector<MockHttpClient> {
+        JenkinsCollector::<MockHttpClient>::new(&JenkinsConfiguration {
+            id: "jenkins".to_owned(),
+            enabled: Some(true),
+            interval: None,
+            timeout: None,
+            server_url: "https://jenkins.example.com".to_owned(),
+            credentials: JenkinsCredentials::Basic {
+                username: "patrik".to_owned(),
+                token: "SECRET".to_owned(),
+            },
+            jobs: jobs.iter().map(|j| j.to_string()).collect(),
+        })
+    }
+
+    fn add_response(collector: &JenkinsCollector<MockHttpClient>, path: &str, body: &str) {
+        collector.get_client().add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Get,
+                format!("https://jenkins.example.com/{}/api/json?{}", path, TREE),
+            )
+            .returns_status(StatusCode::OK)
+            .returns_body(body),
+        );
+    }
+
+    fn collect(collector: &JenkinsCollector<MockHttpClient>) -> Vec<Build> {
+        let (_, listener) = waithandle::new();
+        let mut result = Vec::<Build>::new();
+        collector
+            .collect(listener, &mut |build: Build| {
+                result.push(build);
+            })
+            .unwrap();
+        result
+    }
+
+    #[test]
+    fn should_return_correct_provider_name() {
+        // Given
+        let jenkins = create_collector(vec!["duck"]);
+        // When
+        let provider = &jenkins.info().provider;
+        // Then
+        assert_eq!("Jenkins", provider);
+    }
+
+    #[test]
+    fn should_get_correct_data_for_job() {
+        // Given
+        let jenkins = create_collector(vec!["duck"]);
+        add_response(&jenkins, "job/duck", include_str!("test_data/job.json"));
+
+        // When
+        let result = collect(&jenkins);
+
+        // Then
+        assert_eq!(1, result.len());
+        assert_eq!("42", result[0].build_id);
+        assert_eq!("Jenkins", result[0].provider);
+        assert_eq!("jenkins", result[0].collector);
+        assert_eq!("https://jenkins.example.com/", result[0].origin);
+        assert_eq!("duck", result[0].project_id);
+        assert_eq!("duck", result[0].project_name);
+        assert_eq!("duck", result[0].definition_id);
+        assert_eq!("duck", result[0].definition_name);
+        assert_eq!("42", result[0].build_number);
+        assert_eq!(BuildStatus::Success, result[0].status);
+        assert_eq!("default", result[0].branch);
+        assert_eq!("https://jenkins.example.com/job/duck/42/", result[0].url);
+        assert_eq!(1580589796, result[0].started_at);
+        assert_eq!(1580589916, result[0].finished_at.unwrap());
+    }
+
+    #[test]
+    fn should_get_branches_for_multibranch_pipeline() {
+        // Given
+        let jenkins = create_collector(vec!["apps/duck-multibranch"]);
+        add_response(
+            &jenkins,
+            "job/apps/job/duck-multibranch",
+            include_str!("test_data/multibranch.json"),
+        );
+
+        // When
+        let result = collect(&jenkins);
+
+        // Then
+        assert_eq!(2, result.len());
+        assert_eq!("apps/duck-multibranch", result[0].project_id);
+        assert_eq!("apps/duck-multibranch", result[0].definition_id);
+        assert_eq!("duck-multibranch", result[0].definition_name);
+        assert_eq!("master", result[0].branch);
+        assert_eq!(BuildStatus::Running, result[0].status);
+        assert_eq!(None, result[0].finished_at);
+        assert_eq!("feature/foo", result[1].branch);
+        assert_eq!(BuildStatus::Failed, result[1].status);
+    }
+
+    #[test]
+    fn should_get_jobs_in_folders() {
+        // Given
+        let jenkins = create_collector(vec!["apps"]);
+        add_response(&jenkins, "job/apps", include_str!("test_data/folder.json"));
+        add_response(
+            &jenkins,
+            "job/apps/job/duck-multibranch",
+            include_str!("test_data/multibranch.json"),
+        );
+
+        // When
+        let result = collect(&jenkins);
+
+        // Then
+        assert_eq!(3, result.len());
+        assert_eq!("apps", result[0].project_id);
+        assert_eq!("apps/nightly", result[0].definition_id);
+        assert_eq!("nightly", result[0].definition_name);
+        assert_eq!(BuildStatus::Canceled, result[0].status);
+        assert_eq!("apps/duck-multibranch", result[1].project_id);
+        assert_eq!("master", result[1].branch);
+        assert_eq!("feature/foo", result[2].branch);
+    }
+
+    #[test]
+    fn should_authenticate_using_api_token() {
+        // Given
+        let jenkins = create_collector(vec!["duck"]);
+        add_response(&jenkins, "job/duck", include_str!("test_data/job.json"));
+
+        // When
+        collect(&jenkins);
+
+        // Then
+        let requests = jenkins.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(
+            "Basic cGF0cmlrOlNFQ1JFVA==",
+            requests[0].headers.get("Authorization").unwrap()
+        );
+    }
+
+    #[test_case(Some("SUCCESS"), false, BuildStatus::Success ; "Success")]
+    #[test_case(Some("FAILURE"), false, BuildStatus::Failed ; "Failure")]
+    #[test_case(Some("UNSTABLE"), false, BuildStatus::Failed ; "Unstable")]
+    #[test_case(Some("ABORTED"), false, BuildStatus::Canceled ; "Aborted")]
+    #[test_case(Some("NOT_BUILT"), false, BuildStatus::Skipped ; "Not built")]
+    #[test_case(None, true, BuildStatus::Running ; "Building")]
+    #[test_case(None, false, BuildStatus::Unknown ; "Unknown")]
+    fn should_map_build_status(result: Option<&str>, building: bool, expected: BuildStatus) {
+        // Given
+        let build = JenkinsBuild {
+            number: 1,
+            url: "https://jenkins.example.com/job/duck/1/".to_owned(),
+            result: result.map(|r| r.to_owned()),
+            building,
+            timestamp: 0,
+            duration: 0,
+        };
+
+        // When
+        let status = build.get_status();
+
+        // Then
+        assert_eq!(expected, status);
+    }
+}
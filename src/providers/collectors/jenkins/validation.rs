@@ -0,0 +1,181 @@
+use url::Url;
+
+use crate::config::{JenkinsConfiguration, JenkinsCredentials, Validate};
+use crate::DuckResult;
+
+impl Validate for JenkinsConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        if let Err(e) = Url::parse(&self.server_url[..]) {
+            return Err(format_err!(
+                "[{}] Jenkins server URL is invalid: {}",
+                self.id,
+                e
+            ));
+        }
+        if self.jobs.is_empty() {
+            return Err(format_err!(
+                "[{}] Jenkins configuration have not specified any jobs",
+                self.id
+            ));
+        }
+        if self.jobs.iter().any(|j| j.is_empty()) {
+            return Err(format_err!("[{}] A Jenkins job is empty", self.id));
+        }
+
+        match &self.credentials {
+            JenkinsCredentials::Anonymous => {}
+            JenkinsCredentials::Basic { username, token } => {
+                if username.is_empty() {
+                    return Err(format_err!("[{}] Jenkins username is empty", self.id));
+                }
+                if token.is_empty() {
+                    return Err(format_err!("[{}] Jenkins API token is empty", self.id));
+                }
+            }
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(expected = "[jenkins] Jenkins server URL is invalid")]
+    fn should_return_error_if_jenkins_server_url_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "jenkins": {
+                            "id": "jenkins",
+                            "serverUrl": "not a url",
+                            "credentials": "anonymous",
+                            "jobs": [ "duck" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[jenkins] Jenkins configuration have not specified any jobs")]
+    fn should_return_error_if_jenkins_jobs_are_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "jenkins": {
+                            "id": "jenkins",
+                            "serverUrl": "https://jenkins.example.com",
+                            "credentials": "anonymous",
+                            "jobs": [ ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[jenkins] A Jenkins job is empty")]
+    fn should_return_error_if_a_jenkins_job_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "jenkins": {
+                            "id": "jenkins",
+                            "serverUrl": "https://jenkins.example.com",
+                            "credentials": "anonymous",
+                            "jobs": [ "duck", "" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[jenkins] Jenkins username is empty")]
+    fn should_return_error_if_jenkins_username_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "jenkins": {
+                            "id": "jenkins",
+                            "serverUrl": "https://jenkins.example.com",
+                            "credentials": {
+                                "basic": {
+                                    "username": "",
+                                    "token": "SECRET"
+                                }
+                            },
+                            "jobs": [ "duck" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[jenkins] Jenkins API token is empty")]
+    fn should_return_error_if_jenkins_token_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "jenkins": {
+                            "id": "jenkins",
+                            "serverUrl": "https://jenkins.example.com",
+                            "credentials": {
+                                "basic": {
+                                    "username": "patrik",
+                                    "token": ""
+                                }
+                            },
+                            "jobs": [ "duck" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+}
@@ -21,9 +21,25 @@ impl OctopusDeployClient {
         }
     }
 
-    pub fn get_dashboard(&self) -> DuckResult<OctopusDashboard> {
+    /// Finds the ID of a space by its ID, name or slug.
+    pub fn find_space(&self, space: &str) -> DuckResult<String> {
         let mut response =
-            self.send_get_request(&format!("{url}api/dashboard", url = self.url,)[..])?;
+            self.send_get_request(&format!("{url}api/spaces/all", url = self.url)[..])?;
+        let spaces: Vec<OctopusSpace> = response.json()?;
+        match spaces.iter().find(|s| {
+            s.id == space || s.name.eq_ignore_ascii_case(space) || s.slug.as_deref() == Some(space)
+        }) {
+            Some(found) => Ok(found.id.clone()),
+            None => Err(format_err!("The space '{}' does not exist.", space)),
+        }
+    }
+
+    pub fn get_dashboard(&self, space_id: Option<&str>) -> DuckResult<OctopusDashboard> {
+        let url = match space_id {
+            Some(space) => format!("{url}api/{space}/dashboard", url = self.url, space = space),
+            None => format!("{url}api/dashboard", url = self.url),
+        };
+        let mut response = self.send_get_request(&url[..])?;
         let result: OctopusDashboard = response.json()?;
         Ok(result)
     }
@@ -61,10 +77,30 @@ pub struct OctopusDashboard {
     pub projects: Vec<OctopusProject>,
     #[serde(rename = "Environments")]
     pub environments: Vec<OctopusEnvironment>,
+    #[serde(rename = "Tenants", default)]
+    pub tenants: Vec<OctopusTenant>,
     #[serde(rename = "Items")]
     pub deployments: Vec<OctopusDeployment>,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct OctopusSpace {
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Slug", default)]
+    pub slug: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct OctopusTenant {
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct OctopusProject {
     #[serde(rename = "Id")]
@@ -93,6 +129,8 @@ pub struct OctopusDeployment {
     pub project: String,
     #[serde(rename = "EnvironmentId")]
     pub environment: String,
+    #[serde(rename = "TenantId", default)]
+    pub tenant: Option<String>,
     #[serde(rename = "ReleaseId")]
     pub release_id: String,
     #[serde(rename = "ReleaseVersion")]
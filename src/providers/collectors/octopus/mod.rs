@@ -15,6 +15,7 @@ impl CollectorLoader for OctopusDeployConfiguration {
     fn load(&self) -> DuckResult<Box<dyn Collector>> {
         Ok(Box::new(OctopusDeployCollector {
             server_url: Url::parse(&self.server_url[..]).unwrap(),
+            space: self.space.clone(),
             projects: self.projects.clone(),
             client: OctopusDeployClient::new(
                 Url::parse(&self.server_url[..]).unwrap(),
@@ -31,11 +32,68 @@ impl CollectorLoader for OctopusDeployConfiguration {
 
 pub struct OctopusDeployCollector {
     server_url: Url,
+    space: Option<String>,
     projects: Vec<OctopusDeployProject>,
     client: OctopusDeployClient,
     info: CollectorInfo,
 }
 
+impl OctopusDeployCollector {
+    fn create_build(
+        &self,
+        space_id: Option<&str>,
+        project: &OctopusProject,
+        environment: &OctopusEnvironment,
+        tenant: Option<&OctopusTenant>,
+        deployment: &OctopusDeployment,
+    ) -> DuckResult<Build> {
+        // Tenanted deployments get a definition per tenant.
+        let (definition_id, definition_name) = match tenant {
+            Some(tenant) => (
+                format!("{}/{}", environment.id, tenant.id),
+                format!("{} / {}", environment.name, tenant.name),
+            ),
+            None => (environment.id.clone(), environment.name.clone()),
+        };
+
+        Ok(BuildBuilder::new()
+            .build_id(&deployment.id)
+            .provider("OctopusDeploy")
+            .origin(self.server_url.as_str())
+            .collector(&self.info.id)
+            .project_id(&project.id)
+            .project_name(&project.name)
+            .definition_id(definition_id)
+            .definition_name(definition_name)
+            .build_number(&deployment.release_version)
+            .status(deployment.get_status())
+            .url(format!(
+                "{}app#/{}projects/{}/releases/{}/deployments/{}",
+                self.server_url,
+                match space_id {
+                    Some(space) => format!("{}/", space),
+                    None => String::new(),
+                },
+                project.slug,
+                deployment.release_id,
+                deployment.id
+            ))
+            .started_at(date::to_timestamp(
+                &deployment.get_start_time()[..],
+                date::OCTOPUS_DEPLOY_FORMAT,
+            )?)
+            .finished_at(match &deployment.finish_time {
+                Option::None => None,
+                Option::Some(value) => {
+                    Option::Some(date::to_timestamp(&value[..], date::OCTOPUS_DEPLOY_FORMAT)?)
+                }
+            })
+            .branch(&deployment.release_id)
+            .build()
+            .unwrap())
+    }
+}
+
 impl Collector for OctopusDeployCollector {
     fn info(&self) -> &CollectorInfo {
         &self.info
@@ -46,7 +104,11 @@ impl Collector for OctopusDeployCollector {
         listener: WaitHandleListener,
         callback: &mut dyn FnMut(Build),
     ) -> DuckResult<()> {
-        let response = self.client.get_dashboard()?;
+        let space_id = match &self.space {
+            Some(space) => Some(self.client.find_space(space)?),
+            None => None,
+        };
+        let response = self.client.get_dashboard(space_id.as_deref())?;
 
         for project in self.projects.iter() {
             if listener.check() {
@@ -84,52 +146,29 @@ impl Collector for OctopusDeployCollector {
                     );
                 }
 
-                // Get the deployment for the project and environment combination.
-                let deployment = match response.find_deployment(found_project, found_environment) {
-                    Some(d) => d,
-                    None => {
-                        warn!(
-                            "No deployment found for Environment '{}' in project '{}'",
-                            environment, found_project.name
-                        );
-                        continue;
-                    }
-                };
-
-                callback(
-                    BuildBuilder::new()
-                        .build_id(&deployment.id)
-                        .provider("OctopusDeploy")
-                        .origin(self.server_url.as_str())
-                        .collector(&self.info.id)
-                        .project_id(&found_project.id)
-                        .project_name(&found_project.name)
-                        .definition_id(&found_environment.id)
-                        .definition_name(&found_environment.name)
-                        .build_number(&deployment.release_version)
-                        .status(deployment.get_status())
-                        .url(format!(
-                            "{}app#/projects/{}/releases/{}/deployments/{}",
-                            self.server_url,
-                            found_project.slug,
-                            deployment.release_id,
-                            deployment.id
-                        ))
-                        .started_at(date::to_timestamp(
-                            &deployment.get_start_time()[..],
-                            date::OCTOPUS_DEPLOY_FORMAT,
-                        )?)
-                        .finished_at(match &deployment.finish_time {
-                            Option::None => None,
-                            Option::Some(value) => Option::Some(date::to_timestamp(
-                                &value[..],
-                                date::OCTOPUS_DEPLOY_FORMAT,
-                            )?),
-                        })
-                        .branch(&deployment.release_id)
-                        .build()
-                        .unwrap(),
+                // Get the deployments for the project and environment combination.
+                let deployments = response.find_deployments(
+                    found_project,
+                    found_environment,
+                    project.tenants.as_deref(),
                 );
+                if deployments.is_empty() {
+                    warn!(
+                        "No deployment found for Environment '{}' in project '{}'",
+                        environment, found_project.name
+                    );
+                    continue;
+                }
+
+                for (tenant, deployment) in deployments {
+                    callback(self.create_build(
+                        space_id.as_deref(),
+                        found_project,
+                        found_environment,
+                        tenant,
+                        deployment,
+                    )?);
+                }
             }
         }
 
@@ -138,22 +177,64 @@ impl Collector for OctopusDeployCollector {
 }
 
 impl OctopusDashboard {
-    pub fn find_project(&self, id: &str) -> Option<&OctopusProject> {
-        self.projects.iter().find(|&p| p.id == id)
+    /// Finds a project by its ID, name or slug.
+    pub fn find_project(&self, project: &str) -> Option<&OctopusProject> {
+        self.projects
+            .iter()
+            .find(|&p| p.id == project || p.slug == project || p.name.eq_ignore_ascii_case(project))
     }
 
-    pub fn get_environment(&self, name: &str) -> Option<&OctopusEnvironment> {
-        self.environments.iter().find(|&e| e.id == name)
+    /// Gets an environment by its ID or name.
+    pub fn get_environment(&self, environment: &str) -> Option<&OctopusEnvironment> {
+        self.environments
+            .iter()
+            .find(|&e| e.id == environment || e.name.eq_ignore_ascii_case(environment))
     }
 
-    pub fn find_deployment(
+    /// Finds the latest deployment for each tenant of a project and
+    /// environment combination. Untenanted deployments have no tenant
+    /// and are excluded if any tenants are specified.
+    pub fn find_deployments(
         &self,
         project: &OctopusProject,
         environment: &OctopusEnvironment,
-    ) -> Option<&OctopusDeployment> {
-        self.deployments
+        tenants: Option<&[String]>,
+    ) -> Vec<(Option<&OctopusTenant>, &OctopusDeployment)> {
+        let mut result = Vec::<(Option<&OctopusTenant>, &OctopusDeployment)>::new();
+        for deployment in self
+            .deployments
+            .iter()
+            .filter(|&d| d.project == project.id && d.environment == environment.id)
+        {
+            if result.iter().any(|(_, d)| d.tenant == deployment.tenant) {
+                continue;
+            }
+
+            let tenant = match &deployment.tenant {
+                Some(id) => match self.tenants.iter().find(|t| &t.id == id) {
+                    Some(t) => Some(t),
+                    None => continue,
+                },
+                None => None,
+            };
+            if let Some(tenants) = tenants {
+                match tenant {
+                    Some(t) if t.is_any_of(tenants) => {}
+                    _ => continue,
+                }
+            }
+
+            result.push((tenant, deployment));
+        }
+        result
+    }
+}
+
+impl OctopusTenant {
+    fn is_any_of(&self, tenants: &[String]) -> bool {
+        tenants
             .iter()
-            .find(|&d| d.project == project.id && d.environment == environment.id)
+            .any(|t| t == &self.id || t.eq_ignore_ascii_case(&self.name))
     }
 }
 
@@ -183,3 +264,92 @@ impl OctopusDeployment {
         .unwrap_or_else(|| self.created_time.clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_dashboard() -> OctopusDashboard {
+        serde_json::from_str(include_str!("test_data/dashboard.json")).unwrap()
+    }
+
+    #[test]
+    fn should_find_project_by_id_name_or_slug() {
+        // Given
+        let dashboard = get_dashboard();
+        // When, Then
+        assert_eq!(
+            "Projects-142",
+            dashboard.find_project("Projects-142").unwrap().id
+        );
+        assert_eq!(
+            "Projects-142",
+            dashboard.find_project("customer portal").unwrap().id
+        );
+        assert_eq!(
+            "Projects-142",
+            dashboard.find_project("customer-portal").unwrap().id
+        );
+        assert!(dashboard.find_project("Projects-2").is_none());
+    }
+
+    #[test]
+    fn should_get_environment_by_id_or_name() {
+        // Given
+        let dashboard = get_dashboard();
+        // When, Then
+        assert_eq!(
+            "Environments-1",
+            dashboard.get_environment("Environments-1").unwrap().id
+        );
+        assert_eq!(
+            "Environments-1",
+            dashboard.get_environment("staging").unwrap().id
+        );
+        assert!(dashboard.get_environment("Test").is_none());
+    }
+
+    #[test]
+    fn should_find_latest_deployment_per_tenant() {
+        // Given
+        let dashboard = get_dashboard();
+        let project = dashboard.find_project("Projects-142").unwrap();
+        let environment = dashboard.get_environment("Production").unwrap();
+        // When
+        let result = dashboard.find_deployments(project, environment, None);
+        // Then
+        assert_eq!(2, result.len());
+        assert_eq!("Contoso", result[0].0.unwrap().name);
+        assert_eq!("Deployments-31", result[0].1.id);
+        assert_eq!("Fabrikam", result[1].0.unwrap().name);
+        assert_eq!("Deployments-30", result[1].1.id);
+    }
+
+    #[test]
+    fn should_only_find_deployments_for_specified_tenants() {
+        // Given
+        let dashboard = get_dashboard();
+        let project = dashboard.find_project("Projects-142").unwrap();
+        let environment = dashboard.get_environment("Production").unwrap();
+        let tenants = vec!["fabrikam".to_owned()];
+        // When
+        let result = dashboard.find_deployments(project, environment, Some(&tenants));
+        // Then
+        assert_eq!(1, result.len());
+        assert_eq!("Deployments-30", result[0].1.id);
+    }
+
+    #[test]
+    fn should_find_untenanted_deployment() {
+        // Given
+        let dashboard = get_dashboard();
+        let project = dashboard.find_project("web-app").unwrap();
+        let environment = dashboard.get_environment("Production").unwrap();
+        // When
+        let result = dashboard.find_deployments(project, environment, None);
+        // Then
+        assert_eq!(1, result.len());
+        assert!(result[0].0.is_none());
+        assert_eq!("Deployments-12", result[0].1.id);
+    }
+}
@@ -13,6 +13,12 @@ impl Validate for OctopusDeployConfiguration {
             ));
         }
 
+        if let Some(space) = &self.space {
+            if space.is_empty() {
+                return Err(format_err!("[{}] Octopus Deploy space is empty", self.id));
+            }
+        }
+
         if self.projects.is_empty() {
             return Err(format_err!(
                 "[{}] Octopus Deploy projects are empty",
@@ -305,4 +311,37 @@ mod tests {
 
         providers::create_collectors(&config).unwrap();
     }
+
+    #[test]
+    #[should_panic(expected = "[foo] Octopus Deploy space is empty")]
+    fn should_return_error_if_octopus_deploy_space_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "octopus": {
+                            "id": "foo",
+                            "serverUrl": "http://localhost:9000",
+                            "credentials": {
+                                "apiKey": "MY-SECRET-API-KEY"
+                            },
+                            "space": "",
+                            "projects": [
+                                {
+                                    "project": "Web App",
+                                    "environments": [ "Production" ]
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
 }
@@ -24,7 +24,7 @@ impl TeamCityClient {
 
     pub fn is_online(&self) -> bool {
         self.send_get_request(format!(
-            "{url}{authtype}/app/rest/server",
+            "{url}{authtype}app/rest/server",
             url = self.url,
             authtype = self.credentials.get_auth_type()
         ))
@@ -34,7 +34,7 @@ impl TeamCityClient {
     pub fn get_build_types(&self) -> DuckResult<Vec<TeamCityBuildTypeModel>> {
         // Get all branches for this build configuration.
         let mut response = self.send_get_request(format!(
-            "{url}{authtype}/app/rest/buildTypes",
+            "{url}{authtype}app/rest/buildTypes",
             url = self.url,
             authtype = self.credentials.get_auth_type()
         ))?;
@@ -44,13 +44,30 @@ impl TeamCityClient {
         Ok(result.build_types)
     }
 
+    /// Gets all build types in a project and its subprojects.
+    pub fn get_project_build_types(
+        &self,
+        project_id: &str,
+    ) -> DuckResult<Vec<TeamCityBuildTypeModel>> {
+        let mut response = self.send_get_request(format!(
+            "{url}{authtype}app/rest/buildTypes?locator=affectedProject:(id:{project})",
+            url = self.url,
+            authtype = self.credentials.get_auth_type(),
+            project = project_id
+        ))?;
+
+        let result: TeamCityBuildTypeCollectionModel = response.json()?;
+
+        Ok(result.build_types)
+    }
+
     pub fn get_builds(
         &self,
         build_type: &TeamCityBuildTypeModel,
     ) -> DuckResult<TeamCityBranchCollectionModel> {
         // Get all branches for this build configuration.
         let mut response = self.send_get_request(format!(
-            "{url}{authtype}/app/rest/buildTypes/id:{id}/branches?locator=default:any\
+            "{url}{authtype}app/rest/buildTypes/id:{id}/branches?locator=default:any\
              &fields=count,branch(name,default,active,builds(build(id,number,running,status,\
              branchName,webUrl,startDate,finishDate),count,$locator(running:any,canceled:any,count:1)))",
             url = self.url,
@@ -80,8 +97,10 @@ impl TeamCityClient {
 impl TeamCityAuth {
     pub fn get_auth_type(&self) -> String {
         return match self {
-            TeamCityAuth::Guest => "guestAuth".to_string(),
-            TeamCityAuth::BasicAuth { .. } => "httpAuth".to_string(),
+            TeamCityAuth::Guest => "guestAuth/".to_string(),
+            TeamCityAuth::BasicAuth { .. } => "httpAuth/".to_string(),
+            // Access tokens are used without an authentication prefix.
+            TeamCityAuth::Token(_) => "".to_string(),
         };
     }
     pub fn authenticate(&self, builder: RequestBuilder) -> RequestBuilder {
@@ -90,6 +109,7 @@ impl TeamCityAuth {
             TeamCityAuth::BasicAuth { username, password } => {
                 builder.basic_auth(username, Some(password))
             }
+            TeamCityAuth::Token(token) => builder.bearer_auth(token),
         };
     }
 }
@@ -101,7 +121,7 @@ pub struct TeamCityBuildTypeCollectionModel {
     pub build_types: Vec<TeamCityBuildTypeModel>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct TeamCityBuildTypeModel {
     pub id: String,
     pub name: String,
@@ -4,6 +4,7 @@ use waithandle::WaitHandleListener;
 use crate::builds::{Build, BuildBuilder, BuildStatus};
 use crate::config::TeamCityConfiguration;
 use crate::providers::collectors::{Collector, CollectorInfo};
+use crate::utils::branches::BranchMatcher;
 use crate::utils::date;
 use crate::DuckResult;
 
@@ -18,6 +19,11 @@ impl CollectorLoader for TeamCityConfiguration {
         Ok(Box::new(TeamCityCollector {
             client: TeamCityClient::new(self),
             build_types: self.builds.clone(),
+            projects: self.projects.clone().unwrap_or_default(),
+            branches: BranchMatcher::new(
+                self.branches.as_deref().unwrap_or_default(),
+                self.excluded_branches.as_deref().unwrap_or_default(),
+            )?,
             info: CollectorInfo {
                 id: self.id.clone(),
                 enabled: self.enabled.unwrap_or(true),
@@ -30,9 +36,62 @@ impl CollectorLoader for TeamCityConfiguration {
 pub struct TeamCityCollector {
     client: TeamCityClient,
     build_types: Vec<String>,
+    projects: Vec<String>,
+    branches: BranchMatcher,
     info: CollectorInfo,
 }
 
+impl TeamCityCollector {
+    /// Gets the build types to collect, which are the
+    /// explicitly configured ones followed by the ones
+    /// found in the configured projects.
+    fn get_build_types(
+        &self,
+        listener: &WaitHandleListener,
+    ) -> DuckResult<Vec<TeamCityBuildTypeModel>> {
+        let mut result = Vec::<TeamCityBuildTypeModel>::new();
+
+        if !self.build_types.is_empty() {
+            // Get all known build types from TeamCity.
+            let known_build_types = self.client.get_build_types()?;
+            for build_type in self.build_types.iter() {
+                // Make sure the build type is known.
+                match known_build_types.iter().find(|t| t.id.eq(build_type)) {
+                    Option::None => {
+                        warn!(
+                            "The build type '{}' does not exist in TeamCity.",
+                            build_type
+                        );
+                    }
+                    Option::Some(r) => result.push(r.clone()),
+                };
+            }
+        }
+
+        for project in self.projects.iter() {
+            if listener.check() {
+                break;
+            }
+
+            trace!("Getting build types for project {}...", project);
+            let build_types = self.client.get_project_build_types(project)?;
+            if build_types.is_empty() {
+                warn!(
+                    "The project '{}' does not exist in TeamCity or has no build types.",
+                    project
+                );
+            }
+            for build_type in build_types {
+                if !result.iter().any(|t| t.id == build_type.id) {
+                    result.push(build_type);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
 impl Collector for TeamCityCollector {
     fn info(&self) -> &CollectorInfo {
         &self.info
@@ -49,28 +108,13 @@ impl Collector for TeamCityCollector {
             return Err(format_err!("There was a problem contacting TeamCity."));
         }
 
-        // Get all known build types from TeamCity.
-        let known_build_types = self.client.get_build_types()?;
-
         // Get builds for all build types.
-        for build_type in self.build_types.iter() {
+        for found in self.get_build_types(&listener)?.iter() {
             if listener.check() {
                 return Ok(());
             }
 
-            // Make sure the build type is known.
-            let found = match known_build_types.iter().find(|t| t.id.eq(build_type)) {
-                Option::None => {
-                    warn!(
-                        "The build type '{}' does not exist in TeamCity.",
-                        build_type
-                    );
-                    continue;
-                }
-                Option::Some(r) => r,
-            };
-
-            trace!("Getting builds for {}...", build_type);
+            trace!("Getting builds for {}...", found.id);
             let result = self.client.get_builds(found)?;
             for branch in result.branches {
                 if listener.check() {
@@ -83,6 +127,11 @@ impl Collector for TeamCityCollector {
                     &branch.name
                 };
 
+                if !self.branches.is_match(branch_name) {
+                    trace!("Skipping excluded branch '{}'", branch_name);
+                    continue;
+                }
+
                 match branch.builds.builds.first() {
                     None => trace!("No builds found for branch '{}'", branch_name),
                     Some(build) => {
@@ -1,6 +1,7 @@
 use url::Url;
 
 use crate::config::{TeamCityAuth, TeamCityConfiguration, Validate};
+use crate::utils::branches::BranchMatcher;
 use crate::DuckResult;
 
 impl Validate for TeamCityConfiguration {
@@ -29,8 +30,37 @@ impl Validate for TeamCityConfiguration {
                     ));
                 }
             }
+            TeamCityAuth::Token(token) => {
+                if token.is_empty() {
+                    return Err(format_err!("[{}] TeamCity token cannot be empty", self.id));
+                }
+            }
         };
 
+        let projects = self.projects.as_deref().unwrap_or_default();
+        if self.builds.is_empty() && projects.is_empty() {
+            return Err(format_err!(
+                "[{}] TeamCity configuration have not specified any builds or projects",
+                self.id
+            ));
+        }
+        if projects.iter().any(|p| p.is_empty()) {
+            return Err(format_err!(
+                "[{}] TeamCity project ID cannot be empty",
+                self.id
+            ));
+        }
+        if let Err(e) = BranchMatcher::new(
+            self.branches.as_deref().unwrap_or_default(),
+            self.excluded_branches.as_deref().unwrap_or_default(),
+        ) {
+            return Err(format_err!(
+                "[{}] TeamCity branches are invalid. {}",
+                self.id,
+                e
+            ));
+        }
+
         Ok(())
     }
 }
@@ -150,4 +180,109 @@ mod tests {
 
         providers::create_collectors(&config).unwrap();
     }
+
+    #[test]
+    #[should_panic(expected = "[foo] TeamCity token cannot be empty")]
+    fn should_return_error_if_teamcity_token_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "teamcity": {
+                            "id": "foo",
+                            "serverUrl": "https://localhost:5000",
+                            "credentials": {
+                                "token": ""
+                            },
+                            "builds": [ "Foo" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "[foo] TeamCity configuration have not specified any builds or projects"
+    )]
+    fn should_return_error_if_teamcity_builds_and_projects_are_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "teamcity": {
+                            "id": "foo",
+                            "serverUrl": "https://localhost:5000",
+                            "credentials": "guest",
+                            "builds": [ ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] TeamCity project ID cannot be empty")]
+    fn should_return_error_if_teamcity_project_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "teamcity": {
+                            "id": "foo",
+                            "serverUrl": "https://localhost:5000",
+                            "credentials": "guest",
+                            "projects": [ "" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] TeamCity branches are invalid")]
+    fn should_return_error_if_teamcity_branch_pattern_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "teamcity": {
+                            "id": "foo",
+                            "serverUrl": "https://localhost:5000",
+                            "credentials": "guest",
+                            "builds": [ "Foo" ],
+                            "branches": [ "regex:release/(" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
 }
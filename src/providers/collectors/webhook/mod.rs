@@ -0,0 +1,112 @@
+use waithandle::WaitHandleListener;
+
+use crate::builds::Build;
+use crate::config::{WebhookConfiguration, WebhookCredentials};
+use crate::providers::collectors::{Collector, CollectorInfo, CollectorLoader};
+use crate::utils::signature;
+use crate::DuckResult;
+
+mod validation;
+
+impl CollectorLoader for WebhookConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Collector>> {
+        Ok(Box::new(WebhookCollector {
+            info: CollectorInfo {
+                id: self.id.clone(),
+                enabled: self.enabled.unwrap_or(true),
+                provider: "Webhook".to_owned(),
+            },
+        }))
+    }
+}
+
+/// A collector that builds are pushed to via the HTTP API.
+/// It exists so the engine knows about it, but is never polled.
+pub struct WebhookCollector {
+    info: CollectorInfo,
+}
+
+impl Collector for WebhookCollector {
+    fn info(&self) -> &CollectorInfo {
+        &self.info
+    }
+
+    fn collect(&self, _: WaitHandleListener, _: &mut dyn FnMut(Build)) -> DuckResult<()> {
+        Ok(())
+    }
+
+    fn is_polled(&self) -> bool {
+        false
+    }
+}
+
+impl WebhookCredentials {
+    /// Verifies that a request was sent by someone who knows the secret.
+    pub fn verify(
+        &self,
+        authorization: Option<&str>,
+        signature: Option<&str>,
+        body: &[u8],
+    ) -> bool {
+        match self {
+            WebhookCredentials::Secret(secret) => match authorization {
+                Some(authorization) => match authorization.strip_prefix("Bearer ") {
+                    Some(token) => constant_time_eq(token.trim().as_bytes(), secret.as_bytes()),
+                    None => false,
+                },
+                None => false,
+            },
+            WebhookCredentials::Hmac(secret) => match signature {
+                Some(value) => signature::verify(secret, body, value),
+                None => false,
+            },
+        }
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_not_be_polled() {
+        // Given
+        let collector = WebhookConfiguration {
+            id: "webhook".to_owned(),
+            enabled: None,
+            credentials: WebhookCredentials::Secret("SECRET".to_owned()),
+        }
+        .load()
+        .unwrap();
+        // When
+        let result = collector.is_polled();
+        // Then
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_verify_shared_secret() {
+        // Given
+        let credentials = WebhookCredentials::Secret("SECRET".to_owned());
+        // When, Then
+        assert!(credentials.verify(Some("Bearer SECRET"), None, b"{}"));
+        assert!(!credentials.verify(Some("Bearer HUNTER2"), None, b"{}"));
+        assert!(!credentials.verify(Some("SECRET"), None, b"{}"));
+        assert!(!credentials.verify(None, None, b"{}"));
+    }
+
+    #[test]
+    fn should_verify_hmac_signature() {
+        // Given
+        let credentials = WebhookCredentials::Hmac("SECRET".to_owned());
+        let signature = signature::sign("SECRET", b"{}");
+        // When, Then
+        assert!(credentials.verify(None, Some(&signature), b"{}"));
+        assert!(!credentials.verify(None, Some(&signature), b"{ }"));
+        assert!(!credentials.verify(Some("Bearer SECRET"), None, b"{}"));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::config::{Validate, WebhookConfiguration, WebhookCredentials};
+use crate::DuckResult;
+
+impl Validate for WebhookConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        match &self.credentials {
+            WebhookCredentials::Secret(secret) => {
+                if secret.is_empty() {
+                    return Err(format_err!("[{}] Webhook secret is empty", self.id));
+                }
+            }
+            WebhookCredentials::Hmac(secret) => {
+                if secret.is_empty() {
+                    return Err(format_err!("[{}] Webhook HMAC secret is empty", self.id));
+                }
+            }
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(expected = "[webhook] Webhook secret is empty")]
+    fn should_return_error_if_webhook_secret_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "webhook": {
+                            "id": "webhook",
+                            "credentials": {
+                                "secret": ""
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[webhook] Webhook HMAC secret is empty")]
+    fn should_return_error_if_webhook_hmac_secret_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [
+                    {
+                        "webhook": {
+                            "id": "webhook",
+                            "credentials": {
+                                "hmac": ""
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_collectors(&config).unwrap();
+    }
+}
@@ -4,9 +4,15 @@ use crate::builds::{Build, BuildStatus};
 use crate::filters::BuildFilter;
 use crate::DuckResult;
 
+mod discord;
+mod email;
+mod homeassistant;
 mod hue;
 mod mattermost;
+mod mqtt;
 mod slack;
+mod teams;
+mod webhook;
 
 pub trait ObserverLoader {
     fn load(&self) -> DuckResult<Box<dyn Observer>>;
@@ -0,0 +1,44 @@
+use crate::config::{DiscordConfiguration, DiscordCredentials};
+use crate::utils::http::{HttpClient, HttpRequestBuilder, HttpResponse};
+use crate::DuckResult;
+
+pub struct DiscordClient {
+    credentials: DiscordCredentials,
+}
+
+impl DiscordCredentials {
+    fn get_url(&self) -> &str {
+        match self {
+            DiscordCredentials::Webhook { url } => url,
+        }
+    }
+}
+
+impl DiscordClient {
+    pub fn new(config: &DiscordConfiguration) -> Self {
+        DiscordClient {
+            credentials: config.credentials.clone(),
+        }
+    }
+
+    pub fn send(&self, client: &impl HttpClient, embed: serde_json::Value) -> DuckResult<()> {
+        let payload = json!({ "embeds": [embed] });
+
+        let mut builder = HttpRequestBuilder::post(self.credentials.get_url().to_string());
+        builder.add_header("Content-Type", "application/json");
+        builder.add_header("Accept", "application/json");
+        builder.set_body(payload.to_string());
+
+        // Discord responds with 204 No Content unless
+        // the webhook URL was called with ?wait=true.
+        let response = client.send(&builder)?;
+        if !response.status().is_success() {
+            return Err(format_err!(
+                "Could not send Discord message. ({})",
+                response.status()
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+use log::info;
+
+use crate::builds::{Build, BuildStatus};
+use crate::config::DiscordConfiguration;
+use crate::filters::BuildFilter;
+use crate::providers::observers::{Observation, Observer, ObserverInfo, ObserverLoader};
+use crate::utils::date;
+use crate::utils::http::{HttpClient, ReqwestClient};
+use crate::DuckResult;
+
+use self::client::DiscordClient;
+
+mod client;
+mod validation;
+
+impl ObserverLoader for DiscordConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Observer>> {
+        Ok(Box::new(DiscordObserver::<ReqwestClient>::new(self)?))
+    }
+}
+
+pub struct DiscordObserver<T: HttpClient + Default> {
+    client: DiscordClient,
+    http: T,
+    info: ObserverInfo,
+}
+
+impl<T: HttpClient + Default> DiscordObserver<T> {
+    pub fn new(config: &DiscordConfiguration) -> DuckResult<Self> {
+        Ok(DiscordObserver {
+            client: DiscordClient::new(config),
+            http: Default::default(),
+            info: ObserverInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                filter: BuildFilter::new(config.filter.clone())?,
+                collectors: config
+                    .collectors
+                    .as_ref()
+                    .map(|collectors| collectors.iter().cloned().collect()),
+            },
+        })
+    }
+
+    #[cfg(test)]
+    pub fn get_client(&self) -> &T {
+        &self.http
+    }
+}
+
+impl<T: HttpClient + Default> Observer for DiscordObserver<T> {
+    fn info(&self) -> &ObserverInfo {
+        &self.info
+    }
+
+    fn observe(&self, observation: Observation) -> DuckResult<()> {
+        if let Observation::BuildStatusChanged(build) = observation {
+            if build.status != BuildStatus::Unknown {
+                info!(
+                    "Sending Discord message since build status changed ({})...",
+                    build.status
+                );
+                self.client.send(&self.http, create_embed(build))?;
+            }
+        };
+
+        Ok(())
+    }
+}
+
+fn create_embed(build: &Build) -> serde_json::Value {
+    let mut fields = vec![
+        json!({ "name": "Provider", "value": build.provider, "inline": true }),
+        json!({ "name": "Branch", "value": build.branch, "inline": true }),
+        json!({ "name": "Build", "value": build.build_number, "inline": true }),
+    ];
+    if let Some(finished_at) = build.finished_at {
+        fields.push(json!({
+            "name": "Duration",
+            "value": date::format_duration(finished_at - build.started_at),
+            "inline": true
+        }));
+    }
+
+    json!({
+        "title": format!("{}::{}", build.project_name, build.definition_name),
+        "url": build.url,
+        "description": format!("Build status changed to **{}**", build.status),
+        "color": get_color(&build.status),
+        "fields": fields
+    })
+}
+
+/// Discord embed colors are RGB values encoded as integers.
+fn get_color(status: &BuildStatus) -> u32 {
+    match status {
+        BuildStatus::Success => 0x2ECC71,
+        BuildStatus::Failed => 0xE74C3C,
+        BuildStatus::Canceled | BuildStatus::Blocked => 0xF39C12,
+        BuildStatus::Running => 0x3498DB,
+        _ => 0x95A5A6,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::{BuildBuilder, BuildStatus};
+    use crate::config::DiscordCredentials;
+    use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
+    use reqwest::StatusCode;
+    use test_case::test_case;
+
+    fn create_observer() -> DiscordObserver<MockHttpClient> {
+        let discord = DiscordObserver::<MockHttpClient>::new(&DiscordConfiguration {
+            id: "discord".to_string(),
+            enabled: Some(true),
+            collectors: None,
+            filter: None,
+            credentials: DiscordCredentials::Webhook {
+                url: "https://example.com/webhook".to_string(),
+            },
+        })
+        .unwrap();
+
+        discord.get_client().add_response(
+            MockHttpResponseBuilder::new(HttpMethod::Post, "https://example.com/webhook")
+                .returns_status(StatusCode::NO_CONTENT),
+        );
+
+        discord
+    }
+
+    fn observe(discord: &DiscordObserver<MockHttpClient>, build: &Build) -> serde_json::Value {
+        discord
+            .observe(Observation::BuildStatusChanged(build))
+            .unwrap();
+
+        let requests = discord.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(HttpMethod::Post, requests[0].method);
+        assert_eq!("https://example.com/webhook", &requests[0].url);
+        serde_json::from_str(requests[0].body.as_ref().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn should_post_embed_to_webhook_url() {
+        // Given
+        let discord = create_observer();
+
+        // When
+        let payload = observe(&discord, &BuildBuilder::dummy().unwrap());
+
+        // Then
+        let embed = &payload["embeds"][0];
+        assert_eq!("project_name::definition_name", embed["title"]);
+        assert_eq!("https://dummy", embed["url"]);
+        assert_eq!("Build status changed to **Success**", embed["description"]);
+    }
+
+    #[test]
+    fn should_include_build_fields() {
+        // Given
+        let discord = create_observer();
+
+        // When
+        let payload = observe(&discord, &BuildBuilder::dummy().unwrap());
+
+        // Then
+        assert_eq!(
+            json!([
+                { "name": "Provider", "value": "TeamCity", "inline": true },
+                { "name": "Branch", "value": "branch", "inline": true },
+                { "name": "Build", "value": "build_number", "inline": true },
+                { "name": "Duration", "value": "16m 40s", "inline": true }
+            ]),
+            payload["embeds"][0]["fields"]
+        );
+    }
+
+    #[test]
+    fn should_not_include_duration_for_running_build() {
+        // Given
+        let discord = create_observer();
+        let build = BuildBuilder::dummy()
+            .status(BuildStatus::Running)
+            .finished_at(None)
+            .unwrap();
+
+        // When
+        let payload = observe(&discord, &build);
+
+        // Then
+        let fields = &payload["embeds"][0]["fields"];
+        assert_eq!(3, fields.as_array().unwrap().len());
+    }
+
+    #[test_case(BuildStatus::Success, 0x2ECC71 ; "Success")]
+    #[test_case(BuildStatus::Failed, 0xE74C3C ; "Failed")]
+    #[test_case(BuildStatus::Canceled, 0xF39C12 ; "Canceled")]
+    #[test_case(BuildStatus::Running, 0x3498DB ; "Running")]
+    #[test_case(BuildStatus::Queued, 0x95A5A6 ; "Queued")]
+    fn should_color_embed_by_status(status: BuildStatus, expected: u32) {
+        // Given
+        let discord = create_observer();
+
+        // When
+        let payload = observe(&discord, &BuildBuilder::dummy().status(status).unwrap());
+
+        // Then
+        assert_eq!(expected, payload["embeds"][0]["color"]);
+    }
+
+    #[test]
+    fn should_not_send_message_for_unknown_status() {
+        // Given
+        let discord = create_observer();
+
+        // When
+        discord
+            .observe(Observation::BuildStatusChanged(
+                &BuildBuilder::dummy().status(BuildStatus::Unknown).unwrap(),
+            ))
+            .unwrap();
+
+        // Then
+        assert_eq!(0, discord.get_client().get_sent_requests().len());
+    }
+
+    #[test]
+    fn should_return_error_if_webhook_responds_with_failure() {
+        // Given
+        let discord = DiscordObserver::<MockHttpClient>::new(&DiscordConfiguration {
+            id: "discord".to_string(),
+            enabled: Some(true),
+            collectors: None,
+            filter: None,
+            credentials: DiscordCredentials::Webhook {
+                url: "https://example.com/webhook".to_string(),
+            },
+        })
+        .unwrap();
+        discord.get_client().add_response(
+            MockHttpResponseBuilder::new(HttpMethod::Post, "https://example.com/webhook")
+                .returns_status(StatusCode::BAD_REQUEST),
+        );
+
+        // When
+        let result = discord.observe(Observation::BuildStatusChanged(
+            &BuildBuilder::dummy().unwrap(),
+        ));
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,241 @@
+use std::io::{BufRead, BufReader, Write};
+use std::time::Duration;
+
+use crate::config::{EmailConfiguration, EmailCredentials};
+use crate::utils::net::Transport;
+use crate::DuckResult;
+
+static TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct EmailMessage {
+    pub subject: String,
+    pub body: String,
+}
+
+/// A minimal SMTP client that supports STARTTLS and AUTH PLAIN.
+pub struct SmtpClient {
+    host: String,
+    port: u16,
+    starttls: bool,
+    credentials: Option<EmailCredentials>,
+    from: String,
+    to: Vec<String>,
+}
+
+impl SmtpClient {
+    pub fn new(config: &EmailConfiguration) -> Self {
+        SmtpClient {
+            host: config.host.clone(),
+            port: config.get_port(),
+            starttls: config.starttls.unwrap_or(true),
+            credentials: config.credentials.clone(),
+            from: config.from.clone(),
+            to: config.to.clone(),
+        }
+    }
+
+    pub fn send(&self, message: &EmailMessage) -> DuckResult<()> {
+        let transport = Transport::connect(&self.host, self.port, TIMEOUT)?;
+
+        // Identify ourselves using an address literal
+        // since we don't know our own domain name.
+        let ehlo = format!("EHLO [{}]", transport.local_addr()?.ip());
+
+        let mut connection = Connection::new(transport);
+        connection.expect(220)?;
+        connection.command(&ehlo, 250)?;
+        if self.starttls {
+            connection.command("STARTTLS", 220)?;
+            connection = connection.upgrade(&self.host)?;
+            connection.command(&ehlo, 250)?;
+        }
+
+        if let Some(EmailCredentials::Basic { username, password }) = &self.credentials {
+            let token = base64::encode(format!("\0{}\0{}", username, password));
+            connection.command(&format!("AUTH PLAIN {}", token), 235)?;
+        }
+
+        connection.command(&format!("MAIL FROM:<{}>", self.from), 250)?;
+        for recipient in self.to.iter() {
+            connection.command(&format!("RCPT TO:<{}>", recipient), 250)?;
+        }
+        connection.command("DATA", 354)?;
+        connection.write(&format_message(
+            &self.from,
+            &self.to,
+            message,
+            &chrono::Utc::now().to_rfc2822(),
+        ))?;
+        connection.expect(250)?;
+
+        // The message has been accepted at this point,
+        // so we don't care if QUIT fails.
+        let _ = connection.command("QUIT", 221);
+
+        Ok(())
+    }
+}
+
+struct Connection {
+    reader: BufReader<Transport>,
+}
+
+impl Connection {
+    fn new(transport: Transport) -> Self {
+        Self {
+            reader: BufReader::new(transport),
+        }
+    }
+
+    fn upgrade(self, host: &str) -> DuckResult<Self> {
+        Ok(Connection::new(self.reader.into_inner().upgrade(host)?))
+    }
+
+    fn command(&mut self, command: &str, expected: u16) -> DuckResult<()> {
+        self.write(&format!("{}\r\n", command))?;
+        self.expect(expected)
+    }
+
+    fn write(&mut self, data: &str) -> DuckResult<()> {
+        let transport = self.reader.get_mut();
+        transport.write_all(data.as_bytes())?;
+        transport.flush()?;
+        Ok(())
+    }
+
+    /// Reads a (possibly multiline) reply and makes sure that
+    /// it belongs to the same class as the expected reply code.
+    fn expect(&mut self, expected: u16) -> DuckResult<()> {
+        let mut text = Vec::new();
+        loop {
+            let mut line = String::new();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Err(format_err!("SMTP server closed the connection"));
+            }
+
+            let line = line.trim_end();
+            let code = match line.get(..3).and_then(|code| code.parse::<u16>().ok()) {
+                Some(code) => code,
+                None => return Err(format_err!("Invalid SMTP reply '{}'", line)),
+            };
+            text.push(line.get(4..).unwrap_or_default().to_string());
+
+            if line.get(3..4) != Some("-") {
+                if code / 100 != expected / 100 {
+                    return Err(format_err!(
+                        "Unexpected SMTP reply ({} {})",
+                        code,
+                        text.join(" ")
+                    ));
+                }
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn format_message(from: &str, to: &[String], message: &EmailMessage, date: &str) -> String {
+    let mut result = String::new();
+    result.push_str(&format!("From: <{}>\r\n", from));
+    result.push_str(&format!(
+        "To: {}\r\n",
+        to.iter()
+            .map(|recipient| format!("<{}>", recipient))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    result.push_str(&format!("Subject: {}\r\n", encode_header(&message.subject)));
+    result.push_str(&format!("Date: {}\r\n", date));
+    result.push_str("MIME-Version: 1.0\r\n");
+    result.push_str("Content-Type: text/plain; charset=utf-8\r\n");
+    result.push_str("Content-Transfer-Encoding: 8bit\r\n");
+    result.push_str("\r\n");
+    for line in message.body.lines() {
+        // Lines starting with a dot must be escaped
+        // so they aren't mistaken for the end of data.
+        if line.starts_with('.') {
+            result.push('.');
+        }
+        result.push_str(line);
+        result.push_str("\r\n");
+    }
+    result.push_str(".\r\n");
+    result
+}
+
+/// Encodes non-ASCII header values as described in RFC 2047.
+/// Line breaks are replaced with spaces, since values such as build
+/// names come from remote systems and must not be able to add headers.
+fn encode_header(value: &str) -> String {
+    let value = value.replace(&['\r', '\n'][..], " ");
+    if value.is_ascii() {
+        value
+    } else {
+        format!("=?utf-8?B?{}?=", base64::encode(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_message() {
+        // Given
+        let message = EmailMessage {
+            subject: "Build Failed".to_string(),
+            body: "Line 1\n.Line 2".to_string(),
+        };
+
+        // When
+        let result = format_message(
+            "duck@example.com",
+            &["a@example.com".to_string(), "b@example.com".to_string()],
+            &message,
+            "Sat, 18 Jan 2020 12:00:00 +0000",
+        );
+
+        // Then
+        assert_eq!(
+            "From: <duck@example.com>\r\n\
+             To: <a@example.com>, <b@example.com>\r\n\
+             Subject: Build Failed\r\n\
+             Date: Sat, 18 Jan 2020 12:00:00 +0000\r\n\
+             MIME-Version: 1.0\r\n\
+             Content-Type: text/plain; charset=utf-8\r\n\
+             Content-Transfer-Encoding: 8bit\r\n\
+             \r\n\
+             Line 1\r\n\
+             ..Line 2\r\n\
+             .\r\n",
+            result
+        );
+    }
+
+    #[test]
+    fn should_not_encode_ascii_header() {
+        // Given, When
+        let result = encode_header("Build Failed");
+
+        // Then
+        assert_eq!("Build Failed", result);
+    }
+
+    #[test]
+    fn should_replace_line_breaks_in_header() {
+        // Given, When
+        let result = encode_header("Build Failed\r\nBcc: evil@example.com");
+
+        // Then
+        assert_eq!("Build Failed  Bcc: evil@example.com", result);
+    }
+
+    #[test]
+    fn should_encode_non_ascii_header() {
+        // Given, When
+        let result = encode_header("Bygge: Räksmörgås");
+
+        // Then
+        assert_eq!("=?utf-8?B?QnlnZ2U6IFLDpGtzbcO2cmfDpXM=?=", result);
+    }
+}
@@ -0,0 +1,404 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use log::{error, info};
+use waithandle::{WaitHandleListener, WaitHandleSignaler};
+
+use crate::builds::Build;
+use crate::config::EmailConfiguration;
+use crate::filters::BuildFilter;
+use crate::providers::observers::{Observation, Observer, ObserverInfo, ObserverLoader};
+use crate::utils::date;
+use crate::DuckResult;
+
+use self::client::{EmailMessage, SmtpClient};
+
+mod client;
+#[cfg(test)]
+mod sink;
+mod validation;
+
+impl ObserverLoader for EmailConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Observer>> {
+        Ok(Box::new(EmailObserver::new(self)?))
+    }
+}
+
+pub struct EmailObserver {
+    client: Arc<SmtpClient>,
+    info: ObserverInfo,
+    digest: Option<Arc<Mutex<Digest>>>,
+    signaler: WaitHandleSignaler,
+}
+
+impl EmailObserver {
+    pub fn new(config: &EmailConfiguration) -> DuckResult<Self> {
+        let (signaler, listener) = waithandle::new();
+        let observer = EmailObserver {
+            client: Arc::new(SmtpClient::new(config)),
+            info: ObserverInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                filter: BuildFilter::new(config.filter.clone())?,
+                collectors: config
+                    .collectors
+                    .as_ref()
+                    .map(|collectors| collectors.iter().cloned().collect()),
+            },
+            digest: config.digest.map(|minutes| {
+                Arc::new(Mutex::new(Digest::new(Duration::from_secs(
+                    u64::from(minutes) * 60,
+                ))))
+            }),
+            signaler,
+        };
+
+        if let Some(digest) = &observer.digest {
+            send_digest_when_due(observer.client.clone(), digest.clone(), listener);
+        }
+
+        Ok(observer)
+    }
+}
+
+impl Drop for EmailObserver {
+    fn drop(&mut self) {
+        // Tell the digest thread to send whatever it has and exit.
+        self.signaler.signal();
+    }
+}
+
+impl Observer for EmailObserver {
+    fn info(&self) -> &ObserverInfo {
+        &self.info
+    }
+
+    fn observe(&self, observation: Observation) -> DuckResult<()> {
+        match observation {
+            Observation::BuildStatusChanged(build) => match &self.digest {
+                Some(digest) => {
+                    digest.lock().unwrap().add(build, Instant::now());
+                }
+                None => {
+                    info!(
+                        "Sending email since build status changed ({})...",
+                        build.status
+                    );
+                    self.client
+                        .send(&create_message(std::slice::from_ref(build)))?;
+                }
+            },
+            Observation::ShuttingDown => {
+                // Don't lose any pending changes.
+                if let Some(digest) = &self.digest {
+                    let builds = digest.lock().unwrap().take_all();
+                    if !builds.is_empty() {
+                        info!("Sending email digest ({} changes)...", builds.len());
+                        self.client.send(&create_message(&builds))?;
+                    }
+                }
+            }
+            _ => {}
+        };
+
+        Ok(())
+    }
+}
+
+fn send_digest_when_due(
+    client: Arc<SmtpClient>,
+    digest: Arc<Mutex<Digest>>,
+    listener: WaitHandleListener,
+) {
+    std::thread::spawn(move || loop {
+        let stopped = listener.wait(Duration::from_secs(1));
+        let builds = {
+            let mut digest = digest.lock().unwrap();
+            if stopped {
+                digest.take_all()
+            } else {
+                digest.take_if_due(Instant::now())
+            }
+        };
+
+        if !builds.is_empty() {
+            info!("Sending email digest ({} changes)...", builds.len());
+            if let Err(e) = client.send(&create_message(&builds)) {
+                error!("Could not send email digest: {}", e);
+            }
+        }
+
+        if stopped {
+            break;
+        }
+    });
+}
+
+/// Collects build status changes until the window,
+/// which starts at the first change, has passed.
+struct Digest {
+    window: Duration,
+    started_at: Option<Instant>,
+    builds: Vec<Build>,
+}
+
+impl Digest {
+    fn new(window: Duration) -> Self {
+        Self {
+            window,
+            started_at: None,
+            builds: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, build: &Build, now: Instant) {
+        if self.started_at.is_none() {
+            self.started_at = Some(now);
+        }
+        self.builds.push(build.clone());
+    }
+
+    fn take_if_due(&mut self, now: Instant) -> Vec<Build> {
+        match self.started_at {
+            Some(started_at) if now.duration_since(started_at) >= self.window => self.take_all(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn take_all(&mut self) -> Vec<Build> {
+        self.started_at = None;
+        std::mem::take(&mut self.builds)
+    }
+}
+
+fn create_message(builds: &[Build]) -> EmailMessage {
+    let subject = match builds {
+        [build] => format!(
+            "Build {}: {}::{} ({})",
+            build.status, build.project_name, build.definition_name, build.branch
+        ),
+        _ => format!("{} build status changes", builds.len()),
+    };
+
+    EmailMessage {
+        subject,
+        body: builds
+            .iter()
+            .map(format_build)
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    }
+}
+
+fn format_build(build: &Build) -> String {
+    let mut lines = vec![
+        format!("{}::{}", build.project_name, build.definition_name),
+        format!("Status:   {}", build.status),
+        format!("Branch:   {}", build.branch),
+        format!("Build:    {}", build.build_number),
+        format!("Provider: {}", build.provider),
+    ];
+    if let Some(finished_at) = build.finished_at {
+        lines.push(format!(
+            "Duration: {}",
+            date::format_duration(finished_at - build.started_at)
+        ));
+    }
+    lines.push(build.url.clone());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sink::SmtpSink;
+    use super::*;
+    use crate::builds::{BuildBuilder, BuildStatus};
+    use crate::config::EmailCredentials;
+
+    static TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn create_observer(sink: &SmtpSink, digest: Option<u32>) -> EmailObserver {
+        EmailObserver::new(&EmailConfiguration {
+            id: "email".to_string(),
+            enabled: Some(true),
+            collectors: None,
+            host: "127.0.0.1".to_string(),
+            port: Some(sink.port()),
+            starttls: Some(false),
+            credentials: Some(EmailCredentials::Basic {
+                username: "duck".to_string(),
+                password: "hunter2".to_string(),
+            }),
+            from: "duck@example.com".to_string(),
+            to: vec!["a@example.com".to_string(), "b@example.com".to_string()],
+            digest,
+            filter: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn should_send_email_when_build_status_changes() {
+        // Given
+        let sink = SmtpSink::start();
+        let email = create_observer(&sink, None);
+        let build = BuildBuilder::dummy().status(BuildStatus::Failed).unwrap();
+
+        // When
+        email
+            .observe(Observation::BuildStatusChanged(&build))
+            .unwrap();
+
+        // Then
+        let mails = sink.get_mails(1, TIMEOUT);
+        assert_eq!(1, mails.len());
+        assert_eq!(
+            vec![
+                "EHLO [127.0.0.1]",
+                "AUTH PLAIN AGR1Y2sAaHVudGVyMg==",
+                "MAIL FROM:<duck@example.com>",
+                "RCPT TO:<a@example.com>",
+                "RCPT TO:<b@example.com>",
+                "DATA"
+            ],
+            mails[0].commands
+        );
+        assert!(mails[0]
+            .data
+            .contains("Subject: Build Failed: project_name::definition_name (branch)\r\n"));
+        assert!(mails[0].data.contains(
+            "\r\n\r\nproject_name::definition_name\r\n\
+             Status:   Failed\r\n\
+             Branch:   branch\r\n\
+             Build:    build_number\r\n\
+             Provider: TeamCity\r\n\
+             Duration: 16m 40s\r\n\
+             https://dummy\r\n"
+        ));
+    }
+
+    #[test]
+    fn should_not_allow_build_names_to_add_headers() {
+        // Given
+        let sink = SmtpSink::start();
+        let email = create_observer(&sink, None);
+        let build = BuildBuilder::dummy()
+            .definition_name("definition\r\nBcc: evil@example.com")
+            .unwrap();
+
+        // When
+        email
+            .observe(Observation::BuildStatusChanged(&build))
+            .unwrap();
+
+        // Then
+        let mails = sink.get_mails(1, TIMEOUT);
+        assert_eq!(1, mails.len());
+        assert!(mails[0].data.contains(
+            "Subject: Build Success: project_name::definition  Bcc: evil@example.com (branch)\r\n"
+        ));
+        let headers = mails[0].data.split("\r\n\r\n").next().unwrap();
+        assert!(!headers.contains("\r\nBcc:"));
+    }
+
+    #[test]
+    fn should_not_send_email_when_build_is_updated() {
+        // Given
+        let sink = SmtpSink::start();
+        let email = create_observer(&sink, None);
+
+        // When
+        email
+            .observe(Observation::BuildUpdated(&BuildBuilder::dummy().unwrap()))
+            .unwrap();
+
+        // Then
+        assert_eq!(0, sink.get_mails(1, Duration::from_millis(200)).len());
+    }
+
+    #[test]
+    fn should_batch_changes_in_digest_mode() {
+        // Given
+        let sink = SmtpSink::start();
+        let email = create_observer(&sink, Some(10));
+        let first = BuildBuilder::dummy().status(BuildStatus::Failed).unwrap();
+        let second = BuildBuilder::dummy().definition_name("other").unwrap();
+
+        // When
+        email
+            .observe(Observation::BuildStatusChanged(&first))
+            .unwrap();
+        email
+            .observe(Observation::BuildStatusChanged(&second))
+            .unwrap();
+        let before_shutdown = sink.get_mails(1, Duration::from_millis(200)).len();
+        email.observe(Observation::ShuttingDown).unwrap();
+
+        // Then
+        let mails = sink.get_mails(1, TIMEOUT);
+        assert_eq!(0, before_shutdown);
+        assert_eq!(1, mails.len());
+        assert!(mails[0]
+            .data
+            .contains("Subject: 2 build status changes\r\n"));
+        assert!(mails[0].data.contains("Status:   Failed\r\n"));
+        assert!(mails[0]
+            .data
+            .contains("project_name::other\r\nStatus:   Success\r\n"));
+    }
+
+    #[test]
+    fn should_send_pending_digest_when_dropped() {
+        // Given
+        let sink = SmtpSink::start();
+        let email = create_observer(&sink, Some(10));
+        email
+            .observe(Observation::BuildStatusChanged(
+                &BuildBuilder::dummy().unwrap(),
+            ))
+            .unwrap();
+
+        // When
+        drop(email);
+
+        // Then
+        let mails = sink.get_mails(1, TIMEOUT);
+        assert_eq!(1, mails.len());
+        assert!(mails[0]
+            .data
+            .contains("Subject: Build Success: project_name::definition_name (branch)\r\n"));
+    }
+
+    #[test]
+    fn should_not_take_digest_before_window_has_passed() {
+        // Given
+        let now = Instant::now();
+        let mut digest = Digest::new(Duration::from_secs(60));
+        digest.add(&BuildBuilder::dummy().unwrap(), now);
+
+        // When
+        let builds = digest.take_if_due(now + Duration::from_secs(59));
+
+        // Then
+        assert_eq!(0, builds.len());
+    }
+
+    #[test]
+    fn should_take_digest_when_window_has_passed() {
+        // Given
+        let now = Instant::now();
+        let mut digest = Digest::new(Duration::from_secs(60));
+        digest.add(&BuildBuilder::dummy().unwrap(), now);
+        digest.add(
+            &BuildBuilder::dummy().unwrap(),
+            now + Duration::from_secs(30),
+        );
+
+        // When
+        let builds = digest.take_if_due(now + Duration::from_secs(60));
+
+        // Then
+        assert_eq!(2, builds.len());
+        assert_eq!(0, digest.take_if_due(now + Duration::from_secs(120)).len());
+    }
+}
@@ -0,0 +1,95 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A local SMTP server that accepts everything
+/// and keeps received mails in memory.
+pub struct SmtpSink {
+    port: u16,
+    mails: Arc<Mutex<Vec<ReceivedMail>>>,
+}
+
+#[derive(Clone)]
+pub struct ReceivedMail {
+    pub commands: Vec<String>,
+    pub data: String,
+}
+
+impl SmtpSink {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let mails = Arc::new(Mutex::new(Vec::new()));
+
+        let received = mails.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle(stream, &received);
+            }
+        });
+
+        Self { port, mails }
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Waits until the expected number of mails have been received,
+    /// or until the timeout expires, and returns the received mails.
+    pub fn get_mails(&self, expected: usize, timeout: Duration) -> Vec<ReceivedMail> {
+        let started = Instant::now();
+        loop {
+            let mails = self.mails.lock().unwrap().clone();
+            if mails.len() >= expected || started.elapsed() >= timeout {
+                return mails;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
+
+fn handle(stream: TcpStream, mails: &Arc<Mutex<Vec<ReceivedMail>>>) {
+    let mut writer = stream.try_clone().unwrap();
+    let mut reader = BufReader::new(stream);
+    let mut reply = |text: &str| writer.write_all(format!("{}\r\n", text).as_bytes());
+
+    let mut commands = Vec::new();
+    reply("220 localhost ESMTP").unwrap();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+            return;
+        }
+        let command = line.trim_end().to_string();
+        commands.push(command.clone());
+
+        let verb = command.split(' ').next().unwrap_or_default().to_uppercase();
+        match &verb[..] {
+            "EHLO" => reply("250-localhost\r\n250 AUTH PLAIN").unwrap(),
+            "AUTH" => reply("235 Authentication successful").unwrap(),
+            "DATA" => {
+                reply("354 End data with <CR><LF>.<CR><LF>").unwrap();
+                let mut data = String::new();
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == ".\r\n" {
+                        break;
+                    }
+                    data.push_str(&line);
+                }
+                mails.lock().unwrap().push(ReceivedMail {
+                    commands: commands.clone(),
+                    data,
+                });
+                reply("250 OK").unwrap();
+            }
+            "QUIT" => {
+                reply("221 Bye").unwrap();
+                return;
+            }
+            _ => reply("250 OK").unwrap(),
+        }
+    }
+}
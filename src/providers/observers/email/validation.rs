@@ -0,0 +1,258 @@
+use crate::config::{EmailConfiguration, EmailCredentials, Validate};
+use crate::DuckResult;
+
+impl Validate for EmailConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        if self.host.is_empty() {
+            return Err(format_err!("[{}] SMTP host is empty", self.id));
+        }
+        if let Some(EmailCredentials::Basic { username, .. }) = &self.credentials {
+            if username.is_empty() {
+                return Err(format_err!("[{}] SMTP username is empty", self.id));
+            }
+            // Never send credentials over an unencrypted connection.
+            if !self.starttls.unwrap_or(true) {
+                return Err(format_err!(
+                    "[{}] SMTP credentials require STARTTLS",
+                    self.id
+                ));
+            }
+        }
+        if !is_valid_address(&self.from) {
+            return Err(format_err!(
+                "[{}] Email sender '{}' is invalid",
+                self.id,
+                self.from
+            ));
+        }
+        if self.to.is_empty() {
+            return Err(format_err!(
+                "[{}] Email observer has not specified any recipients",
+                self.id
+            ));
+        }
+        for recipient in self.to.iter() {
+            if !is_valid_address(recipient) {
+                return Err(format_err!(
+                    "[{}] Email recipient '{}' is invalid",
+                    self.id,
+                    recipient
+                ));
+            }
+        }
+        if let Some(0) = self.digest {
+            return Err(format_err!(
+                "[{}] Email digest window must be greater than zero",
+                self.id
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// We only make sure that the address can be
+/// used in an SMTP command without breaking it.
+fn is_valid_address(address: &str) -> bool {
+    address.contains('@')
+        && !address
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || c == '<' || c == '>')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(expected = "[foo] SMTP host is empty")]
+    fn should_return_error_if_host_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "email": {
+                            "id": "foo",
+                            "host": "",
+                            "from": "duck@example.com",
+                            "to": [ "a@example.com" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] SMTP username is empty")]
+    fn should_return_error_if_username_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "email": {
+                            "id": "foo",
+                            "host": "smtp.example.com",
+                            "credentials": {
+                                "basic": { "username": "", "password": "hunter2" }
+                            },
+                            "from": "duck@example.com",
+                            "to": [ "a@example.com" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] SMTP credentials require STARTTLS")]
+    fn should_return_error_if_credentials_are_used_without_starttls() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "email": {
+                            "id": "foo",
+                            "host": "smtp.example.com",
+                            "starttls": false,
+                            "credentials": {
+                                "basic": { "username": "duck", "password": "hunter2" }
+                            },
+                            "from": "duck@example.com",
+                            "to": [ "a@example.com" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] Email sender 'duck' is invalid")]
+    fn should_return_error_if_sender_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "email": {
+                            "id": "foo",
+                            "host": "smtp.example.com",
+                            "from": "duck",
+                            "to": [ "a@example.com" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] Email observer has not specified any recipients")]
+    fn should_return_error_if_no_recipients_have_been_specified() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "email": {
+                            "id": "foo",
+                            "host": "smtp.example.com",
+                            "from": "duck@example.com",
+                            "to": [ ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] Email recipient 'a@example.com>' is invalid")]
+    fn should_return_error_if_recipient_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "email": {
+                            "id": "foo",
+                            "host": "smtp.example.com",
+                            "from": "duck@example.com",
+                            "to": [ "a@example.com>" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] Email digest window must be greater than zero")]
+    fn should_return_error_if_digest_window_is_zero() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "email": {
+                            "id": "foo",
+                            "host": "smtp.example.com",
+                            "from": "duck@example.com",
+                            "to": [ "a@example.com" ],
+                            "digest": 0
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+}
@@ -0,0 +1,106 @@
+use crate::builds::BuildStatus;
+use crate::config::HomeAssistantConfiguration;
+use crate::utils::http::{HttpClient, HttpRequestBuilder, HttpResponse};
+use crate::DuckResult;
+
+pub struct HomeAssistantClient {
+    url: String,
+    token: String,
+    lights: Vec<String>,
+    brightness: u8,
+}
+
+impl HomeAssistantClient {
+    pub fn new(config: &HomeAssistantConfiguration) -> Self {
+        HomeAssistantClient {
+            url: config.server_url.trim_end_matches('/').to_string(),
+            token: config.token.clone(),
+            lights: config.lights.clone().unwrap_or_default(),
+            brightness: config.brightness.unwrap_or(255),
+        }
+    }
+
+    pub fn has_lights(&self) -> bool {
+        !self.lights.is_empty()
+    }
+
+    pub fn set_color(&self, client: &impl HttpClient, color: [u8; 3]) -> DuckResult<()> {
+        self.call_service(
+            client,
+            "light/turn_on",
+            json!({
+                "entity_id": self.lights,
+                "rgb_color": color,
+                "brightness": self.brightness
+            }),
+        )
+    }
+
+    pub fn turn_off(&self, client: &impl HttpClient) -> DuckResult<()> {
+        self.call_service(
+            client,
+            "light/turn_off",
+            json!({
+                "entity_id": self.lights
+            }),
+        )
+    }
+
+    pub fn activate_scene(&self, client: &impl HttpClient, scene: &str) -> DuckResult<()> {
+        self.call_service(
+            client,
+            "scene/turn_on",
+            json!({
+                "entity_id": scene
+            }),
+        )
+    }
+
+    pub fn fire_event(
+        &self,
+        client: &impl HttpClient,
+        event: &str,
+        status: &BuildStatus,
+    ) -> DuckResult<()> {
+        self.post(
+            client,
+            &format!("events/{}", event),
+            json!({
+                "status": status
+            }),
+        )
+    }
+
+    fn call_service(
+        &self,
+        client: &impl HttpClient,
+        service: &str,
+        body: serde_json::Value,
+    ) -> DuckResult<()> {
+        self.post(client, &format!("services/{}", service), body)
+    }
+
+    fn post(
+        &self,
+        client: &impl HttpClient,
+        path: &str,
+        body: serde_json::Value,
+    ) -> DuckResult<()> {
+        let mut builder = HttpRequestBuilder::post(format!("{}/api/{}", self.url, path));
+        builder.add_header("Content-Type", "application/json");
+        builder.add_header("Accept", "application/json");
+        builder.bearer(&self.token);
+        builder.set_body(body.to_string());
+
+        let response = client.send(&builder)?;
+        if !response.status().is_success() {
+            return Err(format_err!(
+                "Could not call Home Assistant API at /api/{} ({})",
+                path,
+                response.status()
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,378 @@
+use log::debug;
+
+use crate::builds::BuildStatus;
+use crate::config::{HomeAssistantAction, HomeAssistantActions, HomeAssistantConfiguration};
+use crate::filters::BuildFilter;
+use crate::providers::observers::{Observation, Observer, ObserverInfo, ObserverLoader};
+use crate::utils::http::{HttpClient, ReqwestClient};
+use crate::DuckResult;
+
+use self::client::HomeAssistantClient;
+
+mod client;
+mod validation;
+
+impl ObserverLoader for HomeAssistantConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Observer>> {
+        Ok(Box::new(HomeAssistantObserver::<ReqwestClient>::new(self)?))
+    }
+}
+
+pub struct HomeAssistantObserver<T: HttpClient + Default> {
+    client: HomeAssistantClient,
+    http: T,
+    info: ObserverInfo,
+    actions: HomeAssistantActions,
+}
+
+impl<T: HttpClient + Default> HomeAssistantObserver<T> {
+    pub fn new(config: &HomeAssistantConfiguration) -> DuckResult<Self> {
+        Ok(HomeAssistantObserver {
+            client: HomeAssistantClient::new(config),
+            http: Default::default(),
+            info: ObserverInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                filter: BuildFilter::new(config.filter.clone())?,
+                collectors: config
+                    .collectors
+                    .as_ref()
+                    .map(|collectors| collectors.iter().cloned().collect()),
+            },
+            actions: config.get_actions(),
+        })
+    }
+
+    #[cfg(test)]
+    pub fn get_client(&self) -> &T {
+        &self.http
+    }
+}
+
+impl<T: HttpClient + Default> Observer for HomeAssistantObserver<T> {
+    fn info(&self) -> &ObserverInfo {
+        &self.info
+    }
+
+    fn observe(&self, observation: Observation) -> DuckResult<()> {
+        match observation {
+            Observation::DuckStatusChanged(status) => {
+                if let Some(action) = self.actions.get(&status) {
+                    debug!("[{}] Running action for '{}'...", self.info.id, status);
+                    match action {
+                        HomeAssistantAction::Color(color) => {
+                            self.client.set_color(&self.http, *color)?
+                        }
+                        HomeAssistantAction::Off => self.client.turn_off(&self.http)?,
+                        HomeAssistantAction::Scene(scene) => {
+                            self.client.activate_scene(&self.http, scene)?
+                        }
+                        HomeAssistantAction::Event(event) => {
+                            self.client.fire_event(&self.http, event, &status)?
+                        }
+                    }
+                }
+            }
+            Observation::ShuttingDown if self.client.has_lights() => {
+                debug!("[{}] Turning off all lights...", self.info.id);
+                self.client.turn_off(&self.http)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl HomeAssistantConfiguration {
+    /// Gets the configured actions, or the default
+    /// light colors if no actions have been configured.
+    pub fn get_actions(&self) -> HomeAssistantActions {
+        match &self.actions {
+            Some(actions) => actions.clone(),
+            None => HomeAssistantActions {
+                success: Some(HomeAssistantAction::Color([0, 255, 0])),
+                failed: Some(HomeAssistantAction::Color([255, 0, 0])),
+                running: Some(HomeAssistantAction::Color([127, 200, 255])),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl HomeAssistantActions {
+    pub fn get(&self, status: &BuildStatus) -> Option<&HomeAssistantAction> {
+        match status {
+            BuildStatus::Success => self.success.as_ref(),
+            BuildStatus::Failed => self.failed.as_ref(),
+            BuildStatus::Running => self.running.as_ref(),
+            BuildStatus::Canceled => self.canceled.as_ref(),
+            BuildStatus::Queued => self.queued.as_ref(),
+            BuildStatus::Blocked => self.blocked.as_ref(),
+            BuildStatus::Skipped => self.skipped.as_ref(),
+            BuildStatus::Unknown => self.unknown.as_ref(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &HomeAssistantAction> {
+        vec![
+            &self.success,
+            &self.failed,
+            &self.running,
+            &self.canceled,
+            &self.queued,
+            &self.blocked,
+            &self.skipped,
+            &self.unknown,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
+    use reqwest::StatusCode;
+    use test_case::test_case;
+
+    fn create_observer(
+        configure: impl FnOnce(&mut HomeAssistantConfiguration),
+    ) -> HomeAssistantObserver<MockHttpClient> {
+        let mut config = HomeAssistantConfiguration {
+            id: "homeassistant".to_string(),
+            enabled: Some(true),
+            collectors: None,
+            server_url: "http://homeassistant.local:8123/".to_string(),
+            token: "SECRET".to_string(),
+            lights: Some(vec![
+                "light.office".to_string(),
+                "light.hallway".to_string(),
+            ]),
+            brightness: None,
+            actions: None,
+            filter: None,
+        };
+        configure(&mut config);
+        HomeAssistantObserver::<MockHttpClient>::new(&config).unwrap()
+    }
+
+    fn add_response(observer: &HomeAssistantObserver<MockHttpClient>, url: &str) {
+        observer.get_client().add_response(
+            MockHttpResponseBuilder::new(HttpMethod::Post, url).returns_status(StatusCode::OK),
+        );
+    }
+
+    #[test_case(BuildStatus::Success, "[0,255,0]" ; "Success")]
+    #[test_case(BuildStatus::Failed, "[255,0,0]" ; "Failed")]
+    #[test_case(BuildStatus::Running, "[127,200,255]" ; "Running")]
+    fn should_set_default_light_color(status: BuildStatus, expected: &str) {
+        // Given
+        let homeassistant = create_observer(|_| {});
+        add_response(
+            &homeassistant,
+            "http://homeassistant.local:8123/api/services/light/turn_on",
+        );
+
+        // When
+        homeassistant
+            .observe(Observation::DuckStatusChanged(status))
+            .unwrap();
+
+        // Then
+        let requests = homeassistant.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(HttpMethod::Post, requests[0].method);
+        assert_eq!(
+            format!(
+                "{{\"brightness\":255,\"entity_id\":[\"light.office\",\"light.hallway\"],\"rgb_color\":{}}}",
+                expected
+            ),
+            requests[0].body.clone().unwrap()
+        );
+        assert_eq!(
+            "Bearer SECRET",
+            requests[0].headers.get("Authorization").unwrap()
+        );
+    }
+
+    #[test]
+    fn should_not_do_anything_for_status_without_default_action() {
+        // Given
+        let homeassistant = create_observer(|_| {});
+
+        // When
+        homeassistant
+            .observe(Observation::DuckStatusChanged(BuildStatus::Queued))
+            .unwrap();
+
+        // Then
+        assert_eq!(0, homeassistant.get_client().get_sent_requests().len());
+    }
+
+    #[test]
+    fn should_use_configured_color_and_brightness() {
+        // Given
+        let homeassistant = create_observer(|config| {
+            config.brightness = Some(128);
+            config.actions = Some(HomeAssistantActions {
+                canceled: Some(HomeAssistantAction::Color([255, 165, 0])),
+                ..Default::default()
+            });
+        });
+        add_response(
+            &homeassistant,
+            "http://homeassistant.local:8123/api/services/light/turn_on",
+        );
+
+        // When
+        homeassistant
+            .observe(Observation::DuckStatusChanged(BuildStatus::Canceled))
+            .unwrap();
+
+        // Then
+        let requests = homeassistant.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(
+            "{\"brightness\":128,\"entity_id\":[\"light.office\",\"light.hallway\"],\"rgb_color\":[255,165,0]}",
+            requests[0].body.clone().unwrap()
+        );
+    }
+
+    #[test]
+    fn should_only_use_configured_actions() {
+        // Given
+        let homeassistant = create_observer(|config| {
+            config.actions = Some(HomeAssistantActions {
+                failed: Some(HomeAssistantAction::Off),
+                ..Default::default()
+            });
+        });
+
+        // When
+        homeassistant
+            .observe(Observation::DuckStatusChanged(BuildStatus::Success))
+            .unwrap();
+
+        // Then
+        assert_eq!(0, homeassistant.get_client().get_sent_requests().len());
+    }
+
+    #[test]
+    fn should_activate_scene() {
+        // Given
+        let homeassistant = create_observer(|config| {
+            config.actions = Some(HomeAssistantActions {
+                failed: Some(HomeAssistantAction::Scene("scene.red_alert".to_string())),
+                ..Default::default()
+            });
+        });
+        add_response(
+            &homeassistant,
+            "http://homeassistant.local:8123/api/services/scene/turn_on",
+        );
+
+        // When
+        homeassistant
+            .observe(Observation::DuckStatusChanged(BuildStatus::Failed))
+            .unwrap();
+
+        // Then
+        let requests = homeassistant.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(
+            "http://homeassistant.local:8123/api/services/scene/turn_on",
+            requests[0].url
+        );
+        assert_eq!(
+            "{\"entity_id\":\"scene.red_alert\"}",
+            requests[0].body.clone().unwrap()
+        );
+    }
+
+    #[test]
+    fn should_fire_event() {
+        // Given
+        let homeassistant = create_observer(|config| {
+            config.lights = None;
+            config.actions = Some(HomeAssistantActions {
+                failed: Some(HomeAssistantAction::Event("duck_build_failed".to_string())),
+                ..Default::default()
+            });
+        });
+        add_response(
+            &homeassistant,
+            "http://homeassistant.local:8123/api/events/duck_build_failed",
+        );
+
+        // When
+        homeassistant
+            .observe(Observation::DuckStatusChanged(BuildStatus::Failed))
+            .unwrap();
+
+        // Then
+        let requests = homeassistant.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(
+            "http://homeassistant.local:8123/api/events/duck_build_failed",
+            requests[0].url
+        );
+        assert_eq!("{\"status\":\"Failed\"}", requests[0].body.clone().unwrap());
+    }
+
+    #[test]
+    fn should_turn_off_lights_when_shutting_down() {
+        // Given
+        let homeassistant = create_observer(|_| {});
+        add_response(
+            &homeassistant,
+            "http://homeassistant.local:8123/api/services/light/turn_off",
+        );
+
+        // When
+        homeassistant.observe(Observation::ShuttingDown).unwrap();
+
+        // Then
+        let requests = homeassistant.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(
+            "{\"entity_id\":[\"light.office\",\"light.hallway\"]}",
+            requests[0].body.clone().unwrap()
+        );
+    }
+
+    #[test]
+    fn should_not_turn_off_anything_when_shutting_down_without_lights() {
+        // Given
+        let homeassistant = create_observer(|config| {
+            config.lights = None;
+        });
+
+        // When
+        homeassistant.observe(Observation::ShuttingDown).unwrap();
+
+        // Then
+        assert_eq!(0, homeassistant.get_client().get_sent_requests().len());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Could not call Home Assistant API at /api/services/light/turn_on (401 Unauthorized)"
+    )]
+    fn should_return_error_if_server_return_non_successful_http_status_code() {
+        // Given
+        let homeassistant = create_observer(|_| {});
+        homeassistant.get_client().add_response(
+            MockHttpResponseBuilder::new(
+                HttpMethod::Post,
+                "http://homeassistant.local:8123/api/services/light/turn_on",
+            )
+            .returns_status(StatusCode::UNAUTHORIZED),
+        );
+
+        // When, Then
+        homeassistant
+            .observe(Observation::DuckStatusChanged(BuildStatus::Success))
+            .unwrap();
+    }
+}
@@ -0,0 +1,262 @@
+use url::Url;
+
+use crate::config::{HomeAssistantAction, HomeAssistantConfiguration, Validate};
+use crate::DuckResult;
+
+impl Validate for HomeAssistantConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        if let Err(e) = Url::parse(&self.server_url[..]) {
+            return Err(format_err!(
+                "[{}] Home Assistant server URL is invalid: {}",
+                self.id,
+                e
+            ));
+        }
+        if self.token.is_empty() {
+            return Err(format_err!("[{}] Home Assistant token is empty", self.id));
+        }
+        if let Some(lights) = &self.lights {
+            for light in lights {
+                if !light.starts_with("light.") {
+                    return Err(format_err!(
+                        "[{}] Home Assistant light entity ID is invalid: {}",
+                        self.id,
+                        light
+                    ));
+                }
+            }
+        }
+
+        let lights = self.lights.as_ref().map_or(0, |lights| lights.len());
+        for action in self.get_actions().iter() {
+            match action {
+                HomeAssistantAction::Color(_) | HomeAssistantAction::Off => {
+                    if lights == 0 {
+                        return Err(format_err!(
+                            "[{}] Home Assistant observer has no lights to control",
+                            self.id
+                        ));
+                    }
+                }
+                HomeAssistantAction::Scene(scene) => {
+                    if !scene.starts_with("scene.") {
+                        return Err(format_err!(
+                            "[{}] Home Assistant scene entity ID is invalid: {}",
+                            self.id,
+                            scene
+                        ));
+                    }
+                }
+                HomeAssistantAction::Event(event) => {
+                    if event.is_empty() {
+                        return Err(format_err!(
+                            "[{}] Home Assistant event type is empty",
+                            self.id
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Configuration;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(
+        expected = "[bar] Home Assistant server URL is invalid: relative URL without a base"
+    )]
+    fn should_return_error_if_home_assistant_server_url_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "homeassistant": {
+                            "id": "bar",
+                            "serverUrl": "",
+                            "token": "SECRET",
+                            "lights": [ "light.office" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[bar] Home Assistant token is empty")]
+    fn should_return_error_if_home_assistant_token_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "homeassistant": {
+                            "id": "bar",
+                            "serverUrl": "http://homeassistant.local:8123",
+                            "token": "",
+                            "lights": [ "light.office" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[bar] Home Assistant light entity ID is invalid: switch.office")]
+    fn should_return_error_if_home_assistant_light_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "homeassistant": {
+                            "id": "bar",
+                            "serverUrl": "http://homeassistant.local:8123",
+                            "token": "SECRET",
+                            "lights": [ "switch.office" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[bar] Home Assistant observer has no lights to control")]
+    fn should_return_error_if_home_assistant_default_actions_have_no_lights() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "homeassistant": {
+                            "id": "bar",
+                            "serverUrl": "http://homeassistant.local:8123",
+                            "token": "SECRET"
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[bar] Home Assistant observer has no lights to control")]
+    fn should_return_error_if_home_assistant_color_action_has_no_lights() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "homeassistant": {
+                            "id": "bar",
+                            "serverUrl": "http://homeassistant.local:8123",
+                            "token": "SECRET",
+                            "lights": [ ],
+                            "actions": {
+                                "failed": { "color": [ 255, 0, 0 ] }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[bar] Home Assistant scene entity ID is invalid: red_alert")]
+    fn should_return_error_if_home_assistant_scene_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "homeassistant": {
+                            "id": "bar",
+                            "serverUrl": "http://homeassistant.local:8123",
+                            "token": "SECRET",
+                            "actions": {
+                                "failed": { "scene": "red_alert" }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[bar] Home Assistant event type is empty")]
+    fn should_return_error_if_home_assistant_event_type_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "homeassistant": {
+                            "id": "bar",
+                            "serverUrl": "http://homeassistant.local:8123",
+                            "token": "SECRET",
+                            "actions": {
+                                "success": "off",
+                                "failed": { "event": "" }
+                            },
+                            "lights": [ "light.office" ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+}
@@ -0,0 +1,118 @@
+use std::io::Write;
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::client::*;
+
+/// A local MQTT broker that keeps published messages in memory.
+pub struct MqttBroker {
+    port: u16,
+    messages: Arc<Mutex<Vec<PublishedMessage>>>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PublishedMessage {
+    pub client_id: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub topic: String,
+    pub payload: String,
+    pub qos: u8,
+    pub retain: bool,
+}
+
+impl MqttBroker {
+    pub fn start() -> Self {
+        MqttBroker::start_with_return_code(0)
+    }
+
+    /// Starts a broker that answers CONNECT with the specified return code.
+    pub fn start_with_return_code(code: u8) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let messages = Arc::new(Mutex::new(Vec::new()));
+
+        let published = messages.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let _ = handle(stream, code, &published);
+            }
+        });
+
+        Self { port, messages }
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Waits until the expected number of messages have been published,
+    /// or until the timeout expires, and returns the published messages.
+    pub fn get_messages(&self, expected: usize, timeout: Duration) -> Vec<PublishedMessage> {
+        let started = Instant::now();
+        loop {
+            let messages = self.messages.lock().unwrap().clone();
+            if messages.len() >= expected || started.elapsed() >= timeout {
+                return messages;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
+
+fn handle(
+    mut stream: TcpStream,
+    code: u8,
+    messages: &Arc<Mutex<Vec<PublishedMessage>>>,
+) -> crate::DuckResult<()> {
+    let mut session = PublishedMessage::default();
+    loop {
+        let (header, body) = read_packet(&mut stream)?;
+        match header & 0xF0 {
+            CONNECT => {
+                // Skip protocol name (6), level (1), flags (1) and keep alive (2).
+                let flags = body[7];
+                let mut offset = 10;
+                session.client_id = read_string(&body, &mut offset);
+                if flags & 0x80 != 0 {
+                    session.username = Some(read_string(&body, &mut offset));
+                }
+                if flags & 0x40 != 0 {
+                    session.password = Some(read_string(&body, &mut offset));
+                }
+                stream.write_all(&[CONNACK, 2, 0, code])?;
+            }
+            PUBLISH => {
+                let qos = (header >> 1) & 0x03;
+                let mut offset = 0;
+                let mut message = session.clone();
+                message.topic = read_string(&body, &mut offset);
+                message.qos = qos;
+                message.retain = header & 0x01 != 0;
+                if qos > 0 {
+                    let packet_id = u16::from_be_bytes([body[offset], body[offset + 1]]);
+                    offset += 2;
+                    let ack = if qos == 1 { PUBACK } else { PUBREC };
+                    stream.write_all(&encode_ack(ack, packet_id))?;
+                }
+                message.payload = String::from_utf8(body[offset..].to_vec())?;
+                messages.lock().unwrap().push(message);
+            }
+            // PUBREL has reserved flags set, so compare the type only.
+            0x60 => {
+                let packet_id = u16::from_be_bytes([body[0], body[1]]);
+                stream.write_all(&encode_ack(PUBCOMP, packet_id))?;
+            }
+            DISCONNECT => return Ok(()),
+            _ => return Err(format_err!("Unexpected packet ({:#04x})", header)),
+        }
+    }
+}
+
+fn read_string(body: &[u8], offset: &mut usize) -> String {
+    let length = u16::from_be_bytes([body[*offset], body[*offset + 1]]) as usize;
+    let value = String::from_utf8_lossy(&body[*offset + 2..*offset + 2 + length]).to_string();
+    *offset += 2 + length;
+    value
+}
@@ -0,0 +1,288 @@
+use std::convert::TryFrom;
+use std::io::{Read, Write};
+use std::time::Duration;
+
+use crate::config::{MqttConfiguration, MqttCredentials};
+use crate::utils::net::Transport;
+use crate::DuckResult;
+
+static TIMEOUT: Duration = Duration::from_secs(30);
+static KEEP_ALIVE: u16 = 30;
+
+// MQTT 3.1.1 control packet types
+pub const CONNECT: u8 = 0x10;
+pub const CONNACK: u8 = 0x20;
+pub const PUBLISH: u8 = 0x30;
+pub const PUBACK: u8 = 0x40;
+pub const PUBREC: u8 = 0x50;
+pub const PUBREL: u8 = 0x62;
+pub const PUBCOMP: u8 = 0x70;
+pub const DISCONNECT: u8 = 0xE0;
+
+pub struct MqttMessage {
+    pub topic: String,
+    pub payload: String,
+}
+
+/// A minimal MQTT 3.1.1 client that connects,
+/// publishes a single message and disconnects.
+pub struct MqttClient {
+    host: String,
+    port: u16,
+    tls: bool,
+    client_id: String,
+    credentials: Option<MqttCredentials>,
+    qos: u8,
+    retain: bool,
+}
+
+impl MqttClient {
+    pub fn new(config: &MqttConfiguration) -> Self {
+        MqttClient {
+            host: config.host.clone(),
+            port: config.get_port(),
+            tls: config.tls.unwrap_or(false),
+            client_id: config.get_client_id(),
+            credentials: config.credentials.clone(),
+            qos: config.qos.unwrap_or(0),
+            retain: config.retain.unwrap_or(true),
+        }
+    }
+
+    pub fn publish(&self, message: &MqttMessage) -> DuckResult<()> {
+        let mut transport = Transport::connect(&self.host, self.port, TIMEOUT)?;
+        if self.tls {
+            transport = transport.upgrade(&self.host)?;
+        }
+
+        transport.write_all(&encode_connect(&self.client_id, self.credentials.as_ref())?)?;
+        let (header, body) = read_packet(&mut transport)?;
+        if header != CONNACK || body.len() != 2 {
+            return Err(format_err!("Expected CONNACK from MQTT broker"));
+        }
+        if body[1] != 0 {
+            return Err(format_err!(
+                "MQTT broker refused the connection ({})",
+                describe_return_code(body[1])
+            ));
+        }
+
+        // We only send a single message per connection,
+        // so the packet identifier is always the same.
+        let packet_id = 1;
+        transport.write_all(&encode_publish(message, self.qos, self.retain, packet_id)?)?;
+        match self.qos {
+            1 => expect_ack(&mut transport, PUBACK, packet_id)?,
+            2 => {
+                expect_ack(&mut transport, PUBREC, packet_id)?;
+                transport.write_all(&encode_ack(PUBREL, packet_id))?;
+                expect_ack(&mut transport, PUBCOMP, packet_id)?;
+            }
+            _ => {}
+        }
+
+        transport.write_all(&[DISCONNECT, 0])?;
+        transport.flush()?;
+
+        Ok(())
+    }
+}
+
+fn expect_ack(transport: &mut impl Read, expected: u8, packet_id: u16) -> DuckResult<()> {
+    let (header, body) = read_packet(transport)?;
+    if header != expected || body != packet_id.to_be_bytes() {
+        return Err(format_err!(
+            "Unexpected packet from MQTT broker ({:#04x})",
+            header
+        ));
+    }
+    Ok(())
+}
+
+fn describe_return_code(code: u8) -> &'static str {
+    match code {
+        1 => "unacceptable protocol version",
+        2 => "identifier rejected",
+        3 => "server unavailable",
+        4 => "bad username or password",
+        5 => "not authorized",
+        _ => "unknown reason",
+    }
+}
+
+/// Reads a packet and returns the fixed header byte and the rest of the packet.
+pub fn read_packet(reader: &mut impl Read) -> DuckResult<(u8, Vec<u8>)> {
+    let mut byte = [0u8; 1];
+    reader.read_exact(&mut byte)?;
+    let header = byte[0];
+
+    let mut length = 0usize;
+    for shift in (0..28).step_by(7) {
+        reader.read_exact(&mut byte)?;
+        length |= ((byte[0] & 0x7F) as usize) << shift;
+        if byte[0] & 0x80 == 0 {
+            let mut body = vec![0u8; length];
+            reader.read_exact(&mut body)?;
+            return Ok((header, body));
+        }
+    }
+
+    Err(format_err!("Malformed remaining length in MQTT packet"))
+}
+
+pub fn encode_ack(header: u8, packet_id: u16) -> Vec<u8> {
+    let mut packet = vec![header, 2];
+    packet.extend_from_slice(&packet_id.to_be_bytes());
+    packet
+}
+
+fn encode_connect(client_id: &str, credentials: Option<&MqttCredentials>) -> DuckResult<Vec<u8>> {
+    let mut flags = 0x02; // Clean session
+    if credentials.is_some() {
+        flags |= 0xC0; // Username and password
+    }
+
+    let mut body = Vec::new();
+    encode_string(&mut body, "MQTT")?;
+    body.push(4); // Protocol level
+    body.push(flags);
+    body.extend_from_slice(&KEEP_ALIVE.to_be_bytes());
+    encode_string(&mut body, client_id)?;
+    if let Some(MqttCredentials::Basic { username, password }) = credentials {
+        encode_string(&mut body, username)?;
+        encode_string(&mut body, password)?;
+    }
+
+    Ok(encode_packet(CONNECT, body))
+}
+
+fn encode_publish(
+    message: &MqttMessage,
+    qos: u8,
+    retain: bool,
+    packet_id: u16,
+) -> DuckResult<Vec<u8>> {
+    let mut body = Vec::new();
+    encode_string(&mut body, &message.topic)?;
+    if qos > 0 {
+        body.extend_from_slice(&packet_id.to_be_bytes());
+    }
+    body.extend_from_slice(message.payload.as_bytes());
+
+    Ok(encode_packet(PUBLISH | (qos << 1) | retain as u8, body))
+}
+
+fn encode_packet(header: u8, body: Vec<u8>) -> Vec<u8> {
+    let mut packet = vec![header];
+    packet.extend(encode_remaining_length(body.len()));
+    packet.extend(body);
+    packet
+}
+
+fn encode_remaining_length(mut length: usize) -> Vec<u8> {
+    let mut result = Vec::new();
+    loop {
+        let mut byte = (length % 128) as u8;
+        length /= 128;
+        if length > 0 {
+            byte |= 0x80;
+        }
+        result.push(byte);
+        if length == 0 {
+            return result;
+        }
+    }
+}
+
+fn encode_string(buffer: &mut Vec<u8>, value: &str) -> DuckResult<()> {
+    let length = u16::try_from(value.len())
+        .map_err(|_| format_err!("MQTT string is too long ({} bytes)", value.len()))?;
+    buffer.extend_from_slice(&length.to_be_bytes());
+    buffer.extend_from_slice(value.as_bytes());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0, &[0x00] ; "Zero")]
+    #[test_case(127, &[0x7F] ; "One byte")]
+    #[test_case(128, &[0x80, 0x01] ; "Two bytes")]
+    #[test_case(16_383, &[0xFF, 0x7F] ; "Two bytes max")]
+    #[test_case(16_384, &[0x80, 0x80, 0x01] ; "Three bytes")]
+    fn should_encode_remaining_length(length: usize, expected: &[u8]) {
+        // Given, When
+        let result = encode_remaining_length(length);
+
+        // Then
+        assert_eq!(expected, &result[..]);
+    }
+
+    #[test]
+    fn should_encode_connect_packet() {
+        // Given, When
+        let result = encode_connect("duck", None).unwrap();
+
+        // Then
+        assert_eq!(
+            vec![
+                0x10, 16, // Fixed header
+                0, 4, b'M', b'Q', b'T', b'T', // Protocol name
+                4, 0x02, 0, 30, // Level, flags and keep alive
+                0, 4, b'd', b'u', b'c', b'k' // Client ID
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn should_encode_publish_packet() {
+        // Given
+        let message = MqttMessage {
+            topic: "a/b".to_string(),
+            payload: "ok".to_string(),
+        };
+
+        // When
+        let result = encode_publish(&message, 1, true, 7).unwrap();
+
+        // Then
+        assert_eq!(
+            vec![0x33, 9, 0, 3, b'a', b'/', b'b', 0, 7, b'o', b'k'],
+            result
+        );
+    }
+
+    #[test]
+    fn should_return_error_if_string_is_too_long() {
+        // Given
+        let message = MqttMessage {
+            topic: "a".repeat(65_536),
+            payload: "ok".to_string(),
+        };
+
+        // When
+        let result = encode_publish(&message, 0, false, 1);
+
+        // Then
+        assert_eq!(
+            "MQTT string is too long (65536 bytes)",
+            result.err().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn should_read_packet() {
+        // Given
+        let mut data: &[u8] = &[0x20, 2, 0, 5];
+
+        // When
+        let (header, body) = read_packet(&mut data).unwrap();
+
+        // Then
+        assert_eq!(CONNACK, header);
+        assert_eq!(vec![0, 5], body);
+    }
+}
@@ -0,0 +1,272 @@
+use log::debug;
+
+use crate::builds::Build;
+use crate::config::MqttConfiguration;
+use crate::filters::BuildFilter;
+use crate::providers::observers::{Observation, Observer, ObserverInfo, ObserverLoader};
+use crate::DuckResult;
+
+use self::client::{MqttClient, MqttMessage};
+
+#[cfg(test)]
+mod broker;
+mod client;
+mod validation;
+
+impl ObserverLoader for MqttConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Observer>> {
+        Ok(Box::new(MqttObserver::new(self)?))
+    }
+}
+
+pub struct MqttObserver {
+    client: MqttClient,
+    info: ObserverInfo,
+    status_topic: String,
+    build_topic: String,
+}
+
+impl MqttObserver {
+    pub fn new(config: &MqttConfiguration) -> DuckResult<Self> {
+        Ok(MqttObserver {
+            client: MqttClient::new(config),
+            info: ObserverInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                filter: BuildFilter::new(config.filter.clone())?,
+                collectors: config
+                    .collectors
+                    .as_ref()
+                    .map(|collectors| collectors.iter().cloned().collect()),
+            },
+            status_topic: config.get_status_topic().to_string(),
+            build_topic: config.get_build_topic().to_string(),
+        })
+    }
+}
+
+impl Observer for MqttObserver {
+    fn info(&self) -> &ObserverInfo {
+        &self.info
+    }
+
+    fn observe(&self, observation: Observation) -> DuckResult<()> {
+        match observation {
+            Observation::DuckStatusChanged(status) => {
+                debug!("[{}] Publishing Duck status '{}'...", self.info.id, status);
+                self.client.publish(&MqttMessage {
+                    topic: self.status_topic.clone(),
+                    payload: status.to_string(),
+                })?;
+            }
+            Observation::BuildStatusChanged(build) => {
+                debug!(
+                    "[{}] Publishing build status '{}'...",
+                    self.info.id, build.status
+                );
+                self.client.publish(&MqttMessage {
+                    topic: get_build_topic(&self.build_topic, build),
+                    payload: create_payload(build).to_string(),
+                })?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+fn get_build_topic(prefix: &str, build: &Build) -> String {
+    format!(
+        "{}/{}/{}/{}/{}",
+        prefix,
+        escape_topic_level(&build.collector),
+        escape_topic_level(&build.project_id),
+        escape_topic_level(&build.definition_id),
+        escape_topic_level(&build.branch)
+    )
+}
+
+/// Makes sure that a value is a single topic level without wildcards.
+fn escape_topic_level(value: &str) -> String {
+    value.replace(&['/', '+', '#'][..], "_")
+}
+
+fn create_payload(build: &Build) -> serde_json::Value {
+    json!({
+        "provider": build.provider,
+        "collector": build.collector,
+        "project": build.project_name,
+        "projectId": build.project_id,
+        "definition": build.definition_name,
+        "definitionId": build.definition_id,
+        "branch": build.branch,
+        "buildId": build.build_id,
+        "buildNumber": build.build_number,
+        "status": build.status,
+        "url": build.url,
+        "started": build.started_at,
+        "finished": build.finished_at
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::broker::MqttBroker;
+    use super::*;
+    use crate::builds::{BuildBuilder, BuildStatus};
+    use crate::config::MqttCredentials;
+    use test_case::test_case;
+
+    static TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn create_observer(
+        broker: &MqttBroker,
+        configure: impl FnOnce(&mut MqttConfiguration),
+    ) -> MqttObserver {
+        let mut config = MqttConfiguration {
+            id: "mqtt".to_string(),
+            enabled: Some(true),
+            collectors: None,
+            host: "127.0.0.1".to_string(),
+            port: Some(broker.port()),
+            tls: None,
+            client_id: None,
+            credentials: None,
+            status_topic: None,
+            build_topic: None,
+            qos: None,
+            retain: None,
+            filter: None,
+        };
+        configure(&mut config);
+        MqttObserver::new(&config).unwrap()
+    }
+
+    #[test]
+    fn should_publish_duck_status() {
+        // Given
+        let broker = MqttBroker::start();
+        let mqtt = create_observer(&broker, |_| {});
+
+        // When
+        mqtt.observe(Observation::DuckStatusChanged(BuildStatus::Failed))
+            .unwrap();
+
+        // Then
+        let messages = broker.get_messages(1, TIMEOUT);
+        assert_eq!(1, messages.len());
+        assert_eq!("duck-mqtt", messages[0].client_id);
+        assert_eq!("duck/status", messages[0].topic);
+        assert_eq!("Failed", messages[0].payload);
+        assert_eq!(0, messages[0].qos);
+        assert!(messages[0].retain);
+        assert_eq!(None, messages[0].username);
+    }
+
+    #[test]
+    fn should_publish_build_status_change() {
+        // Given
+        let broker = MqttBroker::start();
+        let mqtt = create_observer(&broker, |config| {
+            config.build_topic = Some("office/builds".to_string());
+        });
+        let build = BuildBuilder::dummy()
+            .branch("refs/heads/feature/+1")
+            .status(BuildStatus::Failed)
+            .unwrap();
+
+        // When
+        mqtt.observe(Observation::BuildStatusChanged(&build))
+            .unwrap();
+
+        // Then
+        let messages = broker.get_messages(1, TIMEOUT);
+        assert_eq!(1, messages.len());
+        assert_eq!(
+            "office/builds/collector/project_id/definition_id/refs_heads_feature__1",
+            messages[0].topic
+        );
+        let payload: serde_json::Value = serde_json::from_str(&messages[0].payload).unwrap();
+        assert_eq!("Failed", payload["status"]);
+        assert_eq!("project_name", payload["project"]);
+        assert_eq!("refs/heads/feature/+1", payload["branch"]);
+        assert_eq!("https://dummy", payload["url"]);
+    }
+
+    #[test]
+    fn should_not_publish_build_updates() {
+        // Given
+        let broker = MqttBroker::start();
+        let mqtt = create_observer(&broker, |_| {});
+
+        // When
+        mqtt.observe(Observation::BuildUpdated(&BuildBuilder::dummy().unwrap()))
+            .unwrap();
+        mqtt.observe(Observation::ShuttingDown).unwrap();
+
+        // Then
+        assert_eq!(0, broker.get_messages(1, Duration::from_millis(200)).len());
+    }
+
+    #[test_case(0 ; "At most once")]
+    #[test_case(1 ; "At least once")]
+    #[test_case(2 ; "Exactly once")]
+    fn should_publish_using_configured_qos(qos: u8) {
+        // Given
+        let broker = MqttBroker::start();
+        let mqtt = create_observer(&broker, |config| {
+            config.qos = Some(qos);
+            config.retain = Some(false);
+        });
+
+        // When
+        mqtt.observe(Observation::DuckStatusChanged(BuildStatus::Success))
+            .unwrap();
+
+        // Then
+        let messages = broker.get_messages(1, TIMEOUT);
+        assert_eq!(qos, messages[0].qos);
+        assert!(!messages[0].retain);
+    }
+
+    #[test]
+    fn should_send_credentials_and_client_id() {
+        // Given
+        let broker = MqttBroker::start();
+        let mqtt = create_observer(&broker, |config| {
+            config.client_id = Some("build-lamp".to_string());
+            config.credentials = Some(MqttCredentials::Basic {
+                username: "duck".to_string(),
+                password: "hunter2".to_string(),
+            });
+        });
+
+        // When
+        mqtt.observe(Observation::DuckStatusChanged(BuildStatus::Success))
+            .unwrap();
+
+        // Then
+        let messages = broker.get_messages(1, TIMEOUT);
+        assert_eq!("build-lamp", messages[0].client_id);
+        assert_eq!(Some("duck".to_string()), messages[0].username);
+        assert_eq!(Some("hunter2".to_string()), messages[0].password);
+    }
+
+    #[test]
+    fn should_return_error_if_broker_refuses_connection() {
+        // Given
+        let broker = MqttBroker::start_with_return_code(5);
+        let mqtt = create_observer(&broker, |_| {});
+
+        // When
+        let result = mqtt.observe(Observation::DuckStatusChanged(BuildStatus::Success));
+
+        // Then
+        assert_eq!(
+            "MQTT broker refused the connection (not authorized)",
+            result.err().unwrap().to_string()
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use crate::config::{MqttConfiguration, MqttCredentials, Validate};
+use crate::DuckResult;
+
+// Strings in MQTT packets are prefixed with a 16-bit length.
+const MAX_STRING_LENGTH: usize = u16::MAX as usize;
+
+impl Validate for MqttConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        if self.host.is_empty() {
+            return Err(format_err!("[{}] MQTT broker host is empty", self.id));
+        }
+        if let Some(client_id) = &self.client_id {
+            if client_id.is_empty() {
+                return Err(format_err!("[{}] MQTT client ID is empty", self.id));
+            }
+        }
+        if self.get_client_id().len() > MAX_STRING_LENGTH {
+            return Err(format_err!("[{}] MQTT client ID is too long", self.id));
+        }
+        if let Some(MqttCredentials::Basic { username, password }) = &self.credentials {
+            if username.is_empty() {
+                return Err(format_err!("[{}] MQTT username is empty", self.id));
+            }
+            if username.len() > MAX_STRING_LENGTH || password.len() > MAX_STRING_LENGTH {
+                return Err(format_err!("[{}] MQTT credentials are too long", self.id));
+            }
+        }
+        if let Some(qos) = self.qos {
+            if qos > 2 {
+                return Err(format_err!("[{}] MQTT QoS must be 0, 1 or 2", self.id));
+            }
+        }
+        for topic in &[self.get_status_topic(), self.get_build_topic()] {
+            if topic.is_empty() || topic.contains(&['+', '#'][..]) {
+                return Err(format_err!(
+                    "[{}] MQTT topic '{}' is invalid",
+                    self.id,
+                    topic
+                ));
+            }
+            if topic.len() > MAX_STRING_LENGTH {
+                return Err(format_err!("[{}] MQTT topic is too long", self.id));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(expected = "[foo] MQTT broker host is empty")]
+    fn should_return_error_if_host_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "mqtt": {
+                            "id": "foo",
+                            "host": ""
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] MQTT client ID is empty")]
+    fn should_return_error_if_client_id_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "mqtt": {
+                            "id": "foo",
+                            "host": "localhost",
+                            "clientId": ""
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] MQTT username is empty")]
+    fn should_return_error_if_username_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "mqtt": {
+                            "id": "foo",
+                            "host": "localhost",
+                            "credentials": {
+                                "basic": { "username": "", "password": "hunter2" }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] MQTT QoS must be 0, 1 or 2")]
+    fn should_return_error_if_qos_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "mqtt": {
+                            "id": "foo",
+                            "host": "localhost",
+                            "qos": 3
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] MQTT topic 'duck/#' is invalid")]
+    fn should_return_error_if_topic_contains_wildcard() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "mqtt": {
+                            "id": "foo",
+                            "host": "localhost",
+                            "statusTopic": "duck/#"
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] MQTT client ID is too long")]
+    fn should_return_error_if_client_id_is_too_long() {
+        let mut variables = TestVariableProvider::new();
+        variables.add("LONG", &"a".repeat(65_536)[..]);
+        let config = Configuration::from_json(
+            &variables,
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "mqtt": {
+                            "id": "foo",
+                            "host": "localhost",
+                            "clientId": "${LONG}"
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] MQTT credentials are too long")]
+    fn should_return_error_if_credentials_are_too_long() {
+        let mut variables = TestVariableProvider::new();
+        variables.add("LONG", &"a".repeat(65_536)[..]);
+        let config = Configuration::from_json(
+            &variables,
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "mqtt": {
+                            "id": "foo",
+                            "host": "localhost",
+                            "credentials": {
+                                "basic": {
+                                    "username": "duck",
+                                    "password": "${LONG}"
+                                }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] MQTT topic is too long")]
+    fn should_return_error_if_topic_is_too_long() {
+        let mut variables = TestVariableProvider::new();
+        variables.add("LONG", &"a".repeat(65_536)[..]);
+        let config = Configuration::from_json(
+            &variables,
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "mqtt": {
+                            "id": "foo",
+                            "host": "localhost",
+                            "statusTopic": "${LONG}"
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+}
@@ -0,0 +1,259 @@
+use log::info;
+
+use crate::builds::{Build, BuildStatus};
+use crate::config::TeamsConfiguration;
+use crate::filters::BuildFilter;
+use crate::providers::observers::{Observation, Observer, ObserverInfo, ObserverLoader};
+use crate::utils::date;
+use crate::utils::http::{HttpClient, ReqwestClient};
+use crate::DuckResult;
+
+use self::client::TeamsClient;
+
+mod client;
+mod validation;
+
+impl ObserverLoader for TeamsConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Observer>> {
+        Ok(Box::new(TeamsObserver::<ReqwestClient>::new(self)?))
+    }
+}
+
+pub struct TeamsObserver<T: HttpClient + Default> {
+    client: TeamsClient,
+    http: T,
+    info: ObserverInfo,
+}
+
+impl<T: HttpClient + Default> TeamsObserver<T> {
+    pub fn new(config: &TeamsConfiguration) -> DuckResult<Self> {
+        Ok(TeamsObserver {
+            client: TeamsClient::new(config),
+            http: Default::default(),
+            info: ObserverInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                filter: BuildFilter::new(config.filter.clone())?,
+                collectors: config
+                    .collectors
+                    .as_ref()
+                    .map(|collectors| collectors.iter().cloned().collect()),
+            },
+        })
+    }
+
+    #[cfg(test)]
+    pub fn get_client(&self) -> &T {
+        &self.http
+    }
+}
+
+impl<T: HttpClient + Default> Observer for TeamsObserver<T> {
+    fn info(&self) -> &ObserverInfo {
+        &self.info
+    }
+
+    fn observe(&self, observation: Observation) -> DuckResult<()> {
+        if let Observation::BuildStatusChanged(build) = observation {
+            if build.status != BuildStatus::Unknown {
+                info!(
+                    "Sending Microsoft Teams message since build status changed ({})...",
+                    build.status
+                );
+                self.client.send(&self.http, create_card(build))?;
+            }
+        };
+
+        Ok(())
+    }
+}
+
+fn create_card(build: &Build) -> serde_json::Value {
+    let mut facts = vec![
+        json!({ "title": "Provider", "value": build.provider }),
+        json!({ "title": "Branch", "value": build.branch }),
+        json!({ "title": "Build", "value": build.build_number }),
+    ];
+    if let Some(finished_at) = build.finished_at {
+        facts.push(json!({
+            "title": "Duration",
+            "value": date::format_duration(finished_at - build.started_at)
+        }));
+    }
+
+    json!({
+        "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+        "type": "AdaptiveCard",
+        "version": "1.4",
+        "body": [
+            {
+                "type": "TextBlock",
+                "size": "Medium",
+                "weight": "Bolder",
+                "wrap": true,
+                "text": format!("{}::{}", build.project_name, build.definition_name)
+            },
+            {
+                "type": "TextBlock",
+                "wrap": true,
+                "color": match build.status {
+                    BuildStatus::Success => "Good",
+                    BuildStatus::Failed => "Attention",
+                    BuildStatus::Canceled | BuildStatus::Blocked => "Warning",
+                    _ => "Default",
+                },
+                "text": format!("Build status changed to **{}**", build.status)
+            },
+            {
+                "type": "FactSet",
+                "facts": facts
+            }
+        ],
+        "actions": [
+            {
+                "type": "Action.OpenUrl",
+                "title": "View build",
+                "url": build.url
+            }
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::{BuildBuilder, BuildStatus};
+    use crate::config::TeamsCredentials;
+    use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
+    use reqwest::StatusCode;
+    use test_case::test_case;
+
+    fn create_observer() -> TeamsObserver<MockHttpClient> {
+        let teams = TeamsObserver::<MockHttpClient>::new(&TeamsConfiguration {
+            id: "teams".to_string(),
+            enabled: Some(true),
+            collectors: None,
+            filter: None,
+            credentials: TeamsCredentials::Webhook {
+                url: "https://example.com/webhook".to_string(),
+            },
+        })
+        .unwrap();
+
+        teams.get_client().add_response(
+            MockHttpResponseBuilder::new(HttpMethod::Post, "https://example.com/webhook")
+                .returns_status(StatusCode::ACCEPTED),
+        );
+
+        teams
+    }
+
+    fn observe(teams: &TeamsObserver<MockHttpClient>, build: &Build) -> serde_json::Value {
+        teams
+            .observe(Observation::BuildStatusChanged(build))
+            .unwrap();
+
+        let requests = teams.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(HttpMethod::Post, requests[0].method);
+        assert_eq!("https://example.com/webhook", &requests[0].url);
+        serde_json::from_str(requests[0].body.as_ref().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn should_post_adaptive_card_to_webhook_url() {
+        // Given
+        let teams = create_observer();
+
+        // When
+        let payload = observe(&teams, &BuildBuilder::dummy().unwrap());
+
+        // Then
+        let attachment = &payload["attachments"][0];
+        let card = &attachment["content"];
+        assert_eq!("message", payload["type"]);
+        assert_eq!(
+            "application/vnd.microsoft.card.adaptive",
+            attachment["contentType"]
+        );
+        assert_eq!("AdaptiveCard", card["type"]);
+        assert_eq!("project_name::definition_name", card["body"][0]["text"]);
+        assert_eq!(
+            "Build status changed to **Success**",
+            card["body"][1]["text"]
+        );
+        assert_eq!("https://dummy", card["actions"][0]["url"]);
+    }
+
+    #[test]
+    fn should_include_build_facts() {
+        // Given
+        let teams = create_observer();
+
+        // When
+        let payload = observe(&teams, &BuildBuilder::dummy().unwrap());
+
+        // Then
+        let facts = &payload["attachments"][0]["content"]["body"][2]["facts"];
+        assert_eq!(
+            json!([
+                { "title": "Provider", "value": "TeamCity" },
+                { "title": "Branch", "value": "branch" },
+                { "title": "Build", "value": "build_number" },
+                { "title": "Duration", "value": "16m 40s" }
+            ]),
+            *facts
+        );
+    }
+
+    #[test]
+    fn should_not_include_duration_for_running_build() {
+        // Given
+        let teams = create_observer();
+        let build = BuildBuilder::dummy()
+            .status(BuildStatus::Running)
+            .finished_at(None)
+            .unwrap();
+
+        // When
+        let payload = observe(&teams, &build);
+
+        // Then
+        let facts = &payload["attachments"][0]["content"]["body"][2]["facts"];
+        assert_eq!(3, facts.as_array().unwrap().len());
+    }
+
+    #[test_case(BuildStatus::Success, "Good" ; "Success")]
+    #[test_case(BuildStatus::Failed, "Attention" ; "Failed")]
+    #[test_case(BuildStatus::Canceled, "Warning" ; "Canceled")]
+    #[test_case(BuildStatus::Running, "Default" ; "Running")]
+    fn should_color_status_text(status: BuildStatus, expected: &str) {
+        // Given
+        let teams = create_observer();
+
+        // When
+        let payload = observe(&teams, &BuildBuilder::dummy().status(status).unwrap());
+
+        // Then
+        assert_eq!(
+            expected,
+            payload["attachments"][0]["content"]["body"][1]["color"]
+        );
+    }
+
+    #[test]
+    fn should_not_send_message_for_unknown_status() {
+        // Given
+        let teams = create_observer();
+
+        // When
+        teams
+            .observe(Observation::BuildStatusChanged(
+                &BuildBuilder::dummy().status(BuildStatus::Unknown).unwrap(),
+            ))
+            .unwrap();
+
+        // Then
+        assert_eq!(0, teams.get_client().get_sent_requests().len());
+    }
+}
@@ -0,0 +1,59 @@
+use url::Url;
+
+use crate::config::{TeamsConfiguration, TeamsCredentials, Validate};
+use crate::DuckResult;
+
+impl Validate for TeamsConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        match &self.credentials {
+            TeamsCredentials::Webhook { url } => {
+                if let Err(e) = Url::parse(url) {
+                    return Err(format_err!(
+                        "[{}] Microsoft Teams webhook URL is invalid: {}",
+                        self.id,
+                        e
+                    ));
+                }
+            }
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Configuration;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(
+        expected = "[foo] Microsoft Teams webhook URL is invalid: relative URL without a base"
+    )]
+    fn should_return_error_if_teams_webhook_url_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "teams": {
+                            "id": "foo",
+                            "credentials": {
+                                "webhook": {
+                                    "url": ""
+                                }
+                            }
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use crate::config::{WebhookMethod, WebhookObserverConfiguration};
+use crate::utils::http::{HttpClient, HttpRequestBuilder, HttpResponse};
+use crate::utils::signature;
+use crate::DuckResult;
+
+pub struct WebhookClient {
+    url: String,
+    method: WebhookMethod,
+    headers: HashMap<String, String>,
+    secret: Option<String>,
+}
+
+impl WebhookClient {
+    pub fn new(config: &WebhookObserverConfiguration) -> Self {
+        WebhookClient {
+            url: config.url.clone(),
+            method: config.method.clone().unwrap_or(WebhookMethod::Post),
+            headers: config.headers.clone().unwrap_or_default(),
+            secret: config.secret.clone(),
+        }
+    }
+
+    /// Whether or not the body is sent as JSON,
+    /// which is the case unless another content type is specified.
+    pub fn is_json(&self) -> bool {
+        self.headers
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case("Content-Type"))
+            .all(|(_, value)| value.to_ascii_lowercase().contains("json"))
+    }
+
+    pub fn send(&self, client: &impl HttpClient, body: String) -> DuckResult<()> {
+        let mut builder = match self.method {
+            WebhookMethod::Post => HttpRequestBuilder::post(self.url.clone()),
+            WebhookMethod::Put => HttpRequestBuilder::put(self.url.clone()),
+        };
+
+        if !self
+            .headers
+            .keys()
+            .any(|name| name.eq_ignore_ascii_case("Content-Type"))
+        {
+            builder.add_header("Content-Type", "application/json");
+        }
+        for (name, value) in self.headers.iter() {
+            builder.add_header(name, value);
+        }
+        if let Some(secret) = &self.secret {
+            builder.add_header(
+                "X-Duck-Signature-256",
+                &signature::sign(secret, body.as_bytes())[..],
+            );
+        }
+        builder.set_body(body);
+
+        let response = client.send(&builder)?;
+        if !response.status().is_success() {
+            return Err(format_err!(
+                "Could not send webhook request. ({})",
+                response.status()
+            ));
+        }
+
+        Ok(())
+    }
+}
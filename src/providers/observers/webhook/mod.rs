@@ -0,0 +1,387 @@
+use std::collections::HashMap;
+
+use log::info;
+
+use crate::builds::Build;
+use crate::config::{WebhookEvent, WebhookObserverConfiguration};
+use crate::filters::BuildFilter;
+use crate::providers::observers::{Observation, Observer, ObserverInfo, ObserverLoader};
+use crate::utils::http::{HttpClient, ReqwestClient};
+use crate::DuckResult;
+
+use self::client::WebhookClient;
+use self::template::Template;
+
+mod client;
+mod template;
+mod validation;
+
+impl ObserverLoader for WebhookObserverConfiguration {
+    fn load(&self) -> DuckResult<Box<dyn Observer>> {
+        Ok(Box::new(WebhookObserver::<ReqwestClient>::new(self)?))
+    }
+}
+
+impl WebhookEvent {
+    fn name(&self) -> &'static str {
+        match self {
+            WebhookEvent::DuckStatusChanged => "duckStatusChanged",
+            WebhookEvent::BuildUpdated => "buildUpdated",
+            WebhookEvent::BuildStatusChanged => "buildStatusChanged",
+            WebhookEvent::ShuttingDown => "shuttingDown",
+        }
+    }
+}
+
+pub struct WebhookObserver<T: HttpClient + Default> {
+    client: WebhookClient,
+    http: T,
+    info: ObserverInfo,
+    events: Vec<WebhookEvent>,
+    template: Option<Template>,
+}
+
+impl<T: HttpClient + Default> WebhookObserver<T> {
+    pub fn new(config: &WebhookObserverConfiguration) -> DuckResult<Self> {
+        Ok(WebhookObserver {
+            client: WebhookClient::new(config),
+            http: Default::default(),
+            info: ObserverInfo {
+                id: config.id.clone(),
+                enabled: config.enabled.unwrap_or(true),
+                filter: BuildFilter::new(config.filter.clone())?,
+                collectors: config
+                    .collectors
+                    .as_ref()
+                    .map(|collectors| collectors.iter().cloned().collect()),
+            },
+            events: config.events.clone().unwrap_or_else(|| {
+                vec![
+                    WebhookEvent::DuckStatusChanged,
+                    WebhookEvent::BuildUpdated,
+                    WebhookEvent::BuildStatusChanged,
+                    WebhookEvent::ShuttingDown,
+                ]
+            }),
+            template: config.template.as_ref().map(Template::new),
+        })
+    }
+
+    #[cfg(test)]
+    pub fn get_client(&self) -> &T {
+        &self.http
+    }
+}
+
+impl<T: HttpClient + Default> Observer for WebhookObserver<T> {
+    fn info(&self) -> &ObserverInfo {
+        &self.info
+    }
+
+    fn observe(&self, observation: Observation) -> DuckResult<()> {
+        let event = get_event(&observation);
+        if !self.events.contains(&event) {
+            return Ok(());
+        }
+
+        let body = match &self.template {
+            Some(template) if self.client.is_json() => {
+                template.render_json(&get_variables(&event, &observation))
+            }
+            Some(template) => template.render(&get_variables(&event, &observation)),
+            None => create_body(&event, &observation).to_string(),
+        };
+
+        info!("Sending webhook request ({})...", event.name());
+        self.client.send(&self.http, body)
+    }
+}
+
+fn get_event(observation: &Observation) -> WebhookEvent {
+    match observation {
+        Observation::DuckStatusChanged(_) => WebhookEvent::DuckStatusChanged,
+        Observation::BuildUpdated(_) => WebhookEvent::BuildUpdated,
+        Observation::BuildStatusChanged(_) => WebhookEvent::BuildStatusChanged,
+        Observation::ShuttingDown => WebhookEvent::ShuttingDown,
+    }
+}
+
+/// Creates the default body. Builds use the same format as
+/// the webhook collector, so one Duck can push builds to another.
+fn create_body(event: &WebhookEvent, observation: &Observation) -> serde_json::Value {
+    match observation {
+        Observation::DuckStatusChanged(status) => json!({
+            "event": event.name(),
+            "status": status
+        }),
+        Observation::BuildUpdated(build) | Observation::BuildStatusChanged(build) => json!({
+            "event": event.name(),
+            "provider": build.provider,
+            "collector": build.collector,
+            "project": build.project_name,
+            "projectId": build.project_id,
+            "definition": build.definition_name,
+            "definitionId": build.definition_id,
+            "branch": build.branch,
+            "buildId": build.build_id,
+            "buildNumber": build.build_number,
+            "status": build.status,
+            "url": build.url,
+            "started": build.started_at,
+            "finished": build.finished_at
+        }),
+        Observation::ShuttingDown => json!({ "event": event.name() }),
+    }
+}
+
+fn get_variables(event: &WebhookEvent, observation: &Observation) -> HashMap<&'static str, String> {
+    let mut variables = HashMap::new();
+    variables.insert("event", event.name().to_string());
+    match observation {
+        Observation::DuckStatusChanged(status) => {
+            variables.insert("status", status.to_string());
+        }
+        Observation::BuildUpdated(build) | Observation::BuildStatusChanged(build) => {
+            add_build_variables(&mut variables, build);
+        }
+        Observation::ShuttingDown => {}
+    };
+    variables
+}
+
+fn add_build_variables(variables: &mut HashMap<&'static str, String>, build: &Build) {
+    variables.insert("status", build.status.to_string());
+    variables.insert("provider", build.provider.clone());
+    variables.insert("collector", build.collector.clone());
+    variables.insert("project", build.project_name.clone());
+    variables.insert("projectId", build.project_id.clone());
+    variables.insert("definition", build.definition_name.clone());
+    variables.insert("definitionId", build.definition_id.clone());
+    variables.insert("branch", build.branch.clone());
+    variables.insert("buildId", build.build_id.clone());
+    variables.insert("buildNumber", build.build_number.clone());
+    variables.insert("url", build.url.clone());
+    variables.insert("started", build.started_at.to_string());
+    if let Some(finished_at) = build.finished_at {
+        variables.insert("finished", finished_at.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builds::{BuildBuilder, BuildStatus};
+    use crate::config::WebhookMethod;
+    use crate::utils::http::{HttpMethod, MockHttpClient, MockHttpResponseBuilder};
+    use crate::utils::signature;
+    use reqwest::StatusCode;
+
+    fn create_observer(
+        configure: impl FnOnce(&mut WebhookObserverConfiguration),
+    ) -> WebhookObserver<MockHttpClient> {
+        let mut config = WebhookObserverConfiguration {
+            id: "webhook".to_string(),
+            enabled: Some(true),
+            collectors: None,
+            url: "https://example.com/webhook".to_string(),
+            method: None,
+            headers: None,
+            events: None,
+            template: None,
+            secret: None,
+            filter: None,
+        };
+        configure(&mut config);
+
+        let webhook = WebhookObserver::<MockHttpClient>::new(&config).unwrap();
+        for method in &[HttpMethod::Post, HttpMethod::Put] {
+            webhook.get_client().add_response(
+                MockHttpResponseBuilder::new(method.clone(), "https://example.com/webhook")
+                    .returns_status(StatusCode::OK),
+            );
+        }
+
+        webhook
+    }
+
+    #[test]
+    fn should_post_build_as_json_by_default() {
+        // Given
+        let webhook = create_observer(|_| {});
+
+        // When
+        webhook
+            .observe(Observation::BuildStatusChanged(
+                &BuildBuilder::dummy().unwrap(),
+            ))
+            .unwrap();
+
+        // Then
+        let requests = webhook.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(HttpMethod::Post, requests[0].method);
+        assert_eq!("https://example.com/webhook", &requests[0].url);
+        assert_eq!("application/json", requests[0].headers["Content-Type"]);
+        let body: serde_json::Value =
+            serde_json::from_str(requests[0].body.as_ref().unwrap()).unwrap();
+        assert_eq!("buildStatusChanged", body["event"]);
+        assert_eq!("project_name", body["project"]);
+        assert_eq!("definition_name", body["definition"]);
+        assert_eq!("branch", body["branch"]);
+        assert_eq!("foo", body["buildId"]);
+        assert_eq!("Success", body["status"]);
+        assert_eq!(1578820921, body["finished"]);
+    }
+
+    #[test]
+    fn should_post_duck_status_as_json_by_default() {
+        // Given
+        let webhook = create_observer(|_| {});
+
+        // When
+        webhook
+            .observe(Observation::DuckStatusChanged(BuildStatus::Failed))
+            .unwrap();
+
+        // Then
+        let requests = webhook.get_client().get_sent_requests();
+        let body: serde_json::Value =
+            serde_json::from_str(requests[0].body.as_ref().unwrap()).unwrap();
+        assert_eq!(
+            json!({ "event": "duckStatusChanged", "status": "Failed" }),
+            body
+        );
+    }
+
+    #[test]
+    fn should_render_template() {
+        // Given
+        let webhook = create_observer(|config| {
+            config.template = Some("{{project}}/{{branch}}: {{status}} ({{event}})".to_string());
+            config.headers = Some(
+                vec![("content-type".to_string(), "text/plain".to_string())]
+                    .into_iter()
+                    .collect(),
+            );
+        });
+
+        // When
+        webhook
+            .observe(Observation::BuildUpdated(&BuildBuilder::dummy().unwrap()))
+            .unwrap();
+
+        // Then
+        let requests = webhook.get_client().get_sent_requests();
+        assert_eq!(
+            "project_name/branch: Success (buildUpdated)",
+            requests[0].body.as_ref().unwrap()
+        );
+        assert_eq!(1, requests[0].headers.len());
+        assert_eq!("text/plain", requests[0].headers["content-type"]);
+    }
+
+    #[test]
+    fn should_escape_template_values_when_sending_json() {
+        // Given
+        let webhook = create_observer(|config| {
+            config.template = Some(r#"{ "text": "{{project}} is {{status}}" }"#.to_string());
+        });
+        let build = BuildBuilder::dummy()
+            .project_name(r#"The "Duck" project"#)
+            .unwrap();
+
+        // When
+        webhook.observe(Observation::BuildUpdated(&build)).unwrap();
+
+        // Then
+        let requests = webhook.get_client().get_sent_requests();
+        let body: serde_json::Value =
+            serde_json::from_str(requests[0].body.as_ref().unwrap()).unwrap();
+        assert_eq!(r#"The "Duck" project is Success"#, body["text"]);
+    }
+
+    #[test]
+    fn should_use_configured_method_and_headers() {
+        // Given
+        let webhook = create_observer(|config| {
+            config.method = Some(WebhookMethod::Put);
+            config.headers = Some(
+                vec![("X-Api-Key".to_string(), "SECRET".to_string())]
+                    .into_iter()
+                    .collect(),
+            );
+        });
+
+        // When
+        webhook.observe(Observation::ShuttingDown).unwrap();
+
+        // Then
+        let requests = webhook.get_client().get_sent_requests();
+        assert_eq!(HttpMethod::Put, requests[0].method);
+        assert_eq!("SECRET", requests[0].headers["X-Api-Key"]);
+        assert_eq!(
+            r#"{"event":"shuttingDown"}"#,
+            requests[0].body.as_ref().unwrap()
+        );
+    }
+
+    #[test]
+    fn should_sign_body_if_secret_has_been_configured() {
+        // Given
+        let webhook = create_observer(|config| {
+            config.secret = Some("It's a Secret to Everybody".to_string());
+        });
+
+        // When
+        webhook.observe(Observation::ShuttingDown).unwrap();
+
+        // Then
+        let requests = webhook.get_client().get_sent_requests();
+        assert!(signature::verify(
+            "It's a Secret to Everybody",
+            requests[0].body.as_ref().unwrap().as_bytes(),
+            &requests[0].headers["X-Duck-Signature-256"]
+        ));
+    }
+
+    #[test]
+    fn should_not_sign_body_if_secret_has_not_been_configured() {
+        // Given
+        let webhook = create_observer(|_| {});
+
+        // When
+        webhook.observe(Observation::ShuttingDown).unwrap();
+
+        // Then
+        let requests = webhook.get_client().get_sent_requests();
+        assert!(!requests[0].headers.contains_key("X-Duck-Signature-256"));
+    }
+
+    #[test]
+    fn should_only_send_requests_for_configured_events() {
+        // Given
+        let webhook = create_observer(|config| {
+            config.events = Some(vec![WebhookEvent::BuildStatusChanged]);
+        });
+        let build = BuildBuilder::dummy().unwrap();
+
+        // When
+        webhook.observe(Observation::BuildUpdated(&build)).unwrap();
+        webhook
+            .observe(Observation::DuckStatusChanged(BuildStatus::Success))
+            .unwrap();
+        webhook.observe(Observation::ShuttingDown).unwrap();
+        webhook
+            .observe(Observation::BuildStatusChanged(&build))
+            .unwrap();
+
+        // Then
+        let requests = webhook.get_client().get_sent_requests();
+        assert_eq!(1, requests.len());
+        assert!(requests[0]
+            .body
+            .as_ref()
+            .unwrap()
+            .contains("buildStatusChanged"));
+    }
+}
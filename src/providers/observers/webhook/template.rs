@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use regex::{Captures, Regex};
+
+static PLACEHOLDER_REGEX: &str = r"\{\{\s*(?P<NAME>[A-Za-z]+)\s*\}\}";
+
+/// The placeholders that can be used in a template.
+pub static PLACEHOLDERS: &[&str] = &[
+    "event",
+    "status",
+    "provider",
+    "collector",
+    "project",
+    "projectId",
+    "definition",
+    "definitionId",
+    "branch",
+    "buildId",
+    "buildNumber",
+    "url",
+    "started",
+    "finished",
+];
+
+pub struct Template {
+    text: String,
+    regex: Regex,
+}
+
+impl Template {
+    pub fn new<T: Into<String>>(text: T) -> Self {
+        Self {
+            text: text.into(),
+            regex: Regex::new(PLACEHOLDER_REGEX).unwrap(),
+        }
+    }
+
+    /// Gets all placeholders in the template that we don't know about.
+    pub fn get_unknown_placeholders(&self) -> Vec<String> {
+        self.regex
+            .captures_iter(&self.text)
+            .map(|capture| capture["NAME"].to_string())
+            .filter(|name| !PLACEHOLDERS.contains(&&name[..]))
+            .collect()
+    }
+
+    /// Renders the template. Placeholders without a
+    /// value are replaced with an empty string.
+    pub fn render(&self, variables: &HashMap<&str, String>) -> String {
+        self.render_with(variables, |value| value.to_string())
+    }
+
+    /// Renders the template with all values escaped so
+    /// they can be used inside a JSON string.
+    pub fn render_json(&self, variables: &HashMap<&str, String>) -> String {
+        self.render_with(variables, escape_json)
+    }
+
+    fn render_with(&self, variables: &HashMap<&str, String>, escape: fn(&str) -> String) -> String {
+        self.regex
+            .replace_all(&self.text, |capture: &Captures| {
+                variables
+                    .get(&capture["NAME"])
+                    .map(|value| escape(value))
+                    .unwrap_or_default()
+            })
+            .into_owned()
+    }
+}
+
+fn escape_json(value: &str) -> String {
+    let quoted = serde_json::Value::from(value).to_string();
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_render_template() {
+        // Given
+        let template = Template::new("{{project}} on {{ branch }} is {{status}}");
+        let mut variables = HashMap::new();
+        variables.insert("project", "Duck".to_string());
+        variables.insert("branch", "main".to_string());
+        variables.insert("status", "Failed".to_string());
+
+        // When
+        let result = template.render(&variables);
+
+        // Then
+        assert_eq!("Duck on main is Failed", result);
+    }
+
+    #[test]
+    fn should_escape_values_when_rendering_json() {
+        // Given
+        let template = Template::new(r#"{ "text": "{{project}}" }"#);
+        let mut variables = HashMap::new();
+        variables.insert("project", "The \"Duck\" \\ project\n".to_string());
+
+        // When
+        let result = template.render_json(&variables);
+
+        // Then
+        assert_eq!(r#"{ "text": "The \"Duck\" \\ project\n" }"#, result);
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!("The \"Duck\" \\ project\n", json["text"]);
+    }
+
+    #[test]
+    fn should_render_missing_variables_as_empty_string() {
+        // Given
+        let template = Template::new("[{{branch}}]");
+
+        // When
+        let result = template.render(&HashMap::new());
+
+        // Then
+        assert_eq!("[]", result);
+    }
+
+    #[test]
+    fn should_return_unknown_placeholders() {
+        // Given
+        let template = Template::new("{{branch}} {{foo}} {{ bar }}");
+
+        // When
+        let result = template.get_unknown_placeholders();
+
+        // Then
+        assert_eq!(vec!["foo", "bar"], result);
+    }
+}
@@ -0,0 +1,144 @@
+use url::Url;
+
+use crate::config::{Validate, WebhookObserverConfiguration};
+use crate::DuckResult;
+
+use super::template::Template;
+
+impl Validate for WebhookObserverConfiguration {
+    fn validate(&self) -> DuckResult<()> {
+        if let Err(e) = Url::parse(&self.url) {
+            return Err(format_err!("[{}] Webhook URL is invalid: {}", self.id, e));
+        }
+        if let Some(events) = &self.events {
+            if events.is_empty() {
+                return Err(format_err!(
+                    "[{}] Webhook observer has not specified any events",
+                    self.id
+                ));
+            }
+        }
+        if let Some(template) = &self.template {
+            if let Some(placeholder) = Template::new(template).get_unknown_placeholders().first() {
+                return Err(format_err!(
+                    "[{}] Webhook template contains unknown placeholder '{}'",
+                    self.id,
+                    placeholder
+                ));
+            }
+        }
+        if let Some(secret) = &self.secret {
+            if secret.is_empty() {
+                return Err(format_err!("[{}] Webhook HMAC secret is empty", self.id));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+    use crate::providers;
+    use crate::utils::text::TestVariableProvider;
+
+    #[test]
+    #[should_panic(expected = "[foo] Webhook URL is invalid: relative URL without a base")]
+    fn should_return_error_if_webhook_url_is_invalid() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "webhook": {
+                            "id": "foo",
+                            "url": "example.com"
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] Webhook observer has not specified any events")]
+    fn should_return_error_if_no_events_have_been_specified() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "webhook": {
+                            "id": "foo",
+                            "url": "https://example.com",
+                            "events": [ ]
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] Webhook template contains unknown placeholder 'foo'")]
+    fn should_return_error_if_template_contains_unknown_placeholder() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "webhook": {
+                            "id": "foo",
+                            "url": "https://example.com",
+                            "template": "{{branch}} {{foo}}"
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "[foo] Webhook HMAC secret is empty")]
+    fn should_return_error_if_secret_is_empty() {
+        let config = Configuration::from_json(
+            &TestVariableProvider::new(),
+            r#"
+            {
+                "collectors": [ ],
+                "observers": [
+                    {
+                        "webhook": {
+                            "id": "foo",
+                            "url": "https://example.com",
+                            "secret": ""
+                        }
+                    }
+                ]
+            }
+        "#,
+        )
+        .unwrap();
+
+        providers::create_observers(&config).unwrap();
+    }
+}
@@ -169,6 +169,7 @@ pub fn tokenize(text: &str) -> DuckResult<TokenStream> {
                         "running" => result.push(Token::Status(BuildStatus::Running)),
                         "skipped" => result.push(Token::Status(BuildStatus::Skipped)),
                         "queued" => result.push(Token::Status(BuildStatus::Queued)),
+                        "blocked" => result.push(Token::Status(BuildStatus::Blocked)),
                         _ => result.push(Token::Literal(literal)),
                     }
                 }
@@ -342,6 +343,7 @@ mod tests {
     #[test_case("'canceled'", Token::Status(BuildStatus::Canceled) ; "canceled_status")]
     #[test_case("'cancelled'", Token::Status(BuildStatus::Canceled) ; "cancelled_status")]
     #[test_case("'queued'", Token::Status(BuildStatus::Queued) ; "queued_status")]
+    #[test_case("'blocked'", Token::Status(BuildStatus::Blocked) ; "blocked_status")]
     #[test_case("'running'", Token::Status(BuildStatus::Running) ; "running_status")]
     #[test_case("'skipped'", Token::Status(BuildStatus::Skipped) ; "skipped_status")]
     fn should_recognize_statuses(expression: &str, expected: Token) {
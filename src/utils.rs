@@ -3,9 +3,12 @@ use std::sync::Mutex;
 
 use crate::DuckResult;
 
+pub mod branches;
 pub mod colors;
 pub mod date;
 pub mod http;
+pub mod net;
+pub mod signature;
 pub mod switch;
 pub mod text;
 
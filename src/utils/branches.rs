@@ -0,0 +1,184 @@
+use regex::Regex;
+
+use crate::DuckResult;
+
+static REGEX_PREFIX: &str = "regex:";
+
+/// Matches branch names against include and exclude patterns.
+///
+/// A pattern is either an exact branch name, a glob pattern where
+/// `*` matches anything but `/`, `**` matches anything and `?`
+/// matches a single character, or a regular expression prefixed
+/// with `regex:`. Patterns must match the whole branch name.
+pub struct BranchMatcher {
+    include: Vec<BranchPattern>,
+    exclude: Vec<BranchPattern>,
+}
+
+enum BranchPattern {
+    Exact(String),
+    Regex(Regex),
+}
+
+impl BranchMatcher {
+    /// Creates a new matcher. All branches that are not
+    /// excluded are included if there are no include patterns.
+    pub fn new(include: &[String], exclude: &[String]) -> DuckResult<Self> {
+        Ok(Self {
+            include: parse_patterns(include)?,
+            exclude: parse_patterns(exclude)?,
+        })
+    }
+
+    /// Checks whether or not a branch is included and not excluded.
+    pub fn is_match(&self, branch: &str) -> bool {
+        if self.exclude.iter().any(|p| p.is_match(branch)) {
+            return false;
+        }
+        self.include.is_empty() || self.include.iter().any(|p| p.is_match(branch))
+    }
+
+    /// Gets the include patterns if all of them are exact
+    /// branch names, which means that there is no need
+    /// to discover branches.
+    pub fn get_exact_branches(&self) -> Option<Vec<&str>> {
+        if self.include.is_empty() {
+            return None;
+        }
+        self.include
+            .iter()
+            .map(|p| match p {
+                BranchPattern::Exact(branch) => Some(&branch[..]),
+                BranchPattern::Regex(_) => None,
+            })
+            .collect()
+    }
+}
+
+impl BranchPattern {
+    fn parse(pattern: &str) -> DuckResult<Self> {
+        if let Some(regex) = pattern.strip_prefix(REGEX_PREFIX) {
+            return Ok(BranchPattern::Regex(Regex::new(&format!(
+                "^(?:{})$",
+                regex
+            ))?));
+        }
+        if pattern.contains('*') || pattern.contains('?') {
+            return Ok(BranchPattern::Regex(Regex::new(&glob_to_regex(pattern))?));
+        }
+        Ok(BranchPattern::Exact(pattern.to_owned()))
+    }
+
+    fn is_match(&self, branch: &str) -> bool {
+        match self {
+            BranchPattern::Exact(pattern) => pattern == branch,
+            BranchPattern::Regex(regex) => regex.is_match(branch),
+        }
+    }
+}
+
+fn parse_patterns(patterns: &[String]) -> DuckResult<Vec<BranchPattern>> {
+    patterns
+        .iter()
+        .map(|p| {
+            BranchPattern::parse(p)
+                .map_err(|e| format_err!("The branch pattern '{}' is invalid. {}", p, e))
+        })
+        .collect()
+}
+
+fn glob_to_regex(pattern: &str) -> String {
+    let mut result = String::from("^");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                result.push_str(".*");
+            }
+            '*' => result.push_str("[^/]*"),
+            '?' => result.push_str("[^/]"),
+            c => result.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    result.push('$');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn create_matcher(include: &[&str], exclude: &[&str]) -> BranchMatcher {
+        let include: Vec<String> = include.iter().map(|s| s.to_string()).collect();
+        let exclude: Vec<String> = exclude.iter().map(|s| s.to_string()).collect();
+        BranchMatcher::new(&include, &exclude).unwrap()
+    }
+
+    #[test_case("refs/heads/master", "refs/heads/master", true ; "exact")]
+    #[test_case("refs/heads/master", "refs/heads/main", false ; "exact mismatch")]
+    #[test_case("refs/heads/release/*", "refs/heads/release/1.0", true ; "wildcard")]
+    #[test_case("refs/heads/release/*", "refs/heads/release/1.0/hotfix", false ; "wildcard does not match slash")]
+    #[test_case("refs/heads/**", "refs/heads/release/1.0/hotfix", true ; "double wildcard")]
+    #[test_case("refs/heads/v?", "refs/heads/v2", true ; "single character")]
+    #[test_case("release.*", "releaseX1", false ; "dots are not wildcards")]
+    #[test_case("regex:refs/heads/release/\\d+\\.\\d+", "refs/heads/release/1.0", true ; "regex")]
+    #[test_case("regex:release", "refs/heads/release", false ; "regex must match whole name")]
+    fn should_match_branch(pattern: &str, branch: &str, expected: bool) {
+        // Given
+        let matcher = create_matcher(&[pattern], &[]);
+        // When
+        let result = matcher.is_match(branch);
+        // Then
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn should_match_all_branches_if_there_are_no_include_patterns() {
+        // Given
+        let matcher = create_matcher(&[], &["gh-pages"]);
+        // When, Then
+        assert!(matcher.is_match("master"));
+        assert!(!matcher.is_match("gh-pages"));
+    }
+
+    #[test]
+    fn should_not_match_excluded_branches() {
+        // Given
+        let matcher = create_matcher(&["refs/heads/release/*"], &["refs/heads/release/old-*"]);
+        // When, Then
+        assert!(matcher.is_match("refs/heads/release/1.0"));
+        assert!(!matcher.is_match("refs/heads/release/old-1.0"));
+    }
+
+    #[test]
+    fn should_return_exact_branches_if_there_are_no_patterns() {
+        // Given
+        let matcher = create_matcher(&["master", "develop"], &["develop"]);
+        // When
+        let result = matcher.get_exact_branches();
+        // Then
+        assert_eq!(Some(vec!["master", "develop"]), result);
+    }
+
+    #[test]
+    fn should_not_return_exact_branches_if_there_are_patterns() {
+        // Given
+        let matcher = create_matcher(&["master", "release/*"], &[]);
+        // When
+        let result = matcher.get_exact_branches();
+        // Then
+        assert_eq!(None, result);
+    }
+
+    #[test]
+    fn should_return_error_if_regex_is_invalid() {
+        // Given
+        let include = vec!["regex:release/(".to_owned()];
+        // When
+        let result = BranchMatcher::new(&include, &[]);
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -5,6 +5,11 @@ use crate::DuckResult;
 pub static TEAMCITY_FORMAT: &str = "%Y%m%dT%H%M%S%z";
 pub static AZURE_DEVOPS_FORMAT: &str = "%+";
 pub static GITHUB_FORMAT: &str = "%+";
+pub static CIRCLECI_FORMAT: &str = "%+";
+pub static BUILDKITE_FORMAT: &str = "%+";
+pub static ARGOCD_FORMAT: &str = "%+";
+pub static BITBUCKET_FORMAT: &str = "%+";
+pub static GITLAB_FORMAT: &str = "%+";
 pub static OCTOPUS_DEPLOY_FORMAT: &str = "%+";
 pub static APPVEYOR_FORMAT: &str = "%+";
 pub static DEBUGGER_FORMAT: &str = "%+";
@@ -16,9 +21,24 @@ pub fn to_timestamp(input: &str, pattern: &str) -> DuckResult<i64> {
     }
 }
 
+/// Formats a duration in seconds as a short
+/// human readable string such as `1h 2m 5s`.
+pub fn format_duration(seconds: i64) -> String {
+    let seconds = std::cmp::max(0, seconds);
+    let (hours, minutes, seconds) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+    if hours > 0 {
+        format!("{}h {}m {}s", hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use test_case::test_case;
 
     #[test]
     fn should_parse_teamcity_format() {
@@ -44,6 +64,36 @@ mod tests {
         assert_eq!(1580589796, result);
     }
 
+    #[test]
+    fn should_parse_bitbucket_format() {
+        let result = to_timestamp("2020-02-01T20:43:16.085622+00:00", BITBUCKET_FORMAT).unwrap();
+        assert_eq!(1580589796, result);
+    }
+
+    #[test]
+    fn should_parse_argocd_format() {
+        let result = to_timestamp("2020-02-01T20:43:16Z", ARGOCD_FORMAT).unwrap();
+        assert_eq!(1580589796, result);
+    }
+
+    #[test]
+    fn should_parse_buildkite_format() {
+        let result = to_timestamp("2020-02-01T20:43:16.085Z", BUILDKITE_FORMAT).unwrap();
+        assert_eq!(1580589796, result);
+    }
+
+    #[test]
+    fn should_parse_circleci_format() {
+        let result = to_timestamp("2020-02-01T20:43:16.085Z", CIRCLECI_FORMAT).unwrap();
+        assert_eq!(1580589796, result);
+    }
+
+    #[test]
+    fn should_parse_gitlab_format() {
+        let result = to_timestamp("2020-02-01T20:43:16.085Z", GITLAB_FORMAT).unwrap();
+        assert_eq!(1580589796, result);
+    }
+
     #[test]
     fn should_parse_appveyor_format() {
         let result = to_timestamp("2020-03-11T12:09:48.1638791+00:00", APPVEYOR_FORMAT).unwrap();
@@ -55,4 +105,12 @@ mod tests {
         let result = to_timestamp("2020-04-13T17:04:23.6101884+02:00", DEBUGGER_FORMAT).unwrap();
         assert_eq!(1586790263, result);
     }
+
+    #[test_case(42, "42s" ; "seconds")]
+    #[test_case(1000, "16m 40s" ; "minutes")]
+    #[test_case(3725, "1h 2m 5s" ; "hours")]
+    #[test_case(-5, "0s" ; "negative")]
+    fn should_format_duration(seconds: i64, expected: &str) {
+        assert_eq!(expected, format_duration(seconds));
+    }
 }
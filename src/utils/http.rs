@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::fmt;
+use std::time::Duration;
 
 use base64::encode;
 use reqwest::{Client, Response, StatusCode};
@@ -92,10 +93,17 @@ impl Default for ReqwestClient {
     }
 }
 
+// Collector timeouts are cooperative, so this is what
+// keeps a hanging request from blocking a collector forever.
+static REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
 impl ReqwestClient {
     pub fn new() -> Self {
         Self {
-            client: Client::new(),
+            client: Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .expect("Could not create HTTP client"),
         }
     }
 }
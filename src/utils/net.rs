@@ -0,0 +1,133 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rustls::{ClientConfig, ClientSession, StreamOwned};
+use webpki::DNSNameRef;
+
+use crate::DuckResult;
+
+/// A blocking TCP connection that might be encrypted using TLS.
+/// Used by observers that talk protocols other than HTTP.
+pub enum Transport {
+    Plain(TcpStream),
+    Tls(Box<StreamOwned<ClientSession, TcpStream>>),
+}
+
+impl Transport {
+    /// Connects to the first address of the host that answers within the timeout.
+    pub fn connect(host: &str, port: u16, timeout: Duration) -> DuckResult<Self> {
+        let mut error = None;
+        for address in (host, port).to_socket_addrs()? {
+            match TcpStream::connect_timeout(&address, timeout) {
+                Ok(stream) => return Transport::from_stream(stream, timeout),
+                Err(e) => error = Some(e),
+            }
+        }
+        Err(match error {
+            Some(e) => format_err!("Could not connect to {}:{} ({})", host, port, e),
+            None => format_err!("Could not resolve host {}", host),
+        })
+    }
+
+    fn from_stream(stream: TcpStream, timeout: Duration) -> DuckResult<Self> {
+        stream.set_read_timeout(Some(timeout))?;
+        stream.set_write_timeout(Some(timeout))?;
+        Ok(Transport::Plain(stream))
+    }
+
+    pub fn local_addr(&self) -> DuckResult<SocketAddr> {
+        Ok(self.socket().local_addr()?)
+    }
+
+    /// Encrypts the connection, verifying the
+    /// server certificate against the host name.
+    pub fn upgrade(self, host: &str) -> DuckResult<Self> {
+        let stream = match self {
+            Transport::Plain(stream) => stream,
+            Transport::Tls(_) => return Err(format_err!("Connection is already encrypted")),
+        };
+
+        let mut config = ClientConfig::new();
+        config
+            .root_store
+            .add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS);
+        let session = ClientSession::new(&Arc::new(config), DNSNameRef::try_from_ascii_str(host)?);
+
+        Ok(Transport::Tls(Box::new(StreamOwned::new(session, stream))))
+    }
+
+    fn socket(&self) -> &TcpStream {
+        match self {
+            Transport::Plain(stream) => stream,
+            Transport::Tls(stream) => stream.get_ref(),
+        }
+    }
+}
+
+impl Read for Transport {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Transport::Plain(stream) => stream.read(buf),
+            Transport::Tls(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl Write for Transport {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Transport::Plain(stream) => stream.write(buf),
+            Transport::Tls(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Transport::Plain(stream) => stream.flush(),
+            Transport::Tls(stream) => stream.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    static TIMEOUT: Duration = Duration::from_secs(5);
+
+    #[test]
+    fn should_connect_to_host() {
+        // Given
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        // When
+        let transport = Transport::connect("127.0.0.1", port, TIMEOUT).unwrap();
+
+        // Then
+        assert_eq!(
+            "127.0.0.1",
+            transport.local_addr().unwrap().ip().to_string()
+        );
+    }
+
+    #[test]
+    fn should_return_error_if_connection_fails() {
+        // Given
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        // When
+        let result = Transport::connect("127.0.0.1", port, TIMEOUT);
+
+        // Then
+        let error = result.err().unwrap().to_string();
+        assert!(error.starts_with(&format!("Could not connect to 127.0.0.1:{}", port)));
+    }
+}
@@ -0,0 +1,99 @@
+use ring::hmac;
+
+static PREFIX: &str = "sha256=";
+
+/// Signs the payload using HMAC-SHA256 and returns the
+/// signature in the form `sha256=<hex digest>`.
+pub fn sign(secret: &str, payload: &[u8]) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let tag = hmac::sign(&key, payload);
+    let digest: String = tag.as_ref().iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}{}", PREFIX, digest)
+}
+
+/// Verifies a HMAC-SHA256 signature in the form `sha256=<hex digest>`.
+/// The comparison is done in constant time.
+pub fn verify(secret: &str, payload: &[u8], signature: &str) -> bool {
+    let signature = match signature.trim().strip_prefix(PREFIX) {
+        Some(signature) => signature,
+        None => return false,
+    };
+    let signature = match decode_hex(signature) {
+        Some(signature) => signature,
+        None => return false,
+    };
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    hmac::verify(&key, payload, &signature).is_ok()
+}
+
+fn decode_hex(input: &str) -> Option<Vec<u8>> {
+    input
+        .as_bytes()
+        .chunks(2)
+        .map(|chunk| match chunk {
+            [_, _] => u8::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Example taken from GitHub's webhook documentation.
+    static SIGNATURE: &str =
+        "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+    #[test]
+    fn should_sign_payload() {
+        // Given, When
+        let result = sign("It's a Secret to Everybody", b"Hello, World!");
+        // Then
+        assert_eq!(SIGNATURE, result);
+    }
+
+    #[test]
+    fn should_verify_valid_signature() {
+        // Given, When
+        let result = verify("It's a Secret to Everybody", b"Hello, World!", SIGNATURE);
+        // Then
+        assert!(result);
+    }
+
+    #[test]
+    fn should_not_verify_signature_with_wrong_secret() {
+        // Given, When
+        let result = verify("hunter2", b"Hello, World!", SIGNATURE);
+        // Then
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_not_verify_signature_for_tampered_payload() {
+        // Given, When
+        let result = verify("It's a Secret to Everybody", b"Hello, World?", SIGNATURE);
+        // Then
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_not_verify_signature_without_prefix() {
+        // Given, When
+        let result = verify(
+            "It's a Secret to Everybody",
+            b"Hello, World!",
+            &SIGNATURE["sha256=".len()..],
+        );
+        // Then
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_not_verify_malformed_signature() {
+        // Given, When
+        let result = verify("It's a Secret to Everybody", b"Hello, World!", "sha256=xyz");
+        // Then
+        assert!(!result);
+    }
+}
@@ -26,4 +26,11 @@ describe("BuildIcon", () => {
     expect(imageLoader).toHaveBeenCalledWith("./azure.svg")
     expect(wrapper.find("img").attributes().src).toBe("./assets/azure.svg")
   })
+
+  it("displays nothing if there is no svg for the provider", () => {
+    provider = "unknown"
+    imageLoader.mockImplementationOnce(() => { throw new Error("Cannot find module") })
+    mount()
+    expect(wrapper.find("img").exists()).toBe(false)
+  })
 })
\ No newline at end of file
@@ -11,6 +11,7 @@ describe("StatusIcon", () => {
     Running: "running",
     Canceled: "stop-circle",
     Queued: "clock",
+    Blocked: "hand-paper",
     Skipped: "stop-circle"
   }
 